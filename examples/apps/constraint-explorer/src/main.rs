@@ -279,7 +279,7 @@ impl App {
         Paragraph::new(text)
             .fg(Self::TEXT_COLOR)
             .centered()
-            .wrap(Wrap { trim: false })
+            .wrap(Wrap::new().trim(false))
     }
 
     fn swap_legend() -> impl Widget {
@@ -306,7 +306,7 @@ impl App {
             )
             .centered(),
         )
-        .wrap(Wrap { trim: false })
+        .wrap(Wrap::new().trim(false))
     }
 
     /// A bar like `<----- 80 px (gap: 2 px) ----->`
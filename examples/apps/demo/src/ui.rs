@@ -270,7 +270,9 @@ fn draw_text(frame: &mut Frame, area: Rect) {
             .fg(Color::Magenta)
             .add_modifier(Modifier::BOLD),
     ));
-    let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .wrap(Wrap::new().trim(true));
     frame.render_widget(paragraph, area);
 }
 
@@ -59,7 +59,7 @@ impl App {
         };
         let paragraph = Paragraph::new(text.slow_blink())
             .centered()
-            .wrap(Wrap { trim: true });
+            .wrap(Wrap::new().trim(true));
         frame.render_widget(paragraph, instructions);
 
         let block = Block::bordered().title("Content").on_blue();
@@ -253,7 +253,7 @@ impl App {
         Paragraph::new(info)
             .block(block)
             .fg(TEXT_FG_COLOR)
-            .wrap(Wrap { trim: false })
+            .wrap(Wrap::new().trim(false))
             .render(area, buf);
     }
 }
@@ -141,6 +141,71 @@ pub struct WindowSize {
     pub pixels: Size,
 }
 
+/// The features supported by the terminal, as detected by a [`Backend`].
+///
+/// This allows applications and widgets to degrade gracefully on terminals which don't support
+/// some features, e.g. to use indexed colors instead of RGB colors. The default value is
+/// conservative, with every feature unsupported.
+///
+/// See [`Backend::capabilities`].
+///
+/// More capabilities may be added in the future, so this struct can't be constructed outside of
+/// this crate. Backends start from [`Capabilities::default`] or [`Capabilities::from_env`] and set
+/// the fields they detect.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Capabilities {
+    /// Whether RGB colors (24-bit colors) are supported.
+    pub truecolor: bool,
+    /// Whether the color of underlines can be set.
+    pub underline_color: bool,
+    /// Whether curly underlines ([`Modifier::CURLY_UNDERLINE`]) are displayed. Terminals that
+    /// don't support them usually display a straight underline instead.
+    ///
+    /// [`Modifier::CURLY_UNDERLINE`]: crate::style::Modifier::CURLY_UNDERLINE
+    pub undercurl: bool,
+    /// Whether OSC 8 hyperlinks are supported.
+    pub hyperlinks: bool,
+}
+
+impl Capabilities {
+    /// Detects the capabilities of the terminal from the environment variables.
+    ///
+    /// RGB colors are detected with the `COLORTERM` variable, which is set to `truecolor` or
+    /// `24bit` by the terminals which support them. Underline colors, curly underlines and
+    /// hyperlinks are detected by recognizing the terminal with the `TERM`, `TERM_PROGRAM` and
+    /// `VTE_VERSION` variables, so these are only reported for some well known terminals.
+    ///
+    /// This is meant to be used by the backends which can't query the terminal directly.
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars<F: Fn(&str) -> Option<String>>(var: F) -> Self {
+        let colorterm = var("COLORTERM").unwrap_or_default();
+        let term = var("TERM").unwrap_or_default();
+        let term_program = var("TERM_PROGRAM").unwrap_or_default();
+        let vte_version = var("VTE_VERSION")
+            .and_then(|version| version.parse::<u32>().ok())
+            .unwrap_or_default();
+        let modern_terminal =
+            matches!(
+                term.as_str(),
+                "xterm-kitty" | "xterm-ghostty" | "wezterm" | "foot" | "foot-extra"
+            ) || matches!(term_program.as_str(), "WezTerm" | "ghostty" | "vscode");
+        Self {
+            truecolor: colorterm.eq_ignore_ascii_case("truecolor")
+                || colorterm.eq_ignore_ascii_case("24bit"),
+            // VTE supports underline colors and curly underlines since 0.51.2
+            underline_color: modern_terminal || vte_version >= 5102,
+            undercurl: modern_terminal || vte_version >= 5102,
+            // VTE supports hyperlinks since 0.50
+            hyperlinks: modern_terminal || term_program == "iTerm.app" || vte_version >= 5000,
+        }
+    }
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries. It defines the
 /// methods required to draw content, manipulate the cursor, and clear the terminal screen.
 ///
@@ -255,7 +320,7 @@ pub trait Backend {
     ///
     /// This method is optional and may not be implemented by all backends. The default
     /// implementation calls [`clear`] if the `clear_type` is [`ClearType::All`] and returns an
-    /// error otherwise.
+    /// [`io::ErrorKind::Unsupported`] error otherwise.
     ///
     /// # Example
     ///
@@ -271,7 +336,8 @@ pub trait Backend {
     /// # Errors
     ///
     /// This method will return an error if the terminal screen could not be cleared. It will also
-    /// return an error if the `clear_type` is not supported by the backend.
+    /// return an error of kind [`io::ErrorKind::Unsupported`] if the `clear_type` is not supported
+    /// by the backend.
     ///
     /// [`clear`]: Self::clear
     fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
@@ -281,7 +347,7 @@ pub trait Backend {
             | ClearType::BeforeCursor
             | ClearType::CurrentLine
             | ClearType::UntilNewLine => Err(io::Error::new(
-                io::ErrorKind::Other,
+                io::ErrorKind::Unsupported,
                 format!("clear_type [{clear_type:?}] not supported with this backend"),
             )),
         }
@@ -313,6 +379,29 @@ pub trait Backend {
     /// Flush any buffered content to the terminal screen.
     fn flush(&mut self) -> io::Result<()>;
 
+    /// Returns the features supported by the terminal as a [`Capabilities`].
+    ///
+    /// Only the features that the backend is able to use are reported, e.g. a backend which
+    /// doesn't write hyperlinks reports them as unsupported. The default implementation returns
+    /// [`Capabilities::default`], which reports every feature as unsupported.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::backend::TestBackend;
+    /// # let backend = TestBackend::new(80, 25);
+    /// use ratatui::{backend::Backend, style::Color};
+    ///
+    /// let accent = if backend.capabilities().truecolor {
+    ///     Color::Rgb(255, 135, 0)
+    /// } else {
+    ///     Color::Indexed(208)
+    /// };
+    /// ```
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
     /// Scroll a region of the screen upwards, where a region is specified by a (half-open) range
     /// of rows.
     ///
@@ -378,6 +467,66 @@ mod tests {
 
     use super::*;
 
+    fn capabilities_from(vars: &[(&str, &str)]) -> Capabilities {
+        Capabilities::from_vars(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        })
+    }
+
+    #[test]
+    fn capabilities_default() {
+        assert_eq!(
+            Capabilities::default(),
+            Capabilities {
+                truecolor: false,
+                underline_color: false,
+                undercurl: false,
+                hyperlinks: false,
+            }
+        );
+        assert_eq!(capabilities_from(&[]), Capabilities::default());
+        assert_eq!(
+            capabilities_from(&[("TERM", "xterm-256color")]),
+            Capabilities::default()
+        );
+    }
+
+    #[test]
+    fn capabilities_truecolor() {
+        assert!(capabilities_from(&[("COLORTERM", "truecolor")]).truecolor);
+        assert!(capabilities_from(&[("COLORTERM", "24bit")]).truecolor);
+        assert!(!capabilities_from(&[("COLORTERM", "yes")]).truecolor);
+    }
+
+    #[test]
+    fn capabilities_known_terminals() {
+        let modern = Capabilities {
+            truecolor: false,
+            underline_color: true,
+            undercurl: true,
+            hyperlinks: true,
+        };
+        assert_eq!(capabilities_from(&[("TERM", "xterm-kitty")]), modern);
+        assert_eq!(capabilities_from(&[("TERM_PROGRAM", "WezTerm")]), modern);
+        assert_eq!(capabilities_from(&[("VTE_VERSION", "6800")]), modern);
+        assert_eq!(
+            capabilities_from(&[("VTE_VERSION", "5000")]),
+            Capabilities {
+                hyperlinks: true,
+                ..Capabilities::default()
+            }
+        );
+        assert_eq!(
+            capabilities_from(&[("TERM_PROGRAM", "iTerm.app")]),
+            Capabilities {
+                hyperlinks: true,
+                ..Capabilities::default()
+            }
+        );
+    }
+
     #[test]
     fn clear_type_tostring() {
         assert_eq!(ClearType::All.to_string(), "All");
@@ -998,6 +998,15 @@ mod tests {
         backend.flush().unwrap();
     }
 
+    #[test]
+    fn capabilities() {
+        let backend = TestBackend::new(10, 2);
+        assert_eq!(
+            backend.capabilities(),
+            crate::backend::Capabilities::default()
+        );
+    }
+
     #[cfg(feature = "scrolling-regions")]
     mod scrolling_regions {
         use rstest::rstest;
@@ -9,7 +9,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::{
     buffer::Cell,
     layout::{Position, Rect},
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
 };
 
@@ -65,7 +65,7 @@ use crate::{
 /// # Some(())
 /// # }
 /// ```
-#[derive(Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Buffer {
     /// The area represented by this buffer
@@ -75,6 +75,21 @@ pub struct Buffer {
     pub content: Vec<Cell>,
 }
 
+impl Clone for Buffer {
+    fn clone(&self) -> Self {
+        Self {
+            area: self.area,
+            content: self.content.clone(),
+        }
+    }
+
+    /// Reuses the allocation of the content of this buffer when it is large enough.
+    fn clone_from(&mut self, source: &Self) {
+        self.area = source.area;
+        self.content.clone_from(&source.content);
+    }
+}
+
 impl Buffer {
     /// Returns a Buffer with all cells set to the default one
     #[must_use]
@@ -329,10 +344,12 @@ impl Buffer {
     /// Print at most the first n characters of a string if enough space is available
     /// until the end of the line. Skips zero-width graphemes and control characters.
     ///
+    /// The printed cells don't link to any URL, see [`Buffer::set_span`] to print a hyperlink.
+    ///
     /// Use [`Buffer::set_string`] when the maximum amount of characters can be printed.
     pub fn set_stringn<T, S>(
         &mut self,
-        mut x: u16,
+        x: u16,
         y: u16,
         string: T,
         max_width: usize,
@@ -342,9 +359,25 @@ impl Buffer {
         T: AsRef<str>,
         S: Into<Style>,
     {
+        self.set_linked_stringn(x, y, string.as_ref(), max_width, style.into(), None)
+    }
+
+    /// Same as [`Buffer::set_stringn`], but the printed cells link to `link` when the
+    /// `hyperlinks` feature is enabled.
+    fn set_linked_stringn(
+        &mut self,
+        mut x: u16,
+        y: u16,
+        string: &str,
+        max_width: usize,
+        style: Style,
+        link: Option<&str>,
+    ) -> (u16, u16) {
+        #[cfg(not(feature = "hyperlinks"))]
+        let _ = link;
         let max_width = max_width.try_into().unwrap_or(u16::MAX);
         let mut remaining_width = self.area.right().saturating_sub(x).min(max_width);
-        let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true)
+        let graphemes = UnicodeSegmentation::graphemes(string, true)
             .filter(|symbol| !symbol.contains(char::is_control))
             .map(|symbol| (symbol, symbol.width() as u16))
             .filter(|(_symbol, width)| *width > 0)
@@ -352,9 +385,17 @@ impl Buffer {
                 remaining_width = remaining_width.checked_sub(width)?;
                 Some((symbol, width))
             });
-        let style = style.into();
+        let mut graphemes = graphemes.peekable();
+        if graphemes.peek().is_some() {
+            self.clear_wide_grapheme_before(x, y);
+        }
         for (symbol, width) in graphemes {
-            self[(x, y)].set_symbol(symbol).set_style(style);
+            let old_width = self[(x, y)].symbol().width() as u16;
+            let cell = &mut self[(x, y)];
+            cell.set_symbol(symbol).set_style(style);
+            #[cfg(feature = "hyperlinks")]
+            cell.set_hyperlink(link);
+            self.clear_wide_grapheme_after(x, y, old_width, width);
             let next_symbol = x + width;
             x += 1;
             // Reset following cells if multi-width (they would be hidden by the grapheme),
@@ -366,7 +407,73 @@ impl Buffer {
         (x, y)
     }
 
+    /// Sets the symbol of the [`Cell`] at the given position, keeping wide graphemes intact.
+    ///
+    /// Unlike setting the symbol through [`Buffer[]`](Self::index_mut), this takes the
+    /// neighbouring cells into account: if the position is the right half of a wide grapheme (e.g.
+    /// a CJK character), the left half is replaced with a space, and if the position holds a wide
+    /// grapheme that is replaced by a narrower one, its right half is replaced with a space. This
+    /// avoids leaving half of a wide grapheme on the screen. If the new symbol is itself wide, the
+    /// cells that it covers are replaced with spaces.
+    ///
+    /// Returns the cell so that its style can be changed as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{buffer::Buffer, layout::Rect};
+    ///
+    /// let mut buffer = Buffer::with_lines(["称号"]);
+    /// buffer.set_symbol((1, 0), "a");
+    /// assert_eq!(buffer, Buffer::with_lines([" a号"]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside the `Buffer`'s area.
+    #[track_caller]
+    pub fn set_symbol<P: Into<Position>>(&mut self, position: P, symbol: &str) -> &mut Cell {
+        let Position { x, y } = position.into();
+        let old_width = self[(x, y)].symbol().width() as u16;
+        let width = symbol.width() as u16;
+        self.clear_wide_grapheme_before(x, y);
+        self.clear_wide_grapheme_after(x, y, old_width, width);
+        let right = x.saturating_add(width).min(self.area.right());
+        for covered in x.saturating_add(1)..right {
+            let covered_width = self[(covered, y)].symbol().width() as u16;
+            self.clear_wide_grapheme_after(covered, y, covered_width, 0);
+            self[(covered, y)].set_symbol(" ");
+        }
+        self[(x, y)].set_symbol(symbol)
+    }
+
+    /// Replaces the symbol of a wide grapheme that starts to the left of `x` and covers `x` with a
+    /// space, as writing to `x` would otherwise leave only its left half visible.
+    fn clear_wide_grapheme_before(&mut self, x: u16, y: u16) {
+        // wide graphemes are at most 2 columns wide, so only the cell directly to the left can
+        // hold a grapheme that covers `x`
+        if x <= self.area.left() {
+            return;
+        }
+        let cell = &mut self[(x - 1, y)];
+        if cell.symbol().width() > 1 {
+            cell.set_symbol(" ");
+        }
+    }
+
+    /// Replaces the cells covered by a grapheme of `old_width` at `x` with spaces when it is
+    /// overwritten by a grapheme of `new_width`.
+    fn clear_wide_grapheme_after(&mut self, x: u16, y: u16, old_width: u16, new_width: u16) {
+        let start = x.saturating_add(new_width.max(1));
+        let end = x.saturating_add(old_width).min(self.area.right());
+        for covered in start..end {
+            self[(covered, y)].set_symbol(" ");
+        }
+    }
+
     /// Print a line, starting at the position (x, y)
+    ///
+    /// The cells of each span link to the URL of the span, see [`Span::hyperlink`].
     pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
         let mut remaining_width = max_width;
         let mut x = x;
@@ -374,12 +481,13 @@ impl Buffer {
             if remaining_width == 0 {
                 break;
             }
-            let pos = self.set_stringn(
+            let pos = self.set_linked_stringn(
                 x,
                 y,
                 span.content.as_ref(),
                 remaining_width as usize,
                 line.style.patch(span.style),
+                span.link(),
             );
             let w = pos.0.saturating_sub(x);
             x = pos.0;
@@ -389,8 +497,17 @@ impl Buffer {
     }
 
     /// Print a span, starting at the position (x, y)
+    ///
+    /// The cells link to the URL of the span, see [`Span::hyperlink`].
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
-        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+        self.set_linked_stringn(
+            x,
+            y,
+            &span.content,
+            max_width as usize,
+            span.style,
+            span.link(),
+        )
     }
 
     /// Set the style of all cells in the given area.
@@ -409,6 +526,147 @@ impl Buffer {
         }
     }
 
+    /// Set the style of the cells in the given area that are not a space.
+    ///
+    /// Unlike [`Buffer::set_style`], the gaps between words are left untouched, which makes it
+    /// possible to recolor the text of a region (e.g. to highlight matched search terms) without
+    /// painting its background.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, style::Color};
+    ///
+    /// let mut buffer = Buffer::with_lines(["a b"]);
+    /// buffer.set_style_content_only(buffer.area, Color::Red);
+    /// assert_eq!(buffer[(0, 0)].fg, Color::Red);
+    /// assert_eq!(buffer[(1, 0)].fg, Color::Reset);
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn set_style_content_only<S: Into<Style>>(&mut self, area: Rect, style: S) {
+        let style = style.into();
+        let area = self.area.intersection(area);
+        for position in area.positions() {
+            let cell = &mut self[position];
+            if cell.symbol() != " " {
+                cell.set_style(style);
+            }
+        }
+    }
+
+    /// Add the given modifier to all cells in the given area.
+    ///
+    /// The colors and the other modifiers of the cells are left untouched. This is useful for
+    /// temporary effects such as flashing a region with [`Modifier::REVERSED`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, style::Modifier};
+    ///
+    /// let mut buffer = Buffer::with_lines(["abc"]);
+    /// buffer.add_modifier(Rect::new(0, 0, 2, 1), Modifier::REVERSED);
+    /// assert!(buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+    /// assert!(!buffer[(2, 0)].modifier.contains(Modifier::REVERSED));
+    /// ```
+    pub fn add_modifier(&mut self, area: Rect, modifier: Modifier) {
+        let area = self.area.intersection(area);
+        for position in area.positions() {
+            self[position].modifier.insert(modifier);
+        }
+    }
+
+    /// Remove the given modifier from all cells in the given area.
+    ///
+    /// The colors and the other modifiers of the cells are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, style::Modifier};
+    ///
+    /// let mut buffer = Buffer::with_lines(["abc"]);
+    /// buffer.add_modifier(buffer.area, Modifier::BOLD | Modifier::REVERSED);
+    /// buffer.remove_modifier(buffer.area, Modifier::REVERSED);
+    /// assert_eq!(buffer[(0, 0)].modifier, Modifier::BOLD);
+    /// ```
+    pub fn remove_modifier(&mut self, area: Rect, modifier: Modifier) {
+        let area = self.area.intersection(area);
+        for position in area.positions() {
+            self[position].modifier.remove(modifier);
+        }
+    }
+
+    /// Blend the style of all cells in the given area toward the given style.
+    ///
+    /// For each cell, the colors set in `style` are blended with the current colors of the cell
+    /// using [`Color::blend`], where an `alpha` of `0.0` keeps the current colors and an `alpha`
+    /// of `1.0` sets the colors of `style`. This is useful for fade-in / fade-out animations.
+    ///
+    /// Named and indexed colors are converted to RGB before blending. As [`Color::Reset`] depends
+    /// on the terminal, it is treated as white for the foreground and underline colors and as black
+    /// for the background color while blending. The modifiers of `style` are applied as with
+    /// [`Buffer::set_style`], as they can't be blended.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     style::{Color, Style},
+    /// };
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(0, 0, 0)));
+    /// buffer.blend_style(buffer.area, Style::new().bg(Color::Rgb(200, 100, 0)), 0.5);
+    /// assert_eq!(buffer[(0, 0)].bg, Color::Rgb(100, 50, 0));
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    /// [`Color::blend`]: crate::style::Color::blend
+    /// [`Color::Reset`]: crate::style::Color::Reset
+    pub fn blend_style<S: Into<Style>>(&mut self, area: Rect, style: S, alpha: f32) {
+        use crate::style::Color;
+
+        fn blend(from: Color, to: Color, reset: Color, alpha: f32) -> Color {
+            // keep `Color::Reset` as is at the ends of the transition
+            if alpha <= 0.0 {
+                return from;
+            }
+            if alpha >= 1.0 {
+                return to;
+            }
+            let resolve = |color| if color == Color::Reset { reset } else { color };
+            resolve(from).blend(resolve(to), alpha)
+        }
+        let style = style.into();
+        let area = self.area.intersection(area);
+        for position in area.positions() {
+            let cell = &mut self[position];
+            if let Some(fg) = style.fg {
+                cell.fg = blend(cell.fg, fg, Color::White, alpha);
+            }
+            if let Some(bg) = style.bg {
+                cell.bg = blend(cell.bg, bg, Color::Black, alpha);
+            }
+            #[cfg(feature = "underline-color")]
+            if let Some(underline_color) = style.underline_color {
+                cell.underline_color =
+                    blend(cell.underline_color, underline_color, Color::White, alpha);
+            }
+            cell.modifier.insert(style.add_modifier);
+            cell.modifier.remove(style.sub_modifier);
+        }
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -457,6 +715,110 @@ impl Buffer {
         self.area = area;
     }
 
+    /// Copies the cells of `src_area` in `src` into this buffer, with the top left cell of the area
+    /// placed at `dst_origin`.
+    ///
+    /// The copied region is clipped to both the area of `src` and the area of this buffer, so only
+    /// cells that exist in both buffers are copied. Wide graphemes (e.g. CJK characters) that would
+    /// be cut in half by an edge of the destination region are replaced with spaces, both inside
+    /// the region and immediately to its left.
+    ///
+    /// To copy a region within the same buffer, use [`Buffer::copy_within`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     buffer::Buffer,
+    ///     layout::{Position, Rect},
+    /// };
+    ///
+    /// let src = Buffer::with_lines(["abcd", "efgh"]);
+    /// let mut dst = Buffer::with_lines(["......", "......", "......"]);
+    /// dst.blit(Rect::new(1, 0, 2, 2), Position::new(3, 1), &src);
+    /// assert_eq!(dst, Buffer::with_lines(["......", "...bc.", "...fg."]));
+    /// ```
+    pub fn blit(&mut self, src_area: Rect, dst_origin: Position, src: &Self) {
+        let Some((src_area, dst_area)) = blit_areas(src_area, src.area, dst_origin, self.area)
+        else {
+            return;
+        };
+        let width = dst_area.width as usize;
+        for row in 0..dst_area.height {
+            let from = src.index_of(src_area.x, src_area.y + row);
+            let to = self.index_of(dst_area.x, dst_area.y + row);
+            self.content[to..to + width].clone_from_slice(&src.content[from..from + width]);
+        }
+        self.clear_split_wide_graphemes(dst_area);
+    }
+
+    /// Copies the cells of `src_area` to another part of this buffer, with the top left cell of the
+    /// area placed at `dst_origin`.
+    ///
+    /// This behaves like [`Buffer::blit`] with this buffer as the source. The source and
+    /// destination regions may overlap, in which case the cells are copied in the direction that
+    /// reads every source cell before it is overwritten, so the result is the same as copying from
+    /// an unmodified copy of the buffer. This makes it suitable for scrolling part of the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     buffer::Buffer,
+    ///     layout::{Position, Rect},
+    /// };
+    ///
+    /// let mut buffer = Buffer::with_lines(["abcde"]);
+    /// buffer.copy_within(Rect::new(0, 0, 4, 1), Position::new(1, 0));
+    /// assert_eq!(buffer, Buffer::with_lines(["aabcd"]));
+    /// ```
+    pub fn copy_within(&mut self, src_area: Rect, dst_origin: Position) {
+        let Some((src_area, dst_area)) = blit_areas(src_area, self.area, dst_origin, self.area)
+        else {
+            return;
+        };
+        // copy backwards when the destination is after the source so that source cells are read
+        // before being overwritten
+        let rows_reversed = dst_area.y > src_area.y;
+        let columns_reversed = dst_area.y == src_area.y && dst_area.x > src_area.x;
+        for row in 0..dst_area.height {
+            let row = if rows_reversed {
+                dst_area.height - 1 - row
+            } else {
+                row
+            };
+            let from = self.index_of(src_area.x, src_area.y + row);
+            let to = self.index_of(dst_area.x, dst_area.y + row);
+            for column in 0..dst_area.width as usize {
+                let column = if columns_reversed {
+                    dst_area.width as usize - 1 - column
+                } else {
+                    column
+                };
+                self.content[to + column] = self.content[from + column].clone();
+            }
+        }
+        self.clear_split_wide_graphemes(dst_area);
+    }
+
+    /// Replaces the wide graphemes that cross the left or right edge of `area` with spaces.
+    fn clear_split_wide_graphemes(&mut self, area: Rect) {
+        for y in area.top()..area.bottom() {
+            self.clear_wide_grapheme_before(area.left(), y);
+            // the cells covered by a wide grapheme always hold a space, so only the last non space
+            // symbol of the row can extend past the right edge
+            for x in (area.left()..area.right()).rev() {
+                let cell = &mut self[(x, y)];
+                if cell.symbol() != " " {
+                    if x.saturating_add(cell.symbol().width() as u16) > area.right() {
+                        cell.set_symbol(" ");
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
     /// self to other.
     ///
@@ -486,10 +848,84 @@ impl Buffer {
     /// Updates: `0: a, 1: コ` (double width symbol at index 1 - skip index 2)
     /// ```
     pub fn diff<'a>(&self, other: &'a Self) -> Vec<(u16, u16, &'a Cell)> {
+        let mut updates: Vec<(u16, u16, &Cell)> = vec![];
+        self.for_each_update(other, |i, current| {
+            let (x, y) = self.pos_of(i);
+            updates.push((x, y, current));
+        });
+        updates
+    }
+
+    /// Builds the same sequence of updates as [`Buffer::diff`], but replaces the updates of the
+    /// blank cells at the end of each row by the position from which the row can be erased.
+    ///
+    /// A cell is blank when it is equal to [`Cell::EMPTY`], i.e. a space with the default style.
+    /// When some of the blank cells at the end of a row of `other` need to be updated, erasing the
+    /// row from the first of these cells to the end of the line (e.g. with
+    /// [`ClearType::UntilNewLine`]) has the same effect as drawing them, but takes a single escape
+    /// sequence instead of one update per cell. This reduces the output on wide terminals when
+    /// content is removed from the end of the rows.
+    ///
+    /// Returns the updates to draw and, for each row that should be erased, the position of the
+    /// first cell to erase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Position};
+    ///
+    /// let prev = Buffer::with_lines(["abcdef"]);
+    /// let next = Buffer::with_lines(["xb    "]);
+    /// let (updates, erases) = prev.diff_erasing_trailing(&next);
+    /// assert_eq!(updates.len(), 1);
+    /// assert_eq!(updates[0].2.symbol(), "x");
+    /// assert_eq!(erases, [Position::new(2, 0)]);
+    /// ```
+    ///
+    /// [`ClearType::UntilNewLine`]: crate::backend::ClearType::UntilNewLine
+    pub fn diff_erasing_trailing<'a>(
+        &self,
+        other: &'a Self,
+    ) -> (Vec<(u16, u16, &'a Cell)>, Vec<Position>) {
+        let width = usize::from(other.area.width).max(1);
+        // the column of each row of `other` from which all the cells are blank
+        let trailing_starts = other
+            .content
+            .chunks(width)
+            .map(|row| {
+                let mut start = 0;
+                for (x, cell) in row.iter().enumerate() {
+                    if *cell != Cell::EMPTY {
+                        start = x + cell.symbol().width().max(1);
+                    }
+                }
+                start
+            })
+            .collect::<Vec<_>>();
+
+        let mut updates: Vec<(u16, u16, &Cell)> = vec![];
+        let mut erases: Vec<Position> = vec![];
+        self.for_each_update(other, |i, current| {
+            let (x, y) = self.pos_of(i);
+            if i % width < trailing_starts[i / width] {
+                updates.push((x, y, current));
+            } else if erases.last().map_or(true, |erase| erase.y != y) {
+                erases.push(Position::new(x, y));
+            } else {
+                // the row is already erased from an earlier column
+            }
+        });
+        (updates, erases)
+    }
+
+    /// Calls `f` with the index and the next cell for each cell that needs to be updated.
+    fn for_each_update<'a, F>(&self, other: &'a Self, mut f: F)
+    where
+        F: FnMut(usize, &'a Cell),
+    {
         let previous_buffer = &self.content;
         let next_buffer = &other.content;
 
-        let mut updates: Vec<(u16, u16, &Cell)> = vec![];
         // Cells invalidated by drawing/replacing preceding multi-width characters:
         let mut invalidated: usize = 0;
         // Cells from the current buffer to skip due to preceding multi-width characters taking
@@ -497,8 +933,7 @@ impl Buffer {
         let mut to_skip: usize = 0;
         for (i, (current, previous)) in next_buffer.iter().zip(previous_buffer.iter()).enumerate() {
             if !current.skip && (current != previous || invalidated > 0) && to_skip == 0 {
-                let (x, y) = self.pos_of(i);
-                updates.push((x, y, &next_buffer[i]));
+                f(i, current);
             }
 
             to_skip = current.symbol().width().saturating_sub(1);
@@ -506,7 +941,48 @@ impl Buffer {
             let affected_width = std::cmp::max(current.symbol().width(), previous.symbol().width());
             invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
         }
-        updates
+    }
+
+    /// Returns an iterator over the rows of the buffer, each reconstructed as a styled [`Line`].
+    ///
+    /// Adjacent cells with the same style are merged into a single [`Span`]. Multi-width symbols
+    /// are emitted once and the cells hidden by them are skipped.
+    ///
+    /// This is the inverse of rendering text into a buffer and is useful for exporting the
+    /// rendered content (e.g. copying the visible content to the clipboard) or for testing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, text::Line};
+    ///
+    /// let buffer = Buffer::with_lines(["Hello", "World"]);
+    /// let lines: Vec<Line> = buffer.lines().collect();
+    /// assert_eq!(lines.len(), 2);
+    /// assert_eq!(lines[0].to_string(), "Hello");
+    /// ```
+    pub fn lines(&self) -> impl Iterator<Item = Line<'static>> + '_ {
+        // the content is empty when the width is zero, so using 1 avoids a panic in `chunks`
+        let width = usize::from(self.area.width).max(1);
+        self.content.chunks(width).map(|row| {
+            let mut spans: Vec<Span<'static>> = vec![];
+            let mut skip: usize = 0;
+            for cell in row {
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                skip = cell.symbol().width().saturating_sub(1);
+                let style = cell.style();
+                match spans.last_mut() {
+                    Some(span) if span.style == style => {
+                        span.content.to_mut().push_str(cell.symbol());
+                    }
+                    _ => spans.push(Span::styled(cell.symbol().to_string(), style)),
+                }
+            }
+            Line::from(spans)
+        })
     }
 }
 
@@ -644,6 +1120,40 @@ impl fmt::Debug for Buffer {
     }
 }
 
+/// Clips a copy of `src_area` from a buffer covering `src_bounds` to the position `dst_origin` of a
+/// buffer covering `dst_bounds`.
+///
+/// Returns the source and destination areas of the cells that exist in both buffers, which always
+/// have the same size, or `None` if there is nothing to copy.
+fn blit_areas(
+    src_area: Rect,
+    src_bounds: Rect,
+    dst_origin: Position,
+    dst_bounds: Rect,
+) -> Option<(Rect, Rect)> {
+    let src = src_area.intersection(src_bounds);
+    if src.is_empty() {
+        return None;
+    }
+    let unclipped = Rect::new(
+        dst_origin.x.saturating_add(src.x - src_area.x),
+        dst_origin.y.saturating_add(src.y - src_area.y),
+        src.width,
+        src.height,
+    );
+    let dst = unclipped.intersection(dst_bounds);
+    if dst.is_empty() {
+        return None;
+    }
+    let src = Rect::new(
+        src.x + (dst.x - unclipped.x),
+        src.y + (dst.y - unclipped.y),
+        dst.width,
+        dst.height,
+    );
+    Some((src, dst))
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter;
@@ -911,6 +1421,48 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["コン "]));
     }
 
+    #[test]
+    fn set_string_overwrite_right_half_of_wide_grapheme() {
+        let mut buffer = Buffer::with_lines(["称号a"]);
+        buffer.set_string(1, 0, "b", Style::default());
+        assert_eq!(buffer, Buffer::with_lines([" b号a"]));
+
+        // writing nothing leaves the wide grapheme alone
+        let mut buffer = Buffer::with_lines(["称号a"]);
+        buffer.set_string(1, 0, "", Style::default());
+        assert_eq!(buffer, Buffer::with_lines(["称号a"]));
+    }
+
+    #[test]
+    fn set_string_overwrite_left_half_of_wide_grapheme() {
+        let mut buffer = Buffer::with_lines(["称号a"]);
+        buffer.set_string(2, 0, "b", Style::default());
+        assert_eq!(buffer, Buffer::with_lines(["称b a"]));
+    }
+
+    #[rstest]
+    #[case::right_half((1, 0), "b", " b号a ")]
+    #[case::left_half((2, 0), "b", "称b a ")]
+    #[case::wide_over_right_half((1, 0), "界", " 界 a ")]
+    #[case::wide_over_narrow((4, 0), "界", "称号界")]
+    fn set_symbol_wide_grapheme(
+        #[case] position: (u16, u16),
+        #[case] symbol: &str,
+        #[case] expected: &str,
+    ) {
+        let mut buffer = Buffer::with_lines(["称号a "]);
+        buffer.set_symbol(position, symbol);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn set_symbol_returns_cell() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer.set_symbol((0, 0), "a").set_fg(Color::Red);
+        assert_eq!(buffer[(0, 0)].symbol(), "a");
+        assert_eq!(buffer[(0, 0)].fg, Color::Red);
+    }
+
     #[fixture]
     fn small_one_line_buffer() -> Buffer {
         Buffer::empty(Rect::new(0, 0, 5, 1))
@@ -972,6 +1524,36 @@ mod tests {
         assert_eq!(actual_styles, expected_styles);
     }
 
+    #[cfg(feature = "hyperlinks")]
+    #[test]
+    fn set_line_hyperlinks() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buffer.set_string(0, 0, "xxxxxx", Style::new());
+        buffer[(5, 0)].set_hyperlink(Some("https://example.com"));
+        let line = Line::from(vec![
+            Span::raw("ab").hyperlink("https://ratatui.rs"),
+            Span::raw("cd"),
+        ]);
+        buffer.set_line(0, 0, &line, 6);
+        buffer.set_string(4, 0, "e", Style::new());
+        let hyperlinks: Vec<_> = buffer
+            .content
+            .iter()
+            .map(|cell| cell.hyperlink.as_deref())
+            .collect();
+        assert_eq!(
+            hyperlinks,
+            [
+                Some("https://ratatui.rs"),
+                Some("https://ratatui.rs"),
+                None,
+                None,
+                None,
+                Some("https://example.com"),
+            ]
+        );
+    }
+
     #[test]
     fn set_style() {
         let mut buffer = Buffer::with_lines(["aaaaa", "bbbbb", "ccccc"]);
@@ -998,6 +1580,83 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn set_style_content_only_skips_spaces() {
+        let mut buffer = Buffer::with_lines(["ab cd", " e   "]);
+        buffer.set_style_content_only(Rect::new(1, 0, 10, 2), Style::new().red().bold());
+        let expected = Buffer::with_lines([
+            Line::from(vec![
+                "a".into(),
+                "b".red().bold(),
+                " ".into(),
+                "cd".red().bold(),
+            ]),
+            Line::from(vec![" ".into(), "e".red().bold(), "   ".into()]),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn add_modifier_keeps_colors() {
+        let mut buffer = Buffer::with_lines(["aaa".red().on_blue().bold()]);
+        buffer.add_modifier(Rect::new(1, 0, 10, 1), Modifier::REVERSED);
+        let expected = Buffer::with_lines([Line::from(vec![
+            "a".red().on_blue().bold(),
+            "aa".red().on_blue().bold().reversed(),
+        ])]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn remove_modifier_keeps_colors() {
+        let mut buffer = Buffer::with_lines(["aaa".red().on_blue().bold().italic()]);
+        buffer.remove_modifier(Rect::new(0, 0, 2, 1), Modifier::ITALIC);
+        let expected = Buffer::with_lines([Line::from(vec![
+            "aa".red().on_blue().bold(),
+            "a".red().on_blue().bold().italic(),
+        ])]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn blend_style() {
+        let mut buffer = Buffer::with_lines(["aaa"]);
+        buffer.set_style(
+            Rect::new(1, 0, 2, 1),
+            Style::new().fg(Color::Rgb(100, 0, 0)),
+        );
+        buffer.blend_style(
+            Rect::new(0, 0, 10, 1),
+            Style::new()
+                .fg(Color::Rgb(200, 0, 0))
+                .bg(Color::Blue)
+                .bold(),
+            0.5,
+        );
+        let blended = [
+            (Color::Rgb(228, 128, 128), Color::Rgb(0, 0, 64)),
+            (Color::Rgb(150, 0, 0), Color::Rgb(0, 0, 64)),
+            (Color::Rgb(150, 0, 0), Color::Rgb(0, 0, 64)),
+        ];
+        for (cell, (fg, bg)) in buffer.content().iter().zip(blended) {
+            assert_eq!(cell.fg, fg);
+            assert_eq!(cell.bg, bg);
+            assert_eq!(cell.modifier, Modifier::BOLD);
+        }
+    }
+
+    #[test]
+    fn blend_style_keeps_reset_at_the_ends() {
+        let mut buffer = Buffer::with_lines(["a"]);
+        buffer.blend_style(buffer.area, Style::new().red().on_red(), 0.0);
+        assert_eq!(buffer, Buffer::with_lines(["a"]));
+
+        let mut buffer = Buffer::with_lines(["a".red().on_red()]);
+        buffer.blend_style(buffer.area, Style::reset(), 1.0);
+        assert_eq!(buffer[(0, 0)].fg, Color::Reset);
+        assert_eq!(buffer[(0, 0)].bg, Color::Reset);
+    }
+
     #[test]
     fn with_lines() {
         #[rustfmt::skip]
@@ -1013,6 +1672,67 @@ mod tests {
         assert_eq!(buffer.area.height, 4);
     }
 
+    #[test]
+    fn lines() {
+        let buffer = Buffer::with_lines([
+            Line::from(vec!["ab".red(), "cde".into()]),
+            Line::from("コンe"),
+        ]);
+        let reset = Cell::EMPTY.style();
+        assert_eq!(
+            buffer.lines().collect_vec(),
+            [
+                Line::from(vec![
+                    Span::styled("ab", reset.red()),
+                    Span::styled("cde", reset),
+                ]),
+                Line::from(vec![Span::styled("コンe", reset)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn lines_empty_buffer() {
+        let buffer = Buffer::empty(Rect::ZERO);
+        assert_eq!(buffer.lines().count(), 0);
+    }
+
+    #[test]
+    fn diff_erasing_trailing() {
+        let prev = Buffer::with_lines(["abcdef", "abcdef", "abcdef", "a     "]);
+        let next = Buffer::with_lines(["abc   ", "a c   ", "abcdef", "abcd  "]);
+        let (updates, erases) = prev.diff_erasing_trailing(&next);
+        assert_eq!(
+            updates,
+            [
+                (1, 1, &Cell::EMPTY),
+                (1, 3, &Cell::new("b")),
+                (2, 3, &Cell::new("c")),
+                (3, 3, &Cell::new("d")),
+            ]
+        );
+        assert_eq!(erases, [Position::new(3, 0), Position::new(3, 1)]);
+    }
+
+    #[test]
+    fn diff_erasing_trailing_keeps_styled_blank_cells() {
+        let prev = Buffer::with_lines(["abcd"]);
+        let mut next = Buffer::with_lines(["a   "]);
+        next[(2, 0)].set_bg(Color::Red);
+        let (updates, erases) = prev.diff_erasing_trailing(&next);
+        assert_eq!(updates.len(), 2);
+        assert_eq!(erases, [Position::new(3, 0)]);
+    }
+
+    #[test]
+    fn diff_erasing_trailing_after_wide_symbol() {
+        let prev = Buffer::with_lines(["abcdef"]);
+        let next = Buffer::with_lines(["コ    "]);
+        let (updates, erases) = prev.diff_erasing_trailing(&next);
+        assert_eq!(updates, [(0, 0, &Cell::new("コ"))]);
+        assert_eq!(erases, [Position::new(2, 0)]);
+    }
+
     #[test]
     fn diff_empty_empty() {
         let area = Rect::new(0, 0, 40, 40);
@@ -1121,6 +1841,15 @@ mod tests {
         assert_eq!(diff, [(0, 0, &Cell::new("4"))],);
     }
 
+    #[cfg(feature = "hyperlinks")]
+    #[test]
+    fn diff_hyperlink_changed() {
+        let prev = Buffer::with_lines(["abc"]);
+        let mut next = prev.clone();
+        next[(1, 0)].set_hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(prev.diff(&next), [(1, 0, &next[(1, 0)])]);
+    }
+
     #[rstest]
     #[case(Rect::new(0, 0, 2, 2), Rect::new(0, 2, 2, 2), ["11", "11", "22", "22"])]
     #[case(Rect::new(2, 2, 2, 2), Rect::new(0, 0, 2, 2), ["22  ", "22  ", "  11", "  11"])]
@@ -1197,6 +1926,107 @@ mod tests {
         assert_eq!(skipped, expected);
     }
 
+    #[rstest]
+    #[case::whole(Rect::new(0, 0, 3, 2), Position::new(0, 0), ["abc..", "def..", "....."])]
+    #[case::offset(Rect::new(0, 0, 3, 2), Position::new(2, 1), [".....", "..abc", "..def"])]
+    #[case::clipped_to_destination(Rect::new(0, 0, 3, 2), Position::new(3, 2), [".....", ".....", "...ab"])]
+    #[case::clipped_to_source(Rect::new(1, 1, 5, 5), Position::new(0, 0), ["ef...", ".....", "....."])]
+    #[case::outside_source(Rect::new(3, 0, 2, 2), Position::new(0, 0), [".....", ".....", "....."])]
+    #[case::outside_destination(Rect::new(0, 0, 3, 2), Position::new(5, 0), [".....", ".....", "....."])]
+    fn blit<'line, Lines>(
+        #[case] src_area: Rect,
+        #[case] dst_origin: Position,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let src = Buffer::with_lines(["abc", "def"]);
+        let mut dst = Buffer::with_lines(["....."; 3]);
+        dst.blit(src_area, dst_origin, &src);
+        assert_eq!(dst, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn blit_between_offset_buffers() {
+        let mut src = Buffer::with_lines(["abc", "def"]);
+        src.area.x = 10;
+        src.area.y = 5;
+        let mut dst = Buffer::with_lines(["....", "...."]);
+        dst.area.x = 2;
+        dst.area.y = 3;
+        dst.blit(Rect::new(11, 5, 2, 2), Position::new(3, 3), &src);
+        let mut expected = Buffer::with_lines([".bc.", ".ef."]);
+        expected.area.x = 2;
+        expected.area.y = 3;
+        assert_eq!(dst, expected);
+    }
+
+    #[test]
+    fn blit_copies_styles() {
+        let src = Buffer::with_lines([Line::from("ab".red())]);
+        let mut dst = Buffer::with_lines(["...."]);
+        dst.blit(src.area, Position::new(1, 0), &src);
+        assert_eq!(
+            dst,
+            Buffer::with_lines([Line::from(vec![".".into(), "ab".red(), ".".into()])])
+        );
+    }
+
+    #[rstest]
+    #[case::split_at_right_edge(Rect::new(0, 0, 3, 1), Position::new(0, 0), ["ab ..."])]
+    #[case::split_at_left_edge(Rect::new(3, 0, 3, 1), Position::new(0, 0), [" cd..."])]
+    #[case::whole(Rect::new(2, 0, 4, 1), Position::new(1, 0), [".称cd."])]
+    fn blit_wide_graphemes_in_source<'line, Lines>(
+        #[case] src_area: Rect,
+        #[case] dst_origin: Position,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let src = Buffer::with_lines(["ab称cd"]);
+        let mut dst = Buffer::with_lines(["......"]);
+        dst.blit(src_area, dst_origin, &src);
+        assert_eq!(dst, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn blit_over_wide_grapheme_in_destination() {
+        let src = Buffer::with_lines(["xy"]);
+        let mut dst = Buffer::with_lines(["称号."]);
+        dst.blit(src.area, Position::new(1, 0), &src);
+        assert_eq!(dst, Buffer::with_lines([" xy ."]));
+    }
+
+    #[rstest]
+    #[case::right(Rect::new(0, 0, 4, 1), Position::new(1, 0), ["aabcd", "fghij", "klmno"])]
+    #[case::left(Rect::new(1, 0, 4, 1), Position::new(0, 0), ["bcdee", "fghij", "klmno"])]
+    #[case::down(Rect::new(0, 0, 5, 2), Position::new(0, 1), ["abcde", "abcde", "fghij"])]
+    #[case::up(Rect::new(0, 1, 5, 2), Position::new(0, 0), ["fghij", "klmno", "klmno"])]
+    #[case::diagonal_down(Rect::new(0, 0, 3, 2), Position::new(1, 1), ["abcde", "fabcj", "kfgho"])]
+    #[case::diagonal_up(Rect::new(1, 1, 3, 2), Position::new(0, 0), ["ghide", "lmnij", "klmno"])]
+    #[case::clipped(Rect::new(0, 0, 5, 3), Position::new(3, 2), ["abcde", "fghij", "klmab"])]
+    fn copy_within<'line, Lines>(
+        #[case] src_area: Rect,
+        #[case] dst_origin: Position,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let mut buffer = Buffer::with_lines(["abcde", "fghij", "klmno"]);
+        buffer.copy_within(src_area, dst_origin);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn copy_within_splits_wide_graphemes() {
+        let mut buffer = Buffer::with_lines(["a称b..."]);
+        buffer.copy_within(Rect::new(0, 0, 2, 1), Position::new(4, 0));
+        assert_eq!(buffer, Buffer::with_lines(["a称ba ."]));
+    }
+
     #[test]
     fn with_lines_accepts_into_lines() {
         use crate::style::Stylize;
@@ -29,6 +29,13 @@ pub struct Cell {
     /// The modifier of the cell.
     pub modifier: Modifier,
 
+    /// The URL the cell links to.
+    ///
+    /// Backends that support OSC 8 hyperlinks emit a single hyperlink for each run of adjacent
+    /// cells sharing the same URL.
+    #[cfg(feature = "hyperlinks")]
+    pub hyperlink: Option<String>,
+
     /// Whether the cell should be skipped when copying (diffing) the buffer to the screen.
     pub skip: bool,
 }
@@ -51,6 +58,8 @@ impl Cell {
             #[cfg(feature = "underline-color")]
             underline_color: Color::Reset,
             modifier: Modifier::empty(),
+            #[cfg(feature = "hyperlinks")]
+            hyperlink: None,
             skip: false,
         }
     }
@@ -128,6 +137,15 @@ impl Cell {
         }
     }
 
+    /// Sets the URL the cell links to, or removes the hyperlink when `url` is `None`.
+    #[cfg(feature = "hyperlinks")]
+    pub fn set_hyperlink(&mut self, url: Option<&str>) -> &mut Self {
+        if self.hyperlink.as_deref() != url {
+            self.hyperlink = url.map(String::from);
+        }
+        self
+    }
+
     /// Sets the cell to be skipped when copying (diffing) the buffer to the screen.
     ///
     /// This is helpful when it is necessary to prevent the buffer from overwriting a cell that is
@@ -147,6 +165,10 @@ impl Cell {
             self.underline_color = Color::Reset;
         }
         self.modifier = Modifier::empty();
+        #[cfg(feature = "hyperlinks")]
+        {
+            self.hyperlink = None;
+        }
         self.skip = false;
     }
 }
@@ -181,6 +203,8 @@ mod tests {
                 #[cfg(feature = "underline-color")]
                 underline_color: Color::Reset,
                 modifier: Modifier::empty(),
+                #[cfg(feature = "hyperlinks")]
+                hyperlink: None,
                 skip: false,
             }
         );
@@ -238,6 +262,16 @@ mod tests {
         assert_eq!(cell.bg, Color::Blue);
     }
 
+    #[cfg(feature = "hyperlinks")]
+    #[test]
+    fn set_hyperlink() {
+        let mut cell = Cell::EMPTY;
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(cell.hyperlink.as_deref(), Some("https://ratatui.rs"));
+        cell.set_hyperlink(None);
+        assert_eq!(cell.hyperlink, None);
+    }
+
     #[test]
     fn set_skip() {
         let mut cell = Cell::EMPTY;
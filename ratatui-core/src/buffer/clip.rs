@@ -0,0 +1,144 @@
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+};
+
+/// A cell that is never written by widgets, used to detect the cells that were written
+const UNWRITTEN: Cell = Cell::new("\0");
+
+/// A debugging wrapper around a [`Buffer`] that records writes outside of a clip rect.
+///
+/// Widgets are expected to only write to the area they are rendered in, and the content that
+/// doesn't fit in the area is usually truncated. When a widget writes outside of its area, the
+/// written cells overwrite other widgets, or are dropped when the area is clipped (e.g. by
+/// [`Frame::render_widget`]). A `ClipBuffer` makes these writes visible, which helps to diagnose
+/// why the content of a widget is cut off.
+///
+/// The wrapped buffer covers the same area as the original buffer. The cells inside the clip rect
+/// are copied from the original buffer, so that the widget renders as usual, and the other cells
+/// are marked as unwritten. After rendering, [`ClipBuffer::dropped_writes`] counts the cells
+/// outside of the clip rect that the widget wrote to, and [`ClipBuffer::merge_into`] copies the
+/// cells inside of the clip rect back to the original buffer. Writes outside of the area of the
+/// original buffer can't be recorded.
+///
+/// This copies the buffer for each render, so it should only be used for debugging.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::{
+///     buffer::{Buffer, ClipBuffer},
+///     layout::Rect,
+///     style::Style,
+/// };
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+/// let clip = Rect::new(0, 0, 4, 1);
+/// let mut clip_buffer = ClipBuffer::new(&buffer, clip);
+/// // a widget that doesn't truncate its content to its area
+/// clip_buffer
+///     .buffer_mut()
+///     .set_string(clip.x, clip.y, "Hello world", Style::new());
+/// assert_eq!(clip_buffer.dropped_writes(), 6);
+/// clip_buffer.merge_into(&mut buffer);
+/// assert_eq!(buffer, Buffer::with_lines(["Hell      "]));
+/// ```
+///
+/// [`Frame::render_widget`]: crate::terminal::Frame::render_widget
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClipBuffer {
+    buffer: Buffer,
+    clip: Rect,
+}
+
+impl ClipBuffer {
+    /// Creates a new `ClipBuffer` wrapping a copy of `buffer` that records writes outside of
+    /// `clip`.
+    ///
+    /// The clip rect is limited to the area of the buffer.
+    pub fn new(buffer: &Buffer, clip: Rect) -> Self {
+        let clip = buffer.area.intersection(clip);
+        let mut wrapped = Buffer::filled(buffer.area, UNWRITTEN);
+        for position in clip.positions() {
+            wrapped[position] = buffer[position].clone();
+        }
+        Self {
+            buffer: wrapped,
+            clip,
+        }
+    }
+
+    /// Returns the clip rect
+    pub const fn clip(&self) -> Rect {
+        self.clip
+    }
+
+    /// Returns the wrapped buffer to render widgets to
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffer
+    }
+
+    /// Returns the number of cells outside of the clip rect that were written to
+    pub fn dropped_writes(&self) -> usize {
+        self.buffer
+            .area
+            .positions()
+            .filter(|&position| !self.clip.contains(position))
+            .filter(|&position| self.buffer[position] != UNWRITTEN)
+            .count()
+    }
+
+    /// Copies the cells inside of the clip rect to `buffer`, dropping the other writes.
+    pub fn merge_into(self, buffer: &mut Buffer) {
+        let clip = self.clip.intersection(buffer.area);
+        for position in clip.positions() {
+            buffer[position] = self.buffer[position].clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Style, Stylize};
+
+    #[test]
+    fn new_copies_the_clip_area() {
+        let buffer = Buffer::with_lines(["abc", "def"]);
+        let clip_buffer = ClipBuffer::new(&buffer, Rect::new(1, 1, 5, 5));
+        assert_eq!(clip_buffer.clip(), Rect::new(1, 1, 2, 1));
+        assert_eq!(clip_buffer.dropped_writes(), 0);
+        assert_eq!(clip_buffer.buffer[(1, 1)].symbol(), "e");
+    }
+
+    #[test]
+    fn dropped_writes_counts_cells_outside_of_the_clip() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let mut clip_buffer = ClipBuffer::new(&buffer, Rect::new(1, 1, 2, 1));
+        // writes inside of the clip are not counted
+        clip_buffer
+            .buffer_mut()
+            .set_string(1, 1, "ab", Style::new());
+        assert_eq!(clip_buffer.dropped_writes(), 0);
+
+        clip_buffer
+            .buffer_mut()
+            .set_string(1, 1, "abcd", Style::new());
+        // styling a cell is a write too
+        clip_buffer
+            .buffer_mut()
+            .set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(clip_buffer.dropped_writes(), 3);
+    }
+
+    #[test]
+    fn merge_into_drops_writes_outside_of_the_clip() {
+        let mut buffer = Buffer::with_lines(["xxxxx", "xxxxx"]);
+        let mut clip_buffer = ClipBuffer::new(&buffer, Rect::new(1, 0, 2, 2));
+        clip_buffer
+            .buffer_mut()
+            .set_string(0, 1, "abcde", Style::new());
+        clip_buffer.merge_into(&mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["xxxxx", "xbcxx"]));
+    }
+}
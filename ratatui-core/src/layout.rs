@@ -7,16 +7,18 @@ mod direction;
 mod flex;
 mod layout;
 mod margin;
+mod padding;
 mod position;
 mod rect;
 mod size;
 
 pub use alignment::Alignment;
-pub use constraint::Constraint;
+pub use constraint::{Constraint, ConstraintError, ParseConstraintError};
 pub use direction::Direction;
 pub use flex::Flex;
 pub use layout::{Layout, Spacing};
 pub use margin::Margin;
+pub use padding::Padding;
 pub use position::Position;
 pub use rect::{Columns, Offset, Positions, Rect, Rows};
 pub use size::Size;
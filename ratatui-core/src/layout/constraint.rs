@@ -1,6 +1,7 @@
-use std::fmt;
+use std::{fmt, num::ParseIntError, str::FromStr};
 
 use strum::EnumIs;
+use thiserror::Error;
 
 /// A constraint that defines the size of a layout element.
 ///
@@ -237,6 +238,72 @@ impl Constraint {
         lengths.into_iter().map(Self::Length).collect()
     }
 
+    /// Creates a [`Constraint::Ratio`], checking that the denominator is not zero.
+    ///
+    /// The layout solver treats a denominator of zero as one, so `Ratio(1, 0)` silently takes up
+    /// the entire space. Use this instead of the variant when the values are not known to be
+    /// valid, e.g. when they come from user input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintError::ZeroDenominator`] if `denominator` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, ConstraintError};
+    ///
+    /// assert_eq!(Constraint::ratio(1, 3), Ok(Constraint::Ratio(1, 3)));
+    /// assert_eq!(
+    ///     Constraint::ratio(1, 0),
+    ///     Err(ConstraintError::ZeroDenominator)
+    /// );
+    /// ```
+    pub const fn ratio(numerator: u32, denominator: u32) -> Result<Self, ConstraintError> {
+        if denominator == 0 {
+            return Err(ConstraintError::ZeroDenominator);
+        }
+        Ok(Self::Ratio(numerator, denominator))
+    }
+
+    /// Creates a [`Constraint::Ratio`] with the fraction reduced to its lowest terms.
+    ///
+    /// This is the same as [`Constraint::ratio`], except that the numerator and the denominator are
+    /// divided by their greatest common divisor, so that ratios which take up the same space are
+    /// equal (e.g. `2/4` becomes `1/2`, and `0/5` becomes `0/1`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintError::ZeroDenominator`] if `denominator` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Constraint;
+    ///
+    /// assert_eq!(
+    ///     Constraint::normalized_ratio(2, 4),
+    ///     Ok(Constraint::Ratio(1, 2))
+    /// );
+    /// assert_eq!(
+    ///     Constraint::normalized_ratio(6, 3),
+    ///     Ok(Constraint::Ratio(2, 1))
+    /// );
+    /// ```
+    pub const fn normalized_ratio(
+        numerator: u32,
+        denominator: u32,
+    ) -> Result<Self, ConstraintError> {
+        if denominator == 0 {
+            return Err(ConstraintError::ZeroDenominator);
+        }
+        let (mut a, mut b) = (numerator, denominator);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        Ok(Self::Ratio(numerator / a, denominator / a))
+    }
+
     /// Convert an iterator of ratios into a vector of constraints
     ///
     /// # Examples
@@ -380,8 +447,89 @@ impl fmt::Display for Constraint {
     }
 }
 
+/// Error type indicating a failure to parse a constraint string.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+pub enum ParseConstraintError {
+    /// A value of the constraint is not a valid number.
+    #[error("failed to parse Constraint: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+    /// The values of the constraint are not valid (e.g. the denominator of a ratio is zero).
+    #[error("failed to parse Constraint: {0}")]
+    InvalidConstraint(#[from] ConstraintError),
+    /// The constraint starts with a keyword that is not recognized.
+    #[error("failed to parse Constraint: unknown keyword `{0}`")]
+    UnknownKeyword(String),
+}
+
+/// Error type for creating a [`Constraint`] with invalid values.
+#[derive(Debug, Error, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ConstraintError {
+    /// The denominator of a [`Constraint::Ratio`] is zero.
+    #[error("the denominator of a ratio constraint cannot be zero")]
+    ZeroDenominator,
+}
+
+/// Converts a compact string representation to a `Constraint`.
+///
+/// This is convenient for prototyping and for layouts defined in configuration files. The
+/// following formats are supported (whitespace around the values is ignored, and keywords are case
+/// insensitive):
+///
+/// - `20`: [`Constraint::Length(20)`](Constraint::Length)
+/// - `30%`: [`Constraint::Percentage(30)`](Constraint::Percentage)
+/// - `1/3`: [`Constraint::Ratio(1, 3)`](Constraint::Ratio)
+/// - `2fr`: [`Constraint::Fill(2)`](Constraint::Fill)
+/// - `auto`: [`Constraint::Fill(1)`](Constraint::Fill)
+/// - `min(10)`: [`Constraint::Min(10)`](Constraint::Min)
+/// - `max(10)`: [`Constraint::Max(10)`](Constraint::Max)
+///
+/// Parsing fails with a [`ParseConstraintError`] if a value is not a valid number, if a ratio has
+/// a zero denominator, or if the string starts with an unknown keyword.
+///
+/// See [`Layout`](crate::layout::Layout) for parsing a list of constraints.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::layout::Constraint;
+///
+/// assert_eq!("30%".parse(), Ok(Constraint::Percentage(30)));
+/// assert_eq!("1fr".parse(), Ok(Constraint::Fill(1)));
+/// assert_eq!("min(5)".parse(), Ok(Constraint::Min(5)));
+/// assert!("abc".parse::<Constraint>().is_err());
+/// ```
+impl FromStr for Constraint {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn number<T: FromStr<Err = ParseIntError>>(s: &str) -> Result<T, ParseConstraintError> {
+            Ok(s.trim().parse()?)
+        }
+        let s = s.trim().to_lowercase();
+        if s == "auto" {
+            Ok(Self::Fill(1))
+        } else if let Some(percentage) = s.strip_suffix('%') {
+            number(percentage).map(Self::Percentage)
+        } else if let Some(fill) = s.strip_suffix("fr") {
+            number(fill).map(Self::Fill)
+        } else if let Some((numerator, denominator)) = s.split_once('/') {
+            Ok(Self::ratio(number(numerator)?, number(denominator)?)?)
+        } else if let Some(min) = s.strip_prefix("min(").and_then(|s| s.strip_suffix(')')) {
+            number(min).map(Self::Min)
+        } else if let Some(max) = s.strip_prefix("max(").and_then(|s| s.strip_suffix(')')) {
+            number(max).map(Self::Max)
+        } else if s.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            Err(ParseConstraintError::UnknownKeyword(s))
+        } else {
+            number(&s).map(Self::Length)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -398,6 +546,61 @@ mod tests {
         assert_eq!(Constraint::Min(10).to_string(), "Min(10)");
     }
 
+    #[rstest]
+    #[case::length("20", Constraint::Length(20))]
+    #[case::length_whitespace(" 20 ", Constraint::Length(20))]
+    #[case::percentage("30%", Constraint::Percentage(30))]
+    #[case::percentage_whitespace("30 %", Constraint::Percentage(30))]
+    #[case::ratio("1/3", Constraint::Ratio(1, 3))]
+    #[case::ratio_whitespace("1 / 3", Constraint::Ratio(1, 3))]
+    #[case::fill("2fr", Constraint::Fill(2))]
+    #[case::fill_uppercase("2FR", Constraint::Fill(2))]
+    #[case::auto("auto", Constraint::Fill(1))]
+    #[case::min("min(10)", Constraint::Min(10))]
+    #[case::max("Max( 10 )", Constraint::Max(10))]
+    fn from_str(#[case] input: &str, #[case] expected: Constraint) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::negative("-1")]
+    #[case::overflow("70000")]
+    #[case::float("0.5")]
+    #[case::percentage_without_value("%")]
+    #[case::double_percentage("30%%")]
+    #[case::fill_without_value("fr")]
+    #[case::ratio_without_denominator("1/")]
+    #[case::ratio_invalid_numerator_zero_denominator("abc/0")]
+    fn from_str_invalid_number(#[case] input: &str) {
+        assert!(matches!(
+            input.parse::<Constraint>(),
+            Err(ParseConstraintError::InvalidNumber(_))
+        ));
+    }
+
+    #[test]
+    fn from_str_zero_denominator() {
+        assert_eq!(
+            "1/0".parse::<Constraint>(),
+            Err(ParseConstraintError::InvalidConstraint(
+                ConstraintError::ZeroDenominator
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::unknown("foo", "foo")]
+    #[case::uppercase("FOO", "foo")]
+    #[case::min_without_parens("min 10", "min 10")]
+    #[case::max_unclosed("max(10", "max(10")]
+    fn from_str_unknown_keyword(#[case] input: &str, #[case] keyword: &str) {
+        assert_eq!(
+            input.parse::<Constraint>(),
+            Err(ParseConstraintError::UnknownKeyword(keyword.into()))
+        );
+    }
+
     #[test]
     fn from_lengths() {
         let expected = [
@@ -409,6 +612,51 @@ mod tests {
         assert_eq!(Constraint::from_lengths(vec![1, 2, 3]), expected);
     }
 
+    #[test]
+    fn ratio() {
+        assert_eq!(Constraint::ratio(1, 3), Ok(Constraint::Ratio(1, 3)));
+        assert_eq!(Constraint::ratio(0, 1), Ok(Constraint::Ratio(0, 1)));
+        assert_eq!(Constraint::ratio(2, 4), Ok(Constraint::Ratio(2, 4)));
+        assert_eq!(
+            Constraint::ratio(1, 0),
+            Err(ConstraintError::ZeroDenominator)
+        );
+        assert_eq!(
+            Constraint::ratio(0, 0),
+            Err(ConstraintError::ZeroDenominator)
+        );
+    }
+
+    #[rstest]
+    #[case::already_reduced(1, 3, Constraint::Ratio(1, 3))]
+    #[case::reduced(2, 4, Constraint::Ratio(1, 2))]
+    #[case::whole(6, 3, Constraint::Ratio(2, 1))]
+    #[case::equal(5, 5, Constraint::Ratio(1, 1))]
+    #[case::zero(0, 5, Constraint::Ratio(0, 1))]
+    #[case::max(u32::MAX, u32::MAX, Constraint::Ratio(1, 1))]
+    fn normalized_ratio(
+        #[case] numerator: u32,
+        #[case] denominator: u32,
+        #[case] expected: Constraint,
+    ) {
+        assert_eq!(
+            Constraint::normalized_ratio(numerator, denominator),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn normalized_ratio_zero_denominator() {
+        assert_eq!(
+            Constraint::normalized_ratio(1, 0),
+            Err(ConstraintError::ZeroDenominator)
+        );
+        assert_eq!(
+            ConstraintError::ZeroDenominator.to_string(),
+            "the denominator of a ratio constraint cannot be zero"
+        );
+    }
+
     #[test]
     fn from_ratios() {
         let expected = [
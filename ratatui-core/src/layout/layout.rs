@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::HashMap, iter, num::NonZeroUsize, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, iter, num::NonZeroUsize, rc::Rc, str::FromStr};
 
 use cassowary::{
     strength::REQUIRED,
@@ -9,10 +9,11 @@ use itertools::Itertools;
 use lru::LruCache;
 
 use self::strengths::{
-    ALL_SEGMENT_GROW, FILL_GROW, GROW, LENGTH_SIZE_EQ, MAX_SIZE_EQ, MAX_SIZE_LE, MIN_SIZE_EQ,
-    MIN_SIZE_GE, PERCENTAGE_SIZE_EQ, RATIO_SIZE_EQ, SPACER_SIZE_EQ, SPACE_GROW,
+    ALL_SEGMENT_GROW, FILL_GROW, GROW, LENGTH_SHRINK_PRIORITY, LENGTH_SIZE_EQ, MAX_SIZE_EQ,
+    MAX_SIZE_LE, MIN_SIZE_EQ, MIN_SIZE_GE, PERCENTAGE_SIZE_EQ, RATIO_SIZE_EQ, SPACER_SIZE_EQ,
+    SPACE_GROW,
 };
-use crate::layout::{Constraint, Direction, Flex, Margin, Rect};
+use crate::layout::{Constraint, Direction, Flex, Margin, ParseConstraintError, Rect};
 
 type Rects = Rc<[Rect]>;
 type Segments = Rects;
@@ -144,6 +145,9 @@ impl From<i16> for Spacing {
 /// - [`Layout::vertical_margin`]: set the vertical margin of the layout
 /// - [`Layout::flex`]: set the way the space is distributed when the constraints are satisfied
 /// - [`Layout::spacing`]: sets the gap between the constraints of the layout
+/// - [`Layout::spacings`]: sets the gap after each constraint of the layout
+/// - [`Layout::shrink_priorities`]: sets which [`Constraint::Length`] shrinks first when there is
+///   not enough space
 ///
 /// # Example
 ///
@@ -178,6 +182,8 @@ pub struct Layout {
     margin: Margin,
     flex: Flex,
     spacing: Spacing,
+    spacings: Vec<u16>,
+    shrink_priorities: Vec<u16>,
 }
 
 impl Layout {
@@ -273,6 +279,46 @@ impl Layout {
         )
     }
 
+    /// Creates a vertical layout with a header and a footer of fixed heights around a body that
+    /// takes up the remaining space.
+    ///
+    /// This is a shortcut for `Layout::vertical([Length(header), Min(0), Length(footer)])`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 80, 24);
+    /// let [header, body, footer] = Layout::header_body_footer(3, 1).areas(area);
+    /// assert_eq!(body, Rect::new(0, 3, 80, 20));
+    /// ```
+    pub fn header_body_footer(header: u16, footer: u16) -> Self {
+        Self::vertical([
+            Constraint::Length(header),
+            Constraint::Min(0),
+            Constraint::Length(footer),
+        ])
+    }
+
+    /// Creates a horizontal layout with a sidebar of a fixed width next to a main area that takes
+    /// up the remaining space.
+    ///
+    /// This is a shortcut for `Layout::horizontal([Length(width), Min(0)])`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 80, 24);
+    /// let [sidebar, main] = Layout::sidebar(20).areas(area);
+    /// assert_eq!(sidebar, Rect::new(0, 0, 20, 24));
+    /// ```
+    pub fn sidebar(width: u16) -> Self {
+        Self::horizontal([Constraint::Length(width), Constraint::Min(0)])
+    }
+
     /// Initialize an empty cache with a custom size. The cache is keyed on the layout and area, so
     /// that subsequent calls with the same parameters are faster. The cache is a `LruCache`, and
     /// grows until `cache_size` is reached.
@@ -282,6 +328,26 @@ impl Layout {
         LAYOUT_CACHE.with_borrow_mut(|c| c.resize(cache_size));
     }
 
+    /// Removes all the cached layouts of the current thread, keeping the size of the cache.
+    ///
+    /// The layout cache is thread local and lives as long as the thread, so layouts computed by a
+    /// test are still cached when the next test runs on the same thread. Calling this at the
+    /// start of a test makes its results independent of the tests that ran before it, which is
+    /// useful when checking how the cache behaves or when a test depends on which layouts are
+    /// evicted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// Layout::clear_cache();
+    /// let areas = Layout::vertical([Constraint::Fill(1); 2]).split(Rect::new(0, 0, 10, 10));
+    /// ```
+    pub fn clear_cache() {
+        LAYOUT_CACHE.with_borrow_mut(|c| *c = Cache::new(c.cap()));
+    }
+
     /// Set the direction of the layout.
     ///
     /// # Examples
@@ -498,6 +564,69 @@ impl Layout {
         self
     }
 
+    /// Sets the spacing of each gap between the items in the layout.
+    ///
+    /// The spacing at index `i` is the number of cells between the segment `i` and the segment
+    /// `i + 1`. When fewer spacings than gaps are provided, the last spacing is repeated for the
+    /// remaining gaps. This is useful for grouping items, e.g. the buttons of a toolbar.
+    ///
+    /// When set, this takes precedence over the uniform [`Layout::spacing`] between the segments.
+    /// Passing an empty list falls back to the uniform spacing. As with [`Layout::spacing`], the
+    /// spacings will not be applied for [`Flex::SpaceAround`] and [`Flex::SpaceBetween`] if there
+    /// is excess space.
+    ///
+    /// # Examples
+    ///
+    /// In this example, the first two buttons are grouped together and separated from the last two
+    /// buttons by a gap of 3 cells.
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let layout = Layout::horizontal([Length(5); 4]).spacings([1, 3, 1]);
+    /// let [_, second, third, _] = layout.areas(Rect::new(0, 0, 30, 1));
+    /// assert_eq!(second.x, 6);
+    /// assert_eq!(third.x, 14);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacings<I>(mut self, spacings: I) -> Self
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        self.spacings = spacings.into_iter().collect();
+        self
+    }
+
+    /// Sets the shrink priority of each constraint in the layout.
+    ///
+    /// When the area is too small to satisfy all the [`Constraint::Length`] constraints, the
+    /// lengths with a lower shrink priority give up their space before the lengths with a higher
+    /// shrink priority. The priorities are matched to the constraints by index, and constraints
+    /// without a matching priority have a priority of 0. Priorities only affect
+    /// [`Constraint::Length`] constraints.
+    ///
+    /// # Examples
+    ///
+    /// In this example, there is not enough space for both lengths, so the first one shrinks as it
+    /// has the lower priority.
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let layout = Layout::horizontal([Length(50), Length(50)]).shrink_priorities([1, 2]);
+    /// let [left, right] = layout.areas(Rect::new(0, 0, 60, 1));
+    /// assert_eq!(left.width, 10);
+    /// assert_eq!(right.width, 50);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shrink_priorities<I>(mut self, priorities: I) -> Self
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        self.shrink_priorities = priorities.into_iter().collect();
+        self
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -654,6 +783,44 @@ impl Layout {
         })
     }
 
+    /// Splits the given area into a grid of `rows` by `columns` cells of equal size.
+    ///
+    /// The area is first split vertically into rows, then each row is split horizontally into
+    /// columns. The result is indexed by row, then by column. `spacing` is applied between both the
+    /// rows and the columns and accepts the same values as [`Layout::spacing`], so a negative
+    /// spacing can be used to make the borders of adjacent cells overlap.
+    ///
+    /// This is a shortcut for two nested calls to [`Layout::split`] with [`Constraint::Fill`]
+    /// constraints. If the area can't be divided evenly, the extra space is distributed in the same
+    /// way as in [`Layout::split`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 32, 11);
+    /// let grid = Layout::grid(area, 2, 3, 1);
+    /// assert_eq!(grid[0][0], Rect::new(0, 0, 10, 5));
+    /// assert_eq!(grid[1][2], Rect::new(22, 6, 10, 5));
+    /// ```
+    pub fn grid<T>(area: Rect, rows: u16, columns: u16, spacing: T) -> Vec<Vec<Rect>>
+    where
+        T: Into<Spacing>,
+    {
+        let spacing = spacing.into();
+        let row_layout = Self::vertical(iter::repeat(Constraint::Fill(1)).take(rows.into()))
+            .spacing(spacing.clone());
+        let column_layout =
+            Self::horizontal(iter::repeat(Constraint::Fill(1)).take(columns.into()))
+                .spacing(spacing);
+        row_layout
+            .split(area)
+            .iter()
+            .map(|&row| column_layout.split(row).to_vec())
+            .collect()
+    }
+
     fn try_split(&self, area: Rect) -> Result<(Segments, Spacers), AddConstraintError> {
         // To take advantage of all of cassowary features, we would want to store the `Solver` in
         // one of the fields of the Layout struct. And we would want to set it up such that we could
@@ -727,9 +894,20 @@ impl Layout {
         let flex = self.flex;
 
         let spacing = match self.spacing {
-            Spacing::Space(x) => x as i16,
-            Spacing::Overlap(x) => -(x as i16),
+            Spacing::Space(x) => i16::try_from(x).unwrap_or(i16::MAX),
+            Spacing::Overlap(x) => -i16::try_from(x).unwrap_or(i16::MAX),
         };
+        // the spacing of each gap between the segments, falling back to the uniform spacing
+        let spacings = (0..self.constraints.len().saturating_sub(1))
+            .map(|index| {
+                self.spacings
+                    .get(index)
+                    .or_else(|| self.spacings.last())
+                    .map_or(spacing, |&spacing| {
+                        i16::try_from(spacing).unwrap_or(i16::MAX)
+                    })
+            })
+            .collect_vec();
 
         let constraints = &self.constraints;
 
@@ -737,8 +915,15 @@ impl Layout {
         configure_area(&mut solver, area_size, area_start, area_end)?;
         configure_variable_in_area_constraints(&mut solver, &variables, area_size)?;
         configure_variable_constraints(&mut solver, &variables)?;
-        configure_flex_constraints(&mut solver, area_size, &spacers, flex, spacing)?;
-        configure_constraints(&mut solver, area_size, &segments, constraints, flex)?;
+        configure_flex_constraints(&mut solver, area_size, &spacers, flex, spacing, &spacings)?;
+        configure_constraints(
+            &mut solver,
+            area_size,
+            &segments,
+            constraints,
+            &self.shrink_priorities,
+            flex,
+        )?;
         configure_fill_constraints(&mut solver, &segments, constraints, flex)?;
 
         if !flex.is_legacy() {
@@ -759,6 +944,49 @@ impl Layout {
     }
 }
 
+/// Creates a vertical [`Layout`] from a comma separated list of constraints.
+///
+/// Each constraint is parsed using the compact format described in the [`FromStr`] implementation
+/// of [`Constraint`], e.g. `"30%, 1fr, 20"` is parsed as `[Percentage(30), Fill(1), Length(20)]`.
+/// An empty string results in a layout without constraints. The other properties of the layout
+/// can be set afterwards using the usual setters.
+///
+/// This is convenient for prototyping and for layouts defined in configuration files.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::layout::{Constraint, Direction, Layout};
+///
+/// let layout: Layout = "30%, 1fr, 20".parse()?;
+/// let layout = layout.direction(Direction::Horizontal).spacing(1);
+/// assert_eq!(
+///     layout,
+///     Layout::horizontal([
+///         Constraint::Percentage(30),
+///         Constraint::Fill(1),
+///         Constraint::Length(20)
+///     ])
+///     .spacing(1)
+/// );
+/// assert!("30%, , 20".parse::<Layout>().is_err());
+/// # Ok::<(), ratatui_core::layout::ParseConstraintError>(())
+/// ```
+impl FromStr for Layout {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        let constraints = s
+            .split(',')
+            .map(str::parse::<Constraint>)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::vertical(constraints))
+    }
+}
+
 fn configure_area(
     solver: &mut Solver,
     area: Element,
@@ -808,9 +1036,10 @@ fn configure_constraints(
     area: Element,
     segments: &[Element],
     constraints: &[Constraint],
+    shrink_priorities: &[u16],
     flex: Flex,
 ) -> Result<(), AddConstraintError> {
-    for (&constraint, &segment) in constraints.iter().zip(segments.iter()) {
+    for (index, (&constraint, &segment)) in constraints.iter().zip(segments.iter()).enumerate() {
         match constraint {
             Constraint::Max(max) => {
                 solver.add_constraint(segment.has_max_size(max, MAX_SIZE_LE))?;
@@ -825,7 +1054,9 @@ fn configure_constraints(
                 }
             }
             Constraint::Length(length) => {
-                solver.add_constraint(segment.has_int_size(length, LENGTH_SIZE_EQ))?;
+                let priority = shrink_priorities.get(index).copied().unwrap_or_default();
+                let strength = LENGTH_SIZE_EQ + f64::from(priority) * LENGTH_SHRINK_PRIORITY;
+                solver.add_constraint(segment.has_int_size(length, strength))?;
             }
             Constraint::Percentage(p) => {
                 let size = area.size() * f64::from(p) / 100.00;
@@ -851,12 +1082,17 @@ fn configure_flex_constraints(
     spacers: &[Element],
     flex: Flex,
     spacing: i16,
+    spacings: &[i16],
 ) -> Result<(), AddConstraintError> {
     let spacers_except_first_and_last = spacers.get(1..spacers.len() - 1).unwrap_or(&[]);
-    let spacing_f64 = f64::from(spacing) * FLOAT_PRECISION_MULTIPLIER;
+    // each spacer between the segments paired with the spacing of its gap
+    let gaps = spacers_except_first_and_last
+        .iter()
+        .zip(spacings.iter().copied());
     match flex {
         Flex::Legacy => {
-            for spacer in spacers_except_first_and_last {
+            for (spacer, spacing) in gaps {
+                let spacing_f64 = f64::from(spacing) * FLOAT_PRECISION_MULTIPLIER;
                 solver.add_constraint(spacer.has_size(spacing_f64, SPACER_SIZE_EQ))?;
             }
             if let (Some(first), Some(last)) = (spacers.first(), spacers.last()) {
@@ -870,7 +1106,13 @@ fn configure_flex_constraints(
             for (left, right) in spacers.iter().tuple_combinations() {
                 solver.add_constraint(left.has_size(right, SPACER_SIZE_EQ))?;
             }
-            for spacer in spacers {
+            for (index, spacer) in spacers.iter().enumerate() {
+                // the first and last spacers are not between segments and use the uniform spacing
+                let spacing = index
+                    .checked_sub(1)
+                    .and_then(|index| spacings.get(index))
+                    .copied()
+                    .unwrap_or(spacing);
                 solver.add_constraint(spacer.has_min_size(spacing, SPACER_SIZE_EQ))?;
                 solver.add_constraint(spacer.has_size(area, SPACE_GROW))?;
             }
@@ -882,7 +1124,7 @@ fn configure_flex_constraints(
             for (left, right) in spacers_except_first_and_last.iter().tuple_combinations() {
                 solver.add_constraint(left.has_size(right.size(), SPACER_SIZE_EQ))?;
             }
-            for spacer in spacers_except_first_and_last {
+            for (spacer, spacing) in gaps {
                 solver.add_constraint(spacer.has_min_size(spacing, SPACER_SIZE_EQ))?;
                 solver.add_constraint(spacer.has_size(area, SPACE_GROW))?;
             }
@@ -892,7 +1134,8 @@ fn configure_flex_constraints(
             }
         }
         Flex::Start => {
-            for spacer in spacers_except_first_and_last {
+            for (spacer, spacing) in gaps {
+                let spacing_f64 = f64::from(spacing) * FLOAT_PRECISION_MULTIPLIER;
                 solver.add_constraint(spacer.has_size(spacing_f64, SPACER_SIZE_EQ))?;
             }
             if let (Some(first), Some(last)) = (spacers.first(), spacers.last()) {
@@ -901,7 +1144,8 @@ fn configure_flex_constraints(
             }
         }
         Flex::Center => {
-            for spacer in spacers_except_first_and_last {
+            for (spacer, spacing) in gaps {
+                let spacing_f64 = f64::from(spacing) * FLOAT_PRECISION_MULTIPLIER;
                 solver.add_constraint(spacer.has_size(spacing_f64, SPACER_SIZE_EQ))?;
             }
             if let (Some(first), Some(last)) = (spacers.first(), spacers.last()) {
@@ -911,7 +1155,8 @@ fn configure_flex_constraints(
             }
         }
         Flex::End => {
-            for spacer in spacers_except_first_and_last {
+            for (spacer, spacing) in gaps {
+                let spacing_f64 = f64::from(spacing) * FLOAT_PRECISION_MULTIPLIER;
                 solver.add_constraint(spacer.has_size(spacing_f64, SPACER_SIZE_EQ))?;
             }
             if let (Some(first), Some(last)) = (spacers.first(), spacers.last()) {
@@ -1110,6 +1355,15 @@ mod strengths {
     /// └───────────┘
     pub const LENGTH_SIZE_EQ: f64 = STRONG * 10.0;
 
+    /// The strength added to Length constraints for each level of shrink priority.
+    ///
+    /// This is small enough that a Length with the maximum priority is still weaker than Max.
+    ///
+    /// ┌──────────────┐┌───────────────┐
+    /// │Length(==x) +1││Length(==x) +2 │
+    /// └──────────────┘└───────────────┘
+    pub const LENGTH_SHRINK_PRIORITY: f64 = STRONG / 1000.0;
+
     /// The strength to apply to Percentage constraints.
     ///
     /// ┌───────────────┐
@@ -1181,6 +1435,7 @@ mod tests {
         assert!(MAX_SIZE_LE > MAX_SIZE_EQ);
         assert!(MIN_SIZE_GE == MAX_SIZE_LE);
         assert!(MAX_SIZE_LE > LENGTH_SIZE_EQ);
+        assert!(MAX_SIZE_LE > LENGTH_SIZE_EQ + f64::from(u16::MAX) * LENGTH_SHRINK_PRIORITY);
         assert!(LENGTH_SIZE_EQ > PERCENTAGE_SIZE_EQ);
         assert!(PERCENTAGE_SIZE_EQ > RATIO_SIZE_EQ);
         assert!(RATIO_SIZE_EQ > MAX_SIZE_EQ);
@@ -1202,6 +1457,20 @@ mod tests {
         });
     }
 
+    #[test]
+    fn clear_cache() {
+        Layout::init_cache(NonZeroUsize::new(10).unwrap());
+        Layout::clear_cache();
+        let _ = Layout::vertical([Constraint::Min(1)]).split(Rect::new(0, 0, 1, 1));
+        LAYOUT_CACHE.with_borrow(|c| assert_eq!(c.len(), 1));
+
+        Layout::clear_cache();
+        LAYOUT_CACHE.with_borrow(|c| {
+            assert!(c.is_empty());
+            assert_eq!(c.cap().get(), 10);
+        });
+    }
+
     #[test]
     fn default() {
         assert_eq!(
@@ -1212,6 +1481,8 @@ mod tests {
                 constraints: vec![],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                spacings: vec![],
+                shrink_priorities: vec![],
             }
         );
     }
@@ -1257,6 +1528,8 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                spacings: vec![],
+                shrink_priorities: vec![],
             }
         );
     }
@@ -1271,10 +1544,101 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                spacings: vec![],
+                shrink_priorities: vec![],
             }
         );
     }
 
+    #[test]
+    fn header_body_footer() {
+        let layout = Layout::header_body_footer(3, 1);
+        assert_eq!(
+            layout,
+            Layout::vertical([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1)
+            ])
+        );
+        let [header, body, footer] = layout.areas(Rect::new(0, 0, 10, 10));
+        assert_eq!(header, Rect::new(0, 0, 10, 3));
+        assert_eq!(body, Rect::new(0, 3, 10, 6));
+        assert_eq!(footer, Rect::new(0, 9, 10, 1));
+    }
+
+    #[test]
+    fn sidebar() {
+        let layout = Layout::sidebar(4);
+        assert_eq!(
+            layout,
+            Layout::horizontal([Constraint::Length(4), Constraint::Min(0)])
+        );
+        let [sidebar, main] = layout.areas(Rect::new(0, 0, 10, 10));
+        assert_eq!(sidebar, Rect::new(0, 0, 4, 10));
+        assert_eq!(main, Rect::new(4, 0, 6, 10));
+    }
+
+    #[test]
+    fn grid() {
+        let grid = Layout::grid(Rect::new(0, 0, 30, 10), 2, 3, 0);
+        assert_eq!(
+            grid,
+            [
+                [
+                    Rect::new(0, 0, 10, 5),
+                    Rect::new(10, 0, 10, 5),
+                    Rect::new(20, 0, 10, 5),
+                ],
+                [
+                    Rect::new(0, 5, 10, 5),
+                    Rect::new(10, 5, 10, 5),
+                    Rect::new(20, 5, 10, 5),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_with_spacing() {
+        let grid = Layout::grid(Rect::new(0, 0, 32, 11), 2, 3, 1);
+        assert_eq!(
+            grid,
+            [
+                [
+                    Rect::new(0, 0, 10, 5),
+                    Rect::new(11, 0, 10, 5),
+                    Rect::new(22, 0, 10, 5),
+                ],
+                [
+                    Rect::new(0, 6, 10, 5),
+                    Rect::new(11, 6, 10, 5),
+                    Rect::new(22, 6, 10, 5),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_with_overlap() {
+        let grid = Layout::grid(Rect::new(0, 0, 7, 3), 1, 3, -1);
+        assert_eq!(
+            grid,
+            [[
+                Rect::new(0, 0, 3, 3),
+                Rect::new(2, 0, 3, 3),
+                Rect::new(4, 0, 3, 3),
+            ]]
+        );
+    }
+
+    #[test]
+    fn grid_empty() {
+        let area = Rect::new(0, 0, 10, 10);
+        assert!(Layout::grid(area, 0, 3, 0).is_empty());
+        assert_eq!(Layout::grid(area, 2, 0, 0), vec![Vec::<Rect>::new(); 2]);
+    }
+
     /// The purpose of this test is to ensure that layout can be constructed with any type that
     /// implements `IntoIterator<Item = AsRef<Constraint>>`.
     #[test]
@@ -1382,6 +1746,49 @@ mod tests {
         assert_eq!(Layout::default().spacing(-10).spacing, Spacing::Overlap(10));
     }
 
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "30%, 1fr, 20, 1/3, auto, min(5), max(10)".parse(),
+            Ok(Layout::vertical([
+                Constraint::Percentage(30),
+                Constraint::Fill(1),
+                Constraint::Length(20),
+                Constraint::Ratio(1, 3),
+                Constraint::Fill(1),
+                Constraint::Min(5),
+                Constraint::Max(10),
+            ]))
+        );
+        assert_eq!(" ".parse(), Ok(Layout::default()));
+    }
+
+    #[test]
+    fn from_str_error() {
+        for input in ["20, , 30", "20,", "20 30"] {
+            assert!(
+                matches!(
+                    input.parse::<Layout>(),
+                    Err(ParseConstraintError::InvalidNumber(_))
+                ),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            "20, abc".parse::<Layout>(),
+            Err(ParseConstraintError::UnknownKeyword("abc".into()))
+        );
+    }
+
+    #[test]
+    fn spacings() {
+        assert_eq!(Layout::default().spacings([1, 2]).spacings, [1, 2]);
+        assert_eq!(
+            Layout::default().spacings(vec![]).spacings,
+            Vec::<u16>::new()
+        );
+    }
+
     /// Tests for the `Layout::split()` function.
     ///
     /// There are many tests in this as the number of edge cases that are caused by the interaction
@@ -2114,6 +2521,24 @@ mod tests {
             assert_eq!(ranges, expected);
         }
 
+        #[rstest]
+        #[case::first_lower(vec![1, 2], vec![10, 50])]
+        #[case::second_lower(vec![2, 1], vec![50, 10])]
+        #[case::missing_priority(vec![1], vec![50, 10])]
+        fn length_shrink_priorities(#[case] priorities: Vec<u16>, #[case] expected: Vec<u16>) {
+            let rect = Rect::new(0, 0, 60, 1);
+            for flex in [Flex::Legacy, Flex::Start, Flex::End, Flex::Center] {
+                let widths = Layout::horizontal([Length(50), Length(50)])
+                    .shrink_priorities(priorities.iter().copied())
+                    .flex(flex)
+                    .split(rect)
+                    .iter()
+                    .map(|r| r.width)
+                    .collect_vec();
+                assert_eq!(widths, expected, "flex: {flex:?}");
+            }
+        }
+
         #[rstest]
         #[case::min_len_max(vec![Min(25), Length(25), Max(25)], vec![50, 25, 25])]
         #[case::max_len_min(vec![Max(25), Length(25), Min(25)], vec![25, 25, 50])]
@@ -2365,6 +2790,46 @@ mod tests {
             assert_eq!(result, expected);
         }
 
+        #[rstest]
+        #[case::start(vec![(0 , 10), (12, 10), (26, 10), (40, 10)], Flex::Start       , vec![2, 4])]
+        #[case::center(vec![(25, 10), (37, 10), (51, 10), (65, 10)], Flex::Center      , vec![2, 4])]
+        #[case::end(vec![(50, 10), (62, 10), (76, 10), (90, 10)], Flex::End         , vec![2, 4])]
+        #[case::legacy(vec![(0 , 10), (12, 10), (26, 10), (40, 60)], Flex::Legacy      , vec![2, 4])]
+        #[case::between(vec![(0 , 10), (30, 10), (60, 10), (90, 10)], Flex::SpaceBetween, vec![2, 4])]
+        #[case::all_gaps(vec![(0 , 10), (12, 10), (26, 10), (37, 10)], Flex::Start       , vec![2, 4, 1])]
+        #[case::extra(vec![(0 , 10), (12, 10), (26, 10), (37, 10)], Flex::Start       , vec![2, 4, 1, 8])]
+        #[case::empty(vec![(0 , 10), (11, 10), (22, 10), (33, 10)], Flex::Start       , vec![])]
+        fn flex_spacings(
+            #[case] expected: Vec<(u16, u16)>,
+            #[case] flex: Flex,
+            #[case] spacings: Vec<u16>,
+        ) {
+            let rect = Rect::new(0, 0, 100, 1);
+            let r = Layout::horizontal([Length(10); 4])
+                .flex(flex)
+                .spacing(1)
+                .spacings(spacings)
+                .split(rect);
+            let result = r
+                .iter()
+                .map(|r| (r.x, r.width))
+                .collect::<Vec<(u16, u16)>>();
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn large_spacings_do_not_overlap() {
+            let rect = Rect::new(0, 0, 100, 1);
+            let [first, second] = Layout::horizontal([Length(10); 2])
+                .spacings([40_000])
+                .areas(rect);
+            assert!(second.x >= first.right(), "{first:?} {second:?}");
+            let [first, second] = Layout::horizontal([Length(10); 2])
+                .spacing(40_000)
+                .areas(rect);
+            assert!(second.x >= first.right(), "{first:?} {second:?}");
+        }
+
         #[rstest]
         #[case::a(vec![(0, 25), (25, 75)], vec![Length(25), Length(25)])]
         #[case::b(vec![(0, 25), (25, 75)], vec![Length(25), Percentage(25)])]
@@ -1,6 +1,7 @@
-/// Defines the padding for a [`Block`].
+/// Defines the padding for a `Block`, or the per-side insets of a [`Rect`].
 ///
-/// See the [`padding`] method of [`Block`] to configure its padding.
+/// See the [`padding`] method of [`Block`] to configure its padding, and [`Rect::inset`] to shrink
+/// a `Rect` by a `Padding`.
 ///
 /// This concept is similar to [CSS padding].
 ///
@@ -10,7 +11,7 @@
 /// # Example
 ///
 /// ```
-/// use ratatui::widgets::Padding;
+/// use ratatui_core::layout::Padding;
 ///
 /// Padding::uniform(1);
 /// Padding::horizontal(2);
@@ -19,8 +20,10 @@
 /// Padding::symmetric(5, 6);
 /// ```
 ///
-/// [`Block`]: crate::block::Block
-/// [`padding`]: crate::block::Block::padding
+/// [`Block`]: https://docs.rs/ratatui/latest/ratatui/widgets/struct.Block.html
+/// [`padding`]: https://docs.rs/ratatui/latest/ratatui/widgets/struct.Block.html#method.padding
+/// [`Rect`]: crate::layout::Rect
+/// [`Rect::inset`]: crate::layout::Rect::inset
 /// [CSS padding]: https://developer.mozilla.org/en-US/docs/Web/CSS/padding
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Padding {
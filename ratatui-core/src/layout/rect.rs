@@ -4,7 +4,7 @@ use std::{
     fmt,
 };
 
-use crate::layout::{Margin, Position, Size};
+use crate::layout::{Margin, Padding, Position, Size};
 
 mod iter;
 pub use iter::*;
@@ -50,6 +50,18 @@ impl Offset {
     }
 }
 
+impl std::ops::Neg for Offset {
+    type Output = Self;
+
+    /// Returns the opposite offset, saturating at [`i32::MAX`].
+    fn neg(self) -> Self {
+        Self {
+            x: self.x.saturating_neg(),
+            y: self.y.saturating_neg(),
+        }
+    }
+}
+
 impl fmt::Display for Rect {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}x{}+{}+{}", self.width, self.height, self.x, self.y)
@@ -155,6 +167,68 @@ impl Rect {
         }
     }
 
+    /// Returns a new `Rect` inside the current one, with the given padding on each side.
+    ///
+    /// Unlike [`Rect::inner`], each side is inset independently, which is useful for asymmetric
+    /// gutters. If the padding is larger than the `Rect`, the returned `Rect` will have no area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Padding, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(area.inset(Padding::new(1, 2, 3, 4)), Rect::new(1, 3, 7, 3));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn inset(self, padding: Padding) -> Self {
+        Self {
+            x: self.x.saturating_add(padding.left),
+            y: self.y.saturating_add(padding.top),
+            width: self
+                .width
+                .saturating_sub(padding.left.saturating_add(padding.right)),
+            height: self
+                .height
+                .saturating_sub(padding.top.saturating_add(padding.bottom)),
+        }
+    }
+
+    /// Returns a new `Rect` of the given size, centered inside the current one.
+    ///
+    /// The size is clamped to the size of the current `Rect`. When the remaining space can't be
+    /// split evenly, the extra column or row is put after the centered `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Rect, Size};
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(area.centered(Size::new(4, 2)), Rect::new(3, 4, 4, 2));
+    /// assert_eq!(area.centered(Size::new(20, 20)), area);
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn centered(self, size: Size) -> Self {
+        // these calculations avoid using min so that this function can be const
+        let width = if size.width < self.width {
+            size.width
+        } else {
+            self.width
+        };
+        let height = if size.height < self.height {
+            size.height
+        } else {
+            self.height
+        };
+        Self {
+            x: self.x + (self.width - width) / 2,
+            y: self.y + (self.height - height) / 2,
+            width,
+            height,
+        }
+    }
+
     /// Moves the `Rect` without modifying its size.
     ///
     /// Moves the `Rect` according to the given offset without modifying its [`width`](Rect::width)
@@ -162,7 +236,21 @@ impl Rect {
     /// - Positive `x` moves the whole `Rect` to the right, negative to the left.
     /// - Positive `y` moves the whole `Rect` to the bottom, negative to the top.
     ///
+    /// The position saturates so that the whole `Rect` stays within `0..=u16::MAX`. The `+` and
+    /// `-` operators can also be used to offset a `Rect`.
+    ///
     /// See [`Offset`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Offset, Rect};
+    ///
+    /// let rect = Rect::new(5, 5, 10, 10);
+    /// assert_eq!(rect.offset(Offset::new(2, -3)), Rect::new(7, 2, 10, 10));
+    /// assert_eq!(rect + Offset::new(2, -3), Rect::new(7, 2, 10, 10));
+    /// assert_eq!(rect - Offset::new(10, 10), Rect::new(0, 0, 10, 10));
+    /// ```
     #[must_use = "method returns the modified value"]
     pub fn offset(self, offset: Offset) -> Self {
         Self {
@@ -270,6 +358,9 @@ impl Rect {
 
     /// An iterator over rows within the `Rect`.
     ///
+    /// Each row is a `Rect` with the full width of this `Rect` and a height of 1, returned from top
+    /// to bottom. See [`Rect::positions`] for iterating over the individual cells.
+    ///
     /// # Example
     ///
     /// ```
@@ -287,6 +378,9 @@ impl Rect {
 
     /// An iterator over columns within the `Rect`.
     ///
+    /// Each column is a `Rect` with the full height of this `Rect` and a width of 1, returned from
+    /// left to right. See [`Rect::positions`] for iterating over the individual cells.
+    ///
     /// # Example
     ///
     /// ```
@@ -359,6 +453,36 @@ impl Rect {
     }
 }
 
+impl std::ops::Add<Offset> for Rect {
+    type Output = Self;
+
+    /// Moves the `Rect` by the given offset. See [`Rect::offset`].
+    fn add(self, offset: Offset) -> Self {
+        self.offset(offset)
+    }
+}
+
+impl std::ops::Sub<Offset> for Rect {
+    type Output = Self;
+
+    /// Moves the `Rect` by the opposite of the given offset. See [`Rect::offset`].
+    fn sub(self, offset: Offset) -> Self {
+        self.offset(-offset)
+    }
+}
+
+impl std::ops::AddAssign<Offset> for Rect {
+    fn add_assign(&mut self, offset: Offset) {
+        *self = self.offset(offset);
+    }
+}
+
+impl std::ops::SubAssign<Offset> for Rect {
+    fn sub_assign(&mut self, offset: Offset) {
+        *self = self.offset(-offset);
+    }
+}
+
 impl From<(Position, Size)> for Rect {
     fn from((position, size): (Position, Size)) -> Self {
         Self {
@@ -435,6 +559,29 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::zero(Padding::ZERO, Rect::new(1, 2, 10, 10))]
+    #[case::left(Padding::left(3), Rect::new(4, 2, 7, 10))]
+    #[case::right(Padding::right(3), Rect::new(1, 2, 7, 10))]
+    #[case::top(Padding::top(3), Rect::new(1, 5, 10, 7))]
+    #[case::bottom(Padding::bottom(3), Rect::new(1, 2, 10, 7))]
+    #[case::all(Padding::new(1, 2, 3, 4), Rect::new(2, 5, 7, 3))]
+    #[case::larger_than_rect(Padding::uniform(6), Rect::new(7, 8, 0, 0))]
+    #[case::overflow(Padding::horizontal(u16::MAX), Rect::new(u16::MAX, 2, 0, 10))]
+    fn inset(#[case] padding: Padding, #[case] expected: Rect) {
+        assert_eq!(Rect::new(1, 2, 10, 10).inset(padding), expected);
+    }
+
+    #[rstest]
+    #[case::smaller(Rect::new(1, 2, 10, 10), Size::new(4, 2), Rect::new(4, 6, 4, 2))]
+    #[case::uneven(Rect::new(0, 0, 10, 10), Size::new(3, 3), Rect::new(3, 3, 3, 3))]
+    #[case::same(Rect::new(1, 2, 10, 10), Size::new(10, 10), Rect::new(1, 2, 10, 10))]
+    #[case::larger(Rect::new(1, 2, 10, 10), Size::new(20, 5), Rect::new(1, 4, 10, 5))]
+    #[case::empty(Rect::new(1, 2, 10, 10), Size::new(0, 0), Rect::new(6, 7, 0, 0))]
+    fn centered(#[case] rect: Rect, #[case] size: Size, #[case] expected: Rect) {
+        assert_eq!(rect.centered(size), expected);
+    }
+
     #[test]
     fn offset() {
         assert_eq!(
@@ -468,6 +615,46 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::positive(Rect::new(1, 2, 3, 4), Offset::new(5, 6), Rect::new(6, 8, 3, 4))]
+    #[case::negative(Rect::new(4, 3, 3, 4), Offset::new(-2, -1), Rect::new(2, 2, 3, 4))]
+    #[case::saturate_min(Rect::new(1, 2, 3, 4), Offset::new(-5, -6), Rect::new(0, 0, 3, 4))]
+    #[case::saturate_max(
+        Rect::new(u16::MAX - 500, u16::MAX - 500, 100, 100),
+        Offset::new(1000, 1000),
+        Rect::new(u16::MAX - 100, u16::MAX - 100, 100, 100)
+    )]
+    #[case::extreme(Rect::new(1, 2, 3, 4), Offset::new(i32::MAX, i32::MIN), Rect::new(u16::MAX - 3, 0, 3, 4))]
+    fn add_offset(#[case] rect: Rect, #[case] offset: Offset, #[case] expected: Rect) {
+        assert_eq!(rect + offset, expected);
+        let mut rect = rect;
+        rect += offset;
+        assert_eq!(rect, expected);
+    }
+
+    #[rstest]
+    #[case::positive(Rect::new(6, 8, 3, 4), Offset::new(5, 6), Rect::new(1, 2, 3, 4))]
+    #[case::negative(Rect::new(2, 2, 3, 4), Offset::new(-2, -1), Rect::new(4, 3, 3, 4))]
+    #[case::saturate_min(Rect::new(1, 2, 3, 4), Offset::new(5, 6), Rect::new(0, 0, 3, 4))]
+    #[case::saturate_max(
+        Rect::new(u16::MAX - 500, u16::MAX - 500, 100, 100),
+        Offset::new(-1000, -1000),
+        Rect::new(u16::MAX - 100, u16::MAX - 100, 100, 100)
+    )]
+    #[case::extreme(Rect::new(1, 2, 3, 4), Offset::new(i32::MIN, i32::MAX), Rect::new(u16::MAX - 3, 0, 3, 4))]
+    fn sub_offset(#[case] rect: Rect, #[case] offset: Offset, #[case] expected: Rect) {
+        assert_eq!(rect - offset, expected);
+        let mut rect = rect;
+        rect -= offset;
+        assert_eq!(rect, expected);
+    }
+
+    #[test]
+    fn neg_offset() {
+        assert_eq!(-Offset::new(1, -2), Offset::new(-1, 2));
+        assert_eq!(-Offset::new(i32::MIN, 0), Offset::new(i32::MAX, 0));
+    }
+
     #[test]
     fn union() {
         assert_eq!(
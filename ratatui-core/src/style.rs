@@ -72,7 +72,10 @@
 //!
 //! [`Span`]: crate::text::Span
 
-use std::fmt;
+use std::{
+    fmt,
+    ops::{Add, AddAssign},
+};
 
 use bitflags::bitflags;
 pub use color::{Color, ParseColorError};
@@ -102,6 +105,17 @@ bitflags! {
     ///
     /// let m = Modifier::BOLD | Modifier::ITALIC;
     /// ```
+    ///
+    /// `DOUBLE_UNDERLINE` and `CURLY_UNDERLINE` select the style of the underline (SGR `4:2` and
+    /// `4:3`), e.g. to render spellcheck or error squiggles. Terminals only draw one underline
+    /// style at a time: when several underline modifiers are set, `CURLY_UNDERLINE` takes
+    /// precedence over `DOUBLE_UNDERLINE`, which takes precedence over `UNDERLINED`. Backends that
+    /// don't support the underline styles fall back to a plain underline.
+    ///
+    /// `HIDDEN` conceals the text (SGR `8`, removed with SGR `28`) in all the backends: the cells
+    /// keep their width and background, but their symbols are not shown. The text is still sent to
+    /// the terminal, so it can be revealed by copying it or by a terminal that doesn't support
+    /// concealed text. To mask a password, render placeholder symbols (e.g. `*`) instead.
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
     pub struct Modifier: u16 {
@@ -114,6 +128,8 @@ bitflags! {
         const REVERSED          = 0b0000_0100_0000;
         const HIDDEN            = 0b0000_1000_0000;
         const CROSSED_OUT       = 0b0001_0000_0000;
+        const DOUBLE_UNDERLINE  = 0b0010_0000_0000;
+        const CURLY_UNDERLINE   = 0b0100_0000_0000;
     }
 }
 
@@ -325,6 +341,10 @@ impl Style {
 
     /// Changes the background color.
     ///
+    /// A style without a background color (e.g. [`Style::default`]) leaves the background of the
+    /// cells it is applied to untouched. This makes it possible to render widgets over a custom
+    /// backdrop.
+    ///
     /// ## Examples
     ///
     /// ```rust
@@ -346,6 +366,10 @@ impl Style {
     /// but is only implemented in the crossterm backend and enabled by the `underline-color`
     /// feature flag.
     ///
+    /// This method is always available so that code can be written independently of the feature
+    /// flag. When the `underline-color` feature is disabled, it does nothing and returns the style
+    /// unchanged.
+    ///
     /// See
     /// [Wikipedia](https://en.wikipedia.org/wiki/ANSI_escape_code#SGR_(Select_Graphic_Rendition)_parameters)
     /// code `58` and `59` for more information.
@@ -368,10 +392,13 @@ impl Style {
     ///         .add_modifier(Modifier::UNDERLINED)
     /// );
     /// ```
-    #[cfg(feature = "underline-color")]
     #[must_use = "`underline_color` returns the modified style without modifying the original"]
+    #[cfg_attr(not(feature = "underline-color"), allow(unused_mut, unused_variables))]
     pub const fn underline_color(mut self, color: Color) -> Self {
-        self.underline_color = Some(color);
+        #[cfg(feature = "underline-color")]
+        {
+            self.underline_color = Some(color);
+        }
         self
     }
 
@@ -640,6 +667,42 @@ impl From<(Color, Color, Modifier, Modifier)> for Style {
     }
 }
 
+/// Combines two styles, with the right hand side taking precedence.
+///
+/// `a + b` is equivalent to [`a.patch(b)`](Style::patch): the colors of `b` override those of `a`
+/// when set, and the modifiers added or removed by `b` are applied on top of those of `a`.
+///
+/// The right hand side accepts any type that is convertible to [`Style`] (e.g. [`Style`],
+/// [`Color`], or [`Modifier`]).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::style::{Color, Modifier, Style, Stylize};
+///
+/// let base = Style::new().white().on_black();
+/// let accent = Style::new().yellow().bold();
+///
+/// assert_eq!(base + accent, Style::new().yellow().on_black().bold());
+/// assert_eq!(base + Modifier::ITALIC, base.italic());
+/// ```
+impl<S: Into<Self>> Add<S> for Style {
+    type Output = Self;
+
+    fn add(self, rhs: S) -> Self::Output {
+        self.patch(rhs)
+    }
+}
+
+/// Patches the style in place, with the right hand side taking precedence.
+///
+/// See the [`Add`] implementation for details.
+impl<S: Into<Self>> AddAssign<S> for Style {
+    fn add_assign(&mut self, rhs: S) {
+        *self = self.patch(rhs);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -687,6 +750,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn add_gives_same_result_as_patch() {
+        let styles = [
+            Style::new(),
+            Style::new().fg(Color::Yellow),
+            Style::new().bg(Color::Red),
+            Style::new().fg(Color::Blue).bg(Color::Green),
+            Style::new().add_modifier(Modifier::BOLD),
+            Style::new().remove_modifier(Modifier::BOLD),
+            Style::new().add_modifier(Modifier::ITALIC | Modifier::DIM),
+            Style::new().remove_modifier(Modifier::ITALIC | Modifier::DIM),
+            Style::new()
+                .add_modifier(Modifier::BOLD)
+                .remove_modifier(Modifier::ITALIC),
+        ];
+        for &a in &styles {
+            for &b in &styles {
+                assert_eq!(a + b, a.patch(b), "{a:?} + {b:?}");
+                let mut c = a;
+                c += b;
+                assert_eq!(c, a.patch(b), "{a:?} += {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn add_accepts_into_style() {
+        let style = Style::new().red();
+        assert_eq!(style + Color::Blue, style.patch(Color::Blue));
+        assert_eq!(style + Modifier::BOLD, style.patch(Modifier::BOLD));
+        assert_eq!(
+            style + (Color::Green, Color::Black),
+            style.patch((Color::Green, Color::Black))
+        );
+    }
+
+    #[test]
+    fn add_removed_modifier_overrides_added_modifier() {
+        let base = Style::new().bold().italic();
+        let accent = Style::new().not_bold();
+        assert_eq!(base + accent, Style::new().italic().not_bold());
+    }
+
     #[test]
     fn combine_individual_modifiers() {
         use crate::{buffer::Buffer, layout::Rect};
@@ -701,6 +807,8 @@ mod tests {
             Modifier::REVERSED,
             Modifier::HIDDEN,
             Modifier::CROSSED_OUT,
+            Modifier::DOUBLE_UNDERLINE,
+            Modifier::CURLY_UNDERLINE,
         ];
 
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
@@ -725,12 +833,28 @@ mod tests {
     #[case(Modifier::REVERSED, "REVERSED")]
     #[case(Modifier::HIDDEN, "HIDDEN")]
     #[case(Modifier::CROSSED_OUT, "CROSSED_OUT")]
+    #[case(Modifier::DOUBLE_UNDERLINE, "DOUBLE_UNDERLINE")]
+    #[case(Modifier::CURLY_UNDERLINE, "CURLY_UNDERLINE")]
     #[case(Modifier::BOLD | Modifier::DIM, "BOLD | DIM")]
-    #[case(Modifier::all(), "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT")]
+    #[case(Modifier::all(), "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT | DOUBLE_UNDERLINE | CURLY_UNDERLINE")]
     fn modifier_debug(#[case] modifier: Modifier, #[case] expected: &str) {
         assert_eq!(format!("{modifier:?}"), expected);
     }
 
+    #[test]
+    #[cfg(feature = "underline-color")]
+    fn underline_color() {
+        let style = Style::new().underline_color(Color::Red);
+        assert_eq!(style.underline_color, Some(Color::Red));
+    }
+
+    #[test]
+    #[cfg(not(feature = "underline-color"))]
+    fn underline_color_is_noop_without_feature() {
+        let style = Style::new().red().underline_color(Color::Blue);
+        assert_eq!(style, Style::new().red());
+    }
+
     #[test]
     fn style_can_be_const() {
         const RED: Color = Color::Red;
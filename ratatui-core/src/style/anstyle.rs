@@ -130,9 +130,13 @@ impl From<Effects> for Modifier {
         if effect.contains(Effects::ITALIC) {
             modifier |= Self::ITALIC;
         }
+        if effect.contains(Effects::DOUBLE_UNDERLINE) {
+            modifier |= Self::DOUBLE_UNDERLINE;
+        }
+        if effect.contains(Effects::CURLY_UNDERLINE) {
+            modifier |= Self::CURLY_UNDERLINE;
+        }
         if effect.contains(Effects::UNDERLINE)
-            || effect.contains(Effects::DOUBLE_UNDERLINE)
-            || effect.contains(Effects::CURLY_UNDERLINE)
             || effect.contains(Effects::DOTTED_UNDERLINE)
             || effect.contains(Effects::DASHED_UNDERLINE)
         {
@@ -169,6 +173,12 @@ impl From<Modifier> for Effects {
         if modifier.contains(Modifier::UNDERLINED) {
             effects |= Self::UNDERLINE;
         }
+        if modifier.contains(Modifier::DOUBLE_UNDERLINE) {
+            effects |= Self::DOUBLE_UNDERLINE;
+        }
+        if modifier.contains(Modifier::CURLY_UNDERLINE) {
+            effects |= Self::CURLY_UNDERLINE;
+        }
         if modifier.contains(Modifier::SLOW_BLINK) || modifier.contains(Modifier::RAPID_BLINK) {
             effects |= Self::BLINK;
         }
@@ -295,6 +305,21 @@ mod tests {
         assert!(effects.contains(Effects::ITALIC));
     }
 
+    #[test]
+    fn underline_styles() {
+        let effects = Effects::DOUBLE_UNDERLINE | Effects::CURLY_UNDERLINE;
+        let modifier = Modifier::from(effects);
+        assert_eq!(
+            modifier,
+            Modifier::DOUBLE_UNDERLINE | Modifier::CURLY_UNDERLINE
+        );
+        assert_eq!(Effects::from(modifier), effects);
+        assert_eq!(
+            Modifier::from(Effects::DOTTED_UNDERLINE),
+            Modifier::UNDERLINED
+        );
+    }
+
     #[test]
     fn anstyle_style_to_style() {
         let anstyle_style = anstyle::Style::new()
@@ -135,6 +135,184 @@ impl Color {
         let b = u as u8;
         Self::Rgb(r, g, b)
     }
+
+    /// Converts the color to its RGB components.
+    ///
+    /// Named colors and the first 16 indexed colors use the common VGA palette, the other indexed
+    /// colors use the standard 6x6x6 color cube and grayscale ramp of 256 color terminals. The
+    /// actual colors displayed for named and indexed colors depend on the terminal theme, so this
+    /// is an approximation. Returns `None` for [`Color::Reset`] as it depends on the terminal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Red.to_rgb(), Some((128, 0, 0)));
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        let rgb = match self {
+            Self::Reset => return None,
+            Self::Black | Self::Indexed(0) => (0, 0, 0),
+            Self::Red | Self::Indexed(1) => (128, 0, 0),
+            Self::Green | Self::Indexed(2) => (0, 128, 0),
+            Self::Yellow | Self::Indexed(3) => (128, 128, 0),
+            Self::Blue | Self::Indexed(4) => (0, 0, 128),
+            Self::Magenta | Self::Indexed(5) => (128, 0, 128),
+            Self::Cyan | Self::Indexed(6) => (0, 128, 128),
+            Self::Gray | Self::Indexed(7) => (192, 192, 192),
+            Self::DarkGray | Self::Indexed(8) => (128, 128, 128),
+            Self::LightRed | Self::Indexed(9) => (255, 0, 0),
+            Self::LightGreen | Self::Indexed(10) => (0, 255, 0),
+            Self::LightYellow | Self::Indexed(11) => (255, 255, 0),
+            Self::LightBlue | Self::Indexed(12) => (0, 0, 255),
+            Self::LightMagenta | Self::Indexed(13) => (255, 0, 255),
+            Self::LightCyan | Self::Indexed(14) => (0, 255, 255),
+            Self::White | Self::Indexed(15) => (255, 255, 255),
+            Self::Indexed(i @ 16..=231) => {
+                let i = (i - 16) as usize;
+                (
+                    CUBE_LEVELS[i / 36],
+                    CUBE_LEVELS[(i / 6) % 6],
+                    CUBE_LEVELS[i % 6],
+                )
+            }
+            Self::Indexed(i) => {
+                let level = 8 + (i - 232) * 10;
+                (level, level, level)
+            }
+            Self::Rgb(r, g, b) => (r, g, b),
+        };
+        Some(rgb)
+    }
+
+    /// Blends this color toward `other` by the given `alpha`.
+    ///
+    /// An `alpha` of `0.0` returns this color and an `alpha` of `1.0` returns `other`, values in
+    /// between interpolate the RGB components (see [`Color::to_rgb`]) and return an RGB color.
+    /// `alpha` is clamped to the range `0.0..=1.0`. As [`Color::Reset`] has no RGB value, blending
+    /// from or to it switches from this color to `other` at an `alpha` of `0.5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::Black.blend(Color::White, 0.5),
+    ///     Color::Rgb(128, 128, 128)
+    /// );
+    /// assert_eq!(
+    ///     Color::Rgb(0, 0, 0).blend(Color::Rgb(200, 100, 0), 0.25),
+    ///     Color::Rgb(50, 25, 0)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn blend(self, other: Self, alpha: f32) -> Self {
+        let alpha = alpha.clamp(0.0, 1.0);
+        if alpha <= 0.0 {
+            return self;
+        }
+        if alpha >= 1.0 {
+            return other;
+        }
+        let (Some(from), Some(to)) = (self.to_rgb(), other.to_rgb()) else {
+            return if alpha < 0.5 { self } else { other };
+        };
+        let channel = |from: u8, to: u8| {
+            let (from, to) = (f32::from(from), f32::from(to));
+            (from + (to - from) * alpha).round() as u8
+        };
+        Self::Rgb(
+            channel(from.0, to.0),
+            channel(from.1, to.1),
+            channel(from.2, to.2),
+        )
+    }
+
+    /// Returns the candidate color with the highest contrast against the `bg` background color.
+    ///
+    /// The contrast ratio is computed from the relative luminance of the RGB components of the
+    /// colors (see [`Color::to_rgb`]), as defined by the [WCAG]. This is useful to pick a readable
+    /// foreground color for an arbitrary background, e.g. for badges with dynamic colors.
+    ///
+    /// Candidates without an RGB value ([`Color::Reset`]) are ignored. When `bg` is
+    /// [`Color::Reset`], its luminance is unknown and the first remaining candidate is returned.
+    /// Returns [`Color::Reset`] when there is no candidate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let candidates = [Color::Yellow, Color::Blue];
+    /// assert_eq!(
+    ///     Color::best_contrast(Color::Black, &candidates),
+    ///     Color::Yellow
+    /// );
+    /// assert_eq!(Color::best_contrast(Color::White, &candidates), Color::Blue);
+    /// ```
+    ///
+    /// [WCAG]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn best_contrast(bg: Self, candidates: &[Self]) -> Self {
+        let mut candidates = candidates
+            .iter()
+            .filter_map(|&color| Some((color, color.relative_luminance()?)));
+        let Some(bg_luminance) = bg.relative_luminance() else {
+            return candidates.next().map_or(Self::Reset, |(color, _)| color);
+        };
+        candidates
+            .map(|(color, luminance)| {
+                let (lighter, darker) = if luminance > bg_luminance {
+                    (luminance, bg_luminance)
+                } else {
+                    (bg_luminance, luminance)
+                };
+                (color, (lighter + 0.05) / (darker + 0.05))
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(Self::Reset, |(color, _)| color)
+    }
+
+    /// Returns black or white, whichever is the most readable on the `bg` background color.
+    ///
+    /// This is a shortcut for `Color::best_contrast(bg, &[Color::Black, Color::White])`, except
+    /// that [`Color::Reset`] is returned for a [`Color::Reset`] background, as the default
+    /// foreground color of the terminal is readable on its default background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::readable_on(Color::Rgb(255, 200, 0)), Color::Black);
+    /// assert_eq!(Color::readable_on(Color::Blue), Color::White);
+    /// assert_eq!(Color::readable_on(Color::Reset), Color::Reset);
+    /// ```
+    pub fn readable_on(bg: Self) -> Self {
+        if bg == Self::Reset {
+            return Self::Reset;
+        }
+        Self::best_contrast(bg, &[Self::Black, Self::White])
+    }
+
+    /// Returns the relative luminance of the color, from `0.0` for black to `1.0` for white.
+    fn relative_luminance(self) -> Option<f32> {
+        let linear = |channel: u8| {
+            let channel = f32::from(channel) / 255.0;
+            if channel <= 0.039_28 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = self.to_rgb()?;
+        Some(0.0722_f32.mul_add(linear(b), 0.2126_f32.mul_add(linear(r), 0.7152 * linear(g))))
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -480,7 +658,6 @@ mod tests {
 
     #[cfg(feature = "palette")]
     use palette::{Hsl, Hsluv};
-    #[cfg(feature = "palette")]
     use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
@@ -545,6 +722,95 @@ mod tests {
         assert_eq!(Color::from_u32(0xFFFFFF), Color::Rgb(255, 255, 255));
     }
 
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Color::Reset.to_rgb(), None);
+        assert_eq!(Color::Black.to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::LightBlue.to_rgb(), Some((0, 0, 255)));
+        assert_eq!(Color::Indexed(4).to_rgb(), Color::Blue.to_rgb());
+        assert_eq!(Color::Indexed(16).to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::Indexed(17).to_rgb(), Some((0, 0, 95)));
+        assert_eq!(Color::Indexed(231).to_rgb(), Some((255, 255, 255)));
+        assert_eq!(Color::Indexed(232).to_rgb(), Some((8, 8, 8)));
+        assert_eq!(Color::Indexed(255).to_rgb(), Some((238, 238, 238)));
+        assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    }
+
+    #[rstest]
+    #[case::named_red(Color::Red, 1)]
+    #[case::named_gray(Color::Gray, 7)]
+    #[case::named_dark_gray(Color::DarkGray, 8)]
+    #[case::named_white(Color::White, 15)]
+    fn to_rgb_named_matches_indexed(#[case] color: Color, #[case] index: u8) {
+        assert_eq!(color.to_rgb(), Color::Indexed(index).to_rgb());
+    }
+
+    #[rstest]
+    #[case::cube_start(16, (0, 0, 0))]
+    #[case::cube_blue(21, (0, 0, 255))]
+    #[case::cube_green(46, (0, 255, 0))]
+    #[case::cube_mixed(67, (95, 135, 175))]
+    #[case::cube_orange(208, (255, 135, 0))]
+    #[case::cube_red(196, (255, 0, 0))]
+    #[case::cube_end(231, (255, 255, 255))]
+    #[case::gray_start(232, (8, 8, 8))]
+    #[case::gray_middle(244, (128, 128, 128))]
+    #[case::gray_end(255, (238, 238, 238))]
+    fn to_rgb_indexed(#[case] index: u8, #[case] expected: (u8, u8, u8)) {
+        assert_eq!(Color::Indexed(index).to_rgb(), Some(expected));
+    }
+
+    #[test]
+    fn relative_luminance() {
+        assert_eq!(Color::Black.relative_luminance(), Some(0.0));
+        assert!((Color::White.relative_luminance().unwrap() - 1.0).abs() < 1e-6);
+        assert_eq!(Color::Reset.relative_luminance(), None);
+    }
+
+    #[rstest]
+    #[case::dark_background(Color::Rgb(20, 20, 40), Color::White)]
+    #[case::light_background(Color::Rgb(240, 240, 200), Color::Black)]
+    #[case::saturated_yellow(Color::LightYellow, Color::Black)]
+    #[case::saturated_blue(Color::Blue, Color::White)]
+    #[case::indexed(Color::Indexed(252), Color::Black)]
+    #[case::reset(Color::Reset, Color::Reset)]
+    fn readable_on(#[case] bg: Color, #[case] expected: Color) {
+        assert_eq!(Color::readable_on(bg), expected);
+    }
+
+    #[test]
+    fn best_contrast() {
+        let candidates = [Color::Reset, Color::Gray, Color::DarkGray, Color::Red];
+        assert_eq!(Color::best_contrast(Color::White, &candidates), Color::Red);
+        assert_eq!(Color::best_contrast(Color::Black, &candidates), Color::Gray);
+        assert_eq!(Color::best_contrast(Color::Reset, &candidates), Color::Gray);
+        assert_eq!(
+            Color::best_contrast(Color::Reset, &[Color::Reset]),
+            Color::Reset
+        );
+        assert_eq!(Color::best_contrast(Color::White, &[]), Color::Reset);
+        assert_eq!(
+            Color::best_contrast(Color::White, &[Color::Reset]),
+            Color::Reset
+        );
+    }
+
+    #[test]
+    fn blend() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert_eq!(black.blend(white, 0.0), black);
+        assert_eq!(black.blend(white, 1.0), white);
+        assert_eq!(black.blend(white, 0.2), Color::Rgb(51, 51, 51));
+        assert_eq!(black.blend(white, -1.0), black);
+        assert_eq!(black.blend(white, 2.0), white);
+        assert_eq!(Color::Red.blend(Color::Blue, 0.5), Color::Rgb(64, 0, 64));
+        assert_eq!(Color::Red.blend(Color::Red, 1.0), Color::Red);
+        assert_eq!(Color::Reset.blend(white, 0.4), Color::Reset);
+        assert_eq!(Color::Reset.blend(white, 0.5), white);
+        assert_eq!(black.blend(Color::Reset, 0.6), Color::Reset);
+    }
+
     #[test]
     fn from_rgb_color() {
         let color: Color = Color::from_str("#FF0000").unwrap();
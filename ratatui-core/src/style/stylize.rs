@@ -192,7 +192,9 @@ macro_rules! modifier {
 ///
 /// This trait implements a provided method for every color as both foreground and background
 /// (prefixed by `on_`), and all modifiers as both an additive and subtractive modifier (prefixed
-/// by `not_`). The `reset()` method is also provided to reset the style.
+/// by `not_`). Colors from the 256 color palette and RGB colors can be set with `indexed()` and
+/// `rgb()` (and `on_indexed()` and `on_rgb()` for the background). The `reset()` method is also
+/// provided to reset the style.
 ///
 /// # Examples
 /// ```ignore
@@ -239,6 +241,38 @@ pub trait Stylize<'a, T>: Sized {
     color!(light_cyan);
     color!(white);
 
+    /// Sets the foreground color to the given index of the 256 color palette.
+    ///
+    /// This is a shortcut for `fg(Color::Indexed(index))`. See [`Color::Indexed`].
+    #[must_use = "`indexed` returns the modified style without modifying the original"]
+    fn indexed(self, index: u8) -> T {
+        self.fg(Color::Indexed(index))
+    }
+
+    /// Sets the background color to the given index of the 256 color palette.
+    ///
+    /// This is a shortcut for `bg(Color::Indexed(index))`. See [`Color::Indexed`].
+    #[must_use = "`on_indexed` returns the modified style without modifying the original"]
+    fn on_indexed(self, index: u8) -> T {
+        self.bg(Color::Indexed(index))
+    }
+
+    /// Sets the foreground color to the given RGB (truecolor) value.
+    ///
+    /// This is a shortcut for `fg(Color::Rgb(r, g, b))`. See [`Color::Rgb`].
+    #[must_use = "`rgb` returns the modified style without modifying the original"]
+    fn rgb(self, r: u8, g: u8, b: u8) -> T {
+        self.fg(Color::Rgb(r, g, b))
+    }
+
+    /// Sets the background color to the given RGB (truecolor) value.
+    ///
+    /// This is a shortcut for `bg(Color::Rgb(r, g, b))`. See [`Color::Rgb`].
+    #[must_use = "`on_rgb` returns the modified style without modifying the original"]
+    fn on_rgb(self, r: u8, g: u8, b: u8) -> T {
+        self.bg(Color::Rgb(r, g, b))
+    }
+
     modifier!(bold);
     modifier!(dim);
     modifier!(italic);
@@ -447,6 +481,28 @@ mod tests {
         assert_eq!("hello".on_cyan(), Span::styled("hello", cyan_bg));
     }
 
+    #[test]
+    fn indexed() {
+        assert_eq!(
+            "hello".indexed(42).on_indexed(200),
+            Span::styled(
+                "hello",
+                Style::new().fg(Color::Indexed(42)).bg(Color::Indexed(200))
+            )
+        );
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(
+            "hello".rgb(1, 2, 3).on_rgb(4, 5, 6),
+            Span::styled(
+                "hello",
+                Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Rgb(4, 5, 6))
+            )
+        );
+    }
+
     #[test]
     fn color_modifier() {
         let cyan_bold = Style::default()
@@ -11,3 +11,4 @@ pub mod line;
 pub mod marker;
 pub mod scrollbar;
 pub mod shade;
+pub mod throbber;
@@ -0,0 +1,25 @@
+//! Frame sets used to animate a throbber (also known as a spinner).
+//!
+//! Each set is a sequence of symbols that are displayed one after the other, looping back to the
+//! start once the last symbol has been shown.
+
+/// A braille dot circling around a cell.
+///
+/// ```text
+/// ⠋ ⠙ ⠹ ⠸ ⠼ ⠴ ⠦ ⠧ ⠇ ⠏
+/// ```
+pub const BRAILLE: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// A growing and shrinking row of dots.
+///
+/// ```text
+/// ".  " ".. " "..." " .." "  ." "   "
+/// ```
+pub const DOTS: &[&str] = &[".  ", ".. ", "...", " ..", "  .", "   "];
+
+/// A line rotating around its center.
+///
+/// ```text
+/// - \ | /
+/// ```
+pub const LINE: &[&str] = &["-", "\\", "|", "/"];
@@ -1,6 +1,6 @@
 use crate::{
-    buffer::Buffer,
-    layout::{Position, Rect},
+    buffer::{Buffer, ClipBuffer},
+    layout::{Position, Rect, Size},
     widgets::{StatefulWidget, Widget},
 };
 
@@ -29,6 +29,9 @@ pub struct Frame<'a> {
     /// The buffer that is used to draw the current frame
     pub(crate) buffer: &'a mut Buffer,
 
+    /// The buffer of the previous frame, if it holds the content displayed on the terminal
+    pub(crate) previous_buffer: Option<&'a Buffer>,
+
     /// The frame count indicating the sequence number of this frame.
     pub(crate) count: usize,
 }
@@ -56,6 +59,12 @@ impl Frame<'_> {
     /// If your app listens for a resize event from the backend, it should ignore the values from
     /// the event for any calculations that are used to render the current frame and use this value
     /// instead as this is the area of the buffer that is used to render the current frame.
+    ///
+    /// The origin of the area is not always `(0, 0)`: with an inline or a fixed [`Viewport`], the
+    /// area is the part of the terminal taken by the viewport. Widgets should be rendered in this
+    /// area (or a sub-area of it) rather than in an area built from the size of the frame.
+    ///
+    /// [`Viewport`]: crate::terminal::Viewport
     pub const fn area(&self) -> Rect {
         self.viewport_area
     }
@@ -75,7 +84,9 @@ impl Frame<'_> {
     /// Render a [`Widget`] to the current buffer using [`Widget::render`].
     ///
     /// Usually the area argument is the size of the current frame or a sub-area of the current
-    /// frame (which can be obtained using [`Layout`] to split the total area).
+    /// frame (which can be obtained using [`Layout`] to split the total area). The area is clipped
+    /// to the [area of the frame](Frame::area), so that widgets never draw outside of the
+    /// viewport.
     ///
     /// # Example
     ///
@@ -93,13 +104,176 @@ impl Frame<'_> {
     ///
     /// [`Layout`]: crate::layout::Layout
     pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
-        widget.render(area, self.buffer);
+        widget.render(area.intersection(self.area()), self.buffer);
+    }
+
+    /// Render a [`Widget`] and return the number of cells it wrote outside of the given area.
+    ///
+    /// This is the same as [`Frame::render_widget`], except that the widget is rendered to a
+    /// [`ClipBuffer`], which records the writes outside of the area (clipped to the
+    /// [area of the frame](Frame::area)). These writes are dropped. A non-zero count means that
+    /// the widget doesn't respect the area it was given, which helps to diagnose why its content
+    /// is cut off.
+    ///
+    /// This copies the frame buffer, so it should only be used for debugging.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::{layout::Rect, widgets::Paragraph};
+    ///
+    /// let area = Rect::new(0, 0, 5, 1);
+    /// let dropped = frame.render_widget_debug_clip(Paragraph::new("Hello"), area);
+    /// assert_eq!(dropped, 0);
+    /// ```
+    pub fn render_widget_debug_clip<W: Widget>(&mut self, widget: W, area: Rect) -> usize {
+        let area = area.intersection(self.area());
+        let mut clip_buffer = ClipBuffer::new(self.buffer, area);
+        widget.render(area, clip_buffer.buffer_mut());
+        let dropped_writes = clip_buffer.dropped_writes();
+        clip_buffer.merge_into(self.buffer);
+        dropped_writes
+    }
+
+    /// Render a [`Widget`] only if it changed since the previous frame.
+    ///
+    /// When `dirty` is `true`, this is the same as [`Frame::render_widget`]. When `dirty` is
+    /// `false`, the widget is not rendered and the cells of the previous frame in the given area
+    /// are copied forward instead. As these cells are identical to the previous frame, they don't
+    /// produce any update to the terminal. This is useful for apps with large static panels which
+    /// are expensive to render.
+    ///
+    /// The widget is always rendered when the previous frame can't be reused, e.g. for the first
+    /// frame, or after the terminal was cleared or resized.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// # let log_changed = false;
+    /// use ratatui::{layout::Rect, widgets::Paragraph};
+    ///
+    /// let log = Paragraph::new("A large static log panel");
+    /// let area = Rect::new(0, 0, 5, 5);
+    /// frame.render_widget_if_changed(log, area, log_changed);
+    /// ```
+    pub fn render_widget_if_changed<W: Widget>(&mut self, widget: W, area: Rect, dirty: bool) {
+        match self.previous_buffer {
+            Some(previous_buffer) if !dirty => {
+                let area = area
+                    .intersection(self.buffer.area)
+                    .intersection(previous_buffer.area);
+                for position in area.positions() {
+                    self.buffer[position] = previous_buffer[position].clone();
+                }
+            }
+            _ => self.render_widget(widget, area),
+        }
+    }
+
+    /// Render a [`Widget`] of the given size centered in the frame.
+    ///
+    /// This computes the centered area using [`Rect::centered`] and renders the widget there using
+    /// [`Frame::render_widget`]. The size is clamped to the area of the frame. This is useful for
+    /// popups and modal dialogs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(40, 10);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::{
+    ///     layout::Size,
+    ///     widgets::{Block, Paragraph},
+    /// };
+    ///
+    /// let dialog = Paragraph::new("Delete this file? (y/n)")
+    ///     .centered()
+    ///     .block(Block::bordered().title("Confirm"));
+    /// frame.render_widget_centered(dialog, Size::new(30, 3));
+    /// ```
+    pub fn render_widget_centered<W: Widget>(&mut self, widget: W, size: Size) {
+        let area = self.area().centered(size);
+        self.render_widget(widget, area);
+    }
+
+    /// Render a batch of [`Widget`]s, each into its own area.
+    ///
+    /// This is the same as calling [`Frame::render_widget`] for each `(widget, area)` pair in
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(10, 2);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::{layout::Rect, widgets::Paragraph};
+    ///
+    /// frame.render_widgets([
+    ///     (Paragraph::new("Header"), Rect::new(0, 0, 10, 1)),
+    ///     (Paragraph::new("Footer"), Rect::new(0, 1, 10, 1)),
+    /// ]);
+    /// ```
+    pub fn render_widgets<W, I>(&mut self, widgets: I)
+    where
+        W: Widget,
+        I: IntoIterator<Item = (W, Rect)>,
+    {
+        for (widget, area) in widgets {
+            self.render_widget(widget, area);
+        }
+    }
+
+    /// Render a stack of [`Widget`]s into the same area.
+    ///
+    /// The widgets are rendered in order, so later widgets are drawn on top of earlier ones. This
+    /// is useful for simple layering, such as a backdrop with some content on top of it.
+    ///
+    /// Widgets which implement `Widget` for a reference can be passed by reference so that they
+    /// are not consumed. With the `unstable-widget-ref` feature, `&dyn WidgetRef` implements
+    /// `Widget`, which allows stacking widgets of different types.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(10, 3);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::widgets::{Block, Clear, Paragraph, WidgetRef};
+    ///
+    /// let backdrop = Block::bordered();
+    /// let content = Paragraph::new("Hello").centered();
+    /// let area = frame.area();
+    /// frame.render_all(area, [&&Clear as &dyn WidgetRef, &&backdrop, &&content]);
+    /// ```
+    pub fn render_all<I>(&mut self, area: Rect, widgets: I)
+    where
+        I: IntoIterator,
+        I::Item: Widget,
+    {
+        for widget in widgets {
+            self.render_widget(widget, area);
+        }
     }
 
     /// Render a [`StatefulWidget`] to the current buffer using [`StatefulWidget::render`].
     ///
     /// Usually the area argument is the size of the current frame or a sub-area of the current
-    /// frame (which can be obtained using [`Layout`] to split the total area).
+    /// frame (which can be obtained using [`Layout`] to split the total area). The area is clipped
+    /// to the [area of the frame](Frame::area), so that widgets never draw outside of the
+    /// viewport.
     ///
     /// The last argument should be an instance of the [`StatefulWidget::State`] associated to the
     /// given [`StatefulWidget`].
@@ -127,7 +301,7 @@ impl Frame<'_> {
     where
         W: StatefulWidget,
     {
-        widget.render(area, self.buffer, state);
+        widget.render(area.intersection(self.area()), self.buffer, state);
     }
 
     /// After drawing this frame, make the cursor visible and put it at the specified (x, y)
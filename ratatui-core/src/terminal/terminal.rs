@@ -53,6 +53,7 @@ use crate::{
 /// [`Backend`]: crate::backend::Backend
 /// [`Buffer`]: crate::buffer::Buffer
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Terminal<B>
 where
     B: Backend,
@@ -77,6 +78,14 @@ where
     last_known_cursor_pos: Position,
     /// Number of frames rendered up until current time.
     frame_count: usize,
+    /// Whether the next draw has to redraw everything, in which case the previous buffer does not
+    /// hold the content displayed on the terminal and can't be reused.
+    full_redraw: bool,
+    /// Whether the blank cells at the end of the rows are erased instead of drawn
+    erase_trailing_cells: bool,
+    /// Whether the current buffer was presented since the last draw, in which case it still holds
+    /// the presented content and has to be cleared before the next frame is rendered
+    presented: bool,
 }
 
 /// Options to pass to [`Terminal::with_options`]
@@ -141,19 +150,7 @@ where
     /// # std::io::Result::Ok(())
     /// ```
     pub fn with_options(mut backend: B, options: TerminalOptions) -> io::Result<Self> {
-        let area = match options.viewport {
-            Viewport::Fullscreen | Viewport::Inline(_) => {
-                Rect::from((Position::ORIGIN, backend.size()?))
-            }
-            Viewport::Fixed(area) => area,
-        };
-        let (viewport_area, cursor_pos) = match options.viewport {
-            Viewport::Fullscreen => (area, Position::ORIGIN),
-            Viewport::Inline(height) => {
-                compute_inline_size(&mut backend, height, area.as_size(), 0)?
-            }
-            Viewport::Fixed(area) => (area, area.as_position()),
-        };
+        let (area, viewport_area, cursor_pos) = compute_viewport(&mut backend, &options.viewport)?;
         Ok(Self {
             backend,
             buffers: [Buffer::empty(viewport_area), Buffer::empty(viewport_area)],
@@ -164,21 +161,91 @@ where
             last_known_area: area,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            full_redraw: true,
+            erase_trailing_cells: false,
+            presented: false,
         })
     }
 
+    /// Changes the [`Viewport`] of the terminal.
+    ///
+    /// This clears the area of the previous viewport on the terminal and reinitializes the internal
+    /// buffers for the new viewport. As the previous buffer is cleared, the next frame is fully
+    /// repainted. This is useful for apps which toggle between an embedded (e.g. inline or fixed)
+    /// and a fullscreen mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # let backend = ratatui::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui::Terminal::new(backend)?;
+    /// use ratatui::{layout::Rect, Viewport};
+    ///
+    /// terminal.set_viewport(Viewport::Fixed(Rect::new(2, 2, 6, 6)))?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_viewport(&mut self, viewport: Viewport) -> io::Result<()> {
+        self.clear()?;
+        let (area, viewport_area, cursor_pos) = compute_viewport(&mut self.backend, &viewport)?;
+        self.viewport = viewport;
+        self.buffers = [Buffer::empty(viewport_area), Buffer::empty(viewport_area)];
+        self.viewport_area = viewport_area;
+        self.last_known_area = area;
+        self.last_known_cursor_pos = cursor_pos;
+        self.full_redraw = true;
+        Ok(())
+    }
+
     /// Get a Frame object which provides a consistent view into the terminal state for rendering.
     pub fn get_frame(&mut self) -> Frame {
         let count = self.frame_count;
+        let [first, second] = &mut self.buffers;
+        let (buffer, previous_buffer) = if self.current == 0 {
+            (first, &*second)
+        } else {
+            (second, &*first)
+        };
         Frame {
             cursor_position: None,
             viewport_area: self.viewport_area,
-            buffer: self.current_buffer_mut(),
+            buffer,
+            previous_buffer: (!self.full_redraw).then_some(previous_buffer),
             count,
         }
     }
 
     /// Gets the current buffer as a mutable reference.
+    ///
+    /// The terminal is double-buffered: the current (back) buffer is the one being rendered to,
+    /// and the previous (front) buffer holds what was last written to the backend.
+    /// [`Terminal::flush`] diffs these two buffers and writes only the changed cells, and
+    /// [`Terminal::swap_buffers`] makes the current buffer the previous one. [`Terminal::draw`]
+    /// does all of this around its render callback, but advanced users can drive the buffers
+    /// themselves, e.g. in a custom render loop, in tests, or to use a frame built on another
+    /// thread by replacing the current buffer with it.
+    ///
+    /// The area of the current buffer must not be changed: it has to match the area of the
+    /// previous buffer (and of the viewport) for the diff to be correct. A buffer built elsewhere
+    /// should therefore be created with the area of this buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, buffer::Buffer, style::Style, terminal::Terminal};
+    /// # let backend = TestBackend::new(10, 1);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// let area = terminal.current_buffer_mut().area;
+    ///
+    /// // e.g. built on another thread
+    /// let mut buffer = Buffer::empty(area);
+    /// buffer.set_string(0, 0, "Hello", Style::new());
+    ///
+    /// *terminal.current_buffer_mut() = buffer;
+    /// terminal.flush()?;
+    /// terminal.swap_buffers();
+    /// terminal.backend().assert_buffer_lines(["Hello     "]);
+    /// # std::io::Result::Ok(())
+    /// ```
     pub fn current_buffer_mut(&mut self) -> &mut Buffer {
         &mut self.buffers[self.current]
     }
@@ -193,16 +260,130 @@ where
         &mut self.backend
     }
 
+    /// Returns the number of frames drawn by this terminal so far.
+    ///
+    /// This is incremented at the end of each successful [`Terminal::draw`] call, so it is equal
+    /// to the [`Frame::count`] of the next frame. The counter wraps around at `usize::MAX`.
+    pub const fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Returns whether the blank cells at the end of the rows are erased instead of drawn.
+    ///
+    /// See [`Terminal::set_erase_trailing_cells`].
+    pub const fn erase_trailing_cells(&self) -> bool {
+        self.erase_trailing_cells
+    }
+
+    /// Sets whether the blank cells at the end of the rows are erased instead of drawn.
+    ///
+    /// When enabled, the blank cells (spaces with the default style) at the end of a row that
+    /// need to be updated are erased with a single [`ClearType::UntilNewLine`] request to the
+    /// backend (the EL escape sequence) instead of being drawn one by one (see
+    /// [`Buffer::diff_erasing_trailing`]). This reduces the output on wide terminals when content
+    /// is removed from the end of the rows.
+    ///
+    /// The rows are only erased when the viewport reaches the right edge of the terminal, as the
+    /// cells on the right of the viewport would be erased too. Otherwise the cells are drawn.
+    ///
+    /// The backend must support [`ClearType::UntilNewLine`] and erase the cells with the default
+    /// style. If the backend returns an [`io::ErrorKind::Unsupported`] error, the cells are drawn
+    /// instead and this option is disabled. Any other error is returned by [`Terminal::flush`].
+    ///
+    /// This is disabled by default.
+    pub fn set_erase_trailing_cells(&mut self, erase: bool) {
+        self.erase_trailing_cells = erase;
+    }
+
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
     pub fn flush(&mut self) -> io::Result<()> {
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
-        let updates = previous_buffer.diff(current_buffer);
+        if !self.erase_trailing_cells {
+            let updates = previous_buffer.diff(current_buffer);
+            if let Some((col, row, _)) = updates.last() {
+                self.last_known_cursor_pos = Position { x: *col, y: *row };
+            }
+            return self.backend.draw(updates.into_iter());
+        }
+
+        let (updates, erases) = previous_buffer.diff_erasing_trailing(current_buffer);
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = Position { x: *col, y: *row };
         }
-        self.backend.draw(updates.into_iter())
+        self.backend.draw(updates.into_iter())?;
+        if erases.is_empty() {
+            return Ok(());
+        }
+        // erasing until the end of the line would also wipe the cells on the right of a viewport
+        // that doesn't reach the right edge of the terminal
+        let reaches_right_edge = current_buffer.area.right() == self.backend.size()?.width;
+        for position in erases {
+            self.last_known_cursor_pos = position;
+            if reaches_right_edge && self.erase_trailing_cells {
+                self.backend.set_cursor_position(position)?;
+                match self.backend.clear_region(ClearType::UntilNewLine) {
+                    Ok(()) => continue,
+                    // the backend can't erase the end of a line, fall back to drawing the cells
+                    Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+                        self.erase_trailing_cells = false;
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+            let width = current_buffer.area.right() - position.x;
+            let row = Rect::new(position.x, position.y, width, 1);
+            let cells = row
+                .positions()
+                .map(|position| (position.x, position.y, &current_buffer[position]));
+            self.backend.draw(cells)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the changes made to the current buffer to the backend without drawing a frame.
+    ///
+    /// This is intended for animations that mutate the buffer returned by
+    /// [`Terminal::current_buffer_mut`] between calls to [`Terminal::draw`]. Like
+    /// [`Terminal::flush`], only the cells that differ from what was last written to the backend
+    /// are drawn, then the backend is flushed. Unlike [`Terminal::draw`], the current buffer is
+    /// kept rather than cleared, so the next changes can be made incrementally on top of it. The
+    /// cursor and the viewport are left untouched.
+    ///
+    /// Note that [`Terminal::draw`] clears the current buffer after writing it, so the first
+    /// changes following a draw should render the whole content of the buffer. Likewise, the next
+    /// frame drawn after a call to `present` is rendered into an empty buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, style::Style, terminal::Terminal};
+    /// # let backend = TestBackend::new(10, 1);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal
+    ///     .current_buffer_mut()
+    ///     .set_string(0, 0, "loading", Style::new());
+    /// terminal.present()?;
+    /// terminal
+    ///     .current_buffer_mut()
+    ///     .set_string(7, 0, "...", Style::new());
+    /// terminal.present()?;
+    /// terminal.backend().assert_buffer_lines(["loading..."]);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn present(&mut self) -> io::Result<()> {
+        self.flush()?;
+        self.backend.flush()?;
+        let [first, second] = &mut self.buffers;
+        let (current, previous) = if self.current == 0 {
+            (&*first, second)
+        } else {
+            (&*second, first)
+        };
+        previous.clone_from(current);
+        self.presented = true;
+        Ok(())
     }
 
     /// Updates the Terminal so that internal buffers match the requested area.
@@ -237,6 +418,7 @@ where
         self.buffers[self.current].resize(area);
         self.buffers[1 - self.current].resize(area);
         self.viewport_area = area;
+        self.full_redraw = true;
     }
 
     /// Queries the backend for size and resizes if it doesn't match the previous size.
@@ -384,6 +566,10 @@ where
         // Autoresize - otherwise we get glitches if shrinking or potential desync between widgets
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
+        if self.presented {
+            self.buffers[self.current].reset();
+            self.presented = false;
+        }
 
         let mut frame = self.get_frame();
 
@@ -406,6 +592,7 @@ where
         }
 
         self.swap_buffers();
+        self.full_redraw = false;
 
         // Flush
         self.backend.flush()?;
@@ -486,10 +673,65 @@ where
         }
         // Reset the back buffer to make sure the next update will redraw everything.
         self.buffers[1 - self.current].reset();
+        self.full_redraw = true;
         Ok(())
     }
 
+    /// Leaves the last frame of an inline viewport in the terminal and moves the cursor below it.
+    ///
+    /// This is meant to be called once an application that uses [`Viewport::Inline`] is done
+    /// drawing, e.g. a CLI tool that shows a live-updating progress block and exits. The last
+    /// frame stays on screen, and any output printed afterwards starts on the line below the
+    /// viewport, so the frame scrolls into the terminal's scrollback like regular output. When the
+    /// viewport is at the bottom of the screen, the screen is scrolled up by one line to make room
+    /// for the cursor.
+    ///
+    /// The cursor is made visible again. The viewport must not be drawn to after calling this
+    /// method, as its area no longer matches what is on screen.
+    ///
+    /// This has no effect when the viewport is not inline.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ratatui::{backend::TestBackend, widgets::Paragraph, Terminal, TerminalOptions, Viewport};
+    ///
+    /// let backend = TestBackend::new(20, 5);
+    /// let viewport = Viewport::Inline(2);
+    /// let mut terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+    /// terminal.draw(|frame| frame.render_widget(Paragraph::new("Done!"), frame.area()))?;
+    /// terminal.finish_inline()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn finish_inline(&mut self) -> io::Result<()> {
+        if !matches!(self.viewport, Viewport::Inline(_)) {
+            return Ok(());
+        }
+        let last_row = self.last_known_area.bottom().saturating_sub(1);
+        let next_row = if self.viewport_area.bottom() > last_row {
+            self.backend
+                .set_cursor_position(Position::new(0, last_row))?;
+            self.backend.append_lines(1)?;
+            last_row
+        } else {
+            self.viewport_area.bottom()
+        };
+        self.set_cursor_position(Position::new(0, next_row))?;
+        self.show_cursor()?;
+        self.backend.flush()
+    }
+
     /// Clears the inactive buffer and swaps it with the current buffer
+    ///
+    /// This is meant to be called after [`Terminal::flush`], once the current buffer has been
+    /// written to the backend: it becomes the previous buffer that the next frame is compared to,
+    /// and rendering continues on a blank buffer, which should be fully rendered again. Calling
+    /// it without flushing first means that the next flush is compared to content which was never
+    /// written, so the backend gets out of sync. See [`Terminal::current_buffer_mut`] for how to
+    /// use this in a custom render loop.
+    ///
+    /// Unlike [`Terminal::draw`], this doesn't update the cursor, flush the backend or increment
+    /// the [frame count](Terminal::frame_count).
     pub fn swap_buffers(&mut self) {
         self.buffers[1 - self.current].reset();
         self.current = 1 - self.current;
@@ -821,6 +1063,26 @@ where
     }
 }
 
+/// Computes the area of the terminal, the area of the viewport and the initial cursor position for
+/// the given viewport.
+fn compute_viewport<B: Backend>(
+    backend: &mut B,
+    viewport: &Viewport,
+) -> io::Result<(Rect, Rect, Position)> {
+    let area = match viewport {
+        Viewport::Fullscreen | Viewport::Inline(_) => {
+            Rect::from((Position::ORIGIN, backend.size()?))
+        }
+        Viewport::Fixed(area) => *area,
+    };
+    let (viewport_area, cursor_pos) = match viewport {
+        Viewport::Fullscreen => (area, Position::ORIGIN),
+        Viewport::Inline(height) => compute_inline_size(backend, *height, area.as_size(), 0)?,
+        Viewport::Fixed(area) => (*area, area.as_position()),
+    };
+    Ok((area, viewport_area, cursor_pos))
+}
+
 fn compute_inline_size<B: Backend>(
     backend: &mut B,
     height: u16,
@@ -25,6 +25,20 @@ pub enum Viewport {
     ///
     /// The viewport's height is fixed and specified in number of lines. The width is the same as
     /// the terminal's width. The viewport is drawn below the cursor position.
+    ///
+    /// When the terminal is created, the lines needed for the viewport are reserved below the
+    /// cursor. If there are not enough lines left below the cursor, the content of the terminal is
+    /// scrolled up, pushing the topmost lines into the scrollback, and the viewport is placed at
+    /// the bottom of the screen. As the viewport is anchored to a position on the screen,
+    /// scrolling the terminal by other means (e.g. printing directly to stdout) moves the
+    /// content out of the viewport; use [`Terminal::insert_before`] to print above the
+    /// viewport instead.
+    ///
+    /// Call [`Terminal::finish_inline`] when done to leave the last frame in the terminal and
+    /// move the cursor below it.
+    ///
+    /// [`Terminal::insert_before`]: crate::terminal::Terminal::insert_before
+    /// [`Terminal::finish_inline`]: crate::terminal::Terminal::finish_inline
     Inline(u16),
     /// The viewport is drawn in a fixed area of the terminal. The area is specified by a [`Rect`].
     Fixed(Rect),
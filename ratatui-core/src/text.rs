@@ -48,11 +48,14 @@
 //! ]);
 //! ```
 
+mod cursor;
+pub use cursor::cursor_column;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
 mod line;
-pub use line::{Line, ToLine};
+pub use line::{CellChange, Line, ToLine};
 
 mod masked;
 pub use masked::Masked;
@@ -0,0 +1,62 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Returns the display column of a cursor positioned before the grapheme at `grapheme_index`.
+///
+/// The column is the sum of the display widths of the graphemes before the cursor, so wide
+/// characters (e.g. CJK or emoji) move the cursor by two columns and combining characters don't
+/// move it at all. When `grapheme_index` is at or past the end of the string, the total width of
+/// the string is returned, which is the position of a cursor after the last grapheme.
+///
+/// This is useful for positioning the terminal cursor in an input field. See
+/// [`Line::cursor_column`] for the equivalent on a [`Line`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::cursor_column;
+///
+/// assert_eq!(cursor_column("hello", 2), 2);
+/// assert_eq!(cursor_column("你好", 1), 2);
+/// assert_eq!(cursor_column("e\u{301}x", 1), 1);
+/// assert_eq!(cursor_column("hello", 10), 5);
+/// ```
+///
+/// [`Line`]: crate::text::Line
+/// [`Line::cursor_column`]: crate::text::Line::cursor_column
+pub fn cursor_column(s: &str, grapheme_index: usize) -> u16 {
+    graphemes_column(s.graphemes(true), grapheme_index)
+}
+
+/// Returns the sum of the widths of the first `grapheme_index` graphemes, saturating at
+/// `u16::MAX`.
+pub(super) fn graphemes_column<'a, I>(graphemes: I, grapheme_index: usize) -> u16
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let width: usize = graphemes
+        .into_iter()
+        .take(grapheme_index)
+        .map(UnicodeWidthStr::width)
+        .sum();
+    u16::try_from(width).unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::empty("", 0, 0)]
+    #[case::start("hello", 0, 0)]
+    #[case::middle("hello", 2, 2)]
+    #[case::end("hello", 5, 5)]
+    #[case::past_end("hello", 10, 5)]
+    #[case::wide("你好世界", 2, 4)]
+    #[case::emoji("a😃b", 2, 3)]
+    #[case::combining("e\u{301}e\u{301}", 1, 1)]
+    #[case::zero_width("a\u{200b}b", 2, 1)]
+    fn cursor_column(#[case] s: &str, #[case] index: usize, #[case] expected: u16) {
+        assert_eq!(super::cursor_column(s, index), expected);
+    }
+}
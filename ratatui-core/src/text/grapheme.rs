@@ -12,6 +12,8 @@ const ZWSP: &str = "\u{200b}";
 pub struct StyledGrapheme<'a> {
     pub symbol: &'a str,
     pub style: Style,
+    /// The URL the grapheme links to, see [`Span::hyperlink`](crate::text::Span::hyperlink).
+    pub link: Option<&'a str>,
 }
 
 impl<'a> StyledGrapheme<'a> {
@@ -25,6 +27,7 @@ impl<'a> StyledGrapheme<'a> {
         Self {
             symbol,
             style: style.into(),
+            link: None,
         }
     }
 
@@ -58,6 +61,7 @@ mod tests {
         let sg = StyledGrapheme::new("a", style);
         assert_eq!(sg.symbol, "a");
         assert_eq!(sg.style, style);
+        assert_eq!(sg.link, None);
     }
 
     #[test]
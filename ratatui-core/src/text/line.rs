@@ -2,13 +2,15 @@
 #![warn(clippy::pedantic, clippy::nursery, clippy::arithmetic_side_effects)]
 use std::{borrow::Cow, fmt};
 
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_truncate::UnicodeTruncateStr;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    buffer::Buffer,
+    buffer::{Buffer, Cell},
     layout::{Alignment, Rect},
-    style::{Style, Styled},
-    text::{Span, StyledGrapheme, Text},
+    style::{Color, Style, Styled},
+    text::{cursor::graphemes_column, Span, StyledGrapheme, Text},
     widgets::Widget,
 };
 
@@ -175,7 +177,7 @@ use crate::{
 /// # fn render(area: Rect, buf: &mut Buffer) {
 /// let line = Line::from("Hello world!").yellow().italic();
 /// Paragraph::new(line)
-///     .wrap(Wrap { trim: true })
+///     .wrap(Wrap::new().trim(true))
 ///     .render(area, buf);
 /// # }
 /// ```
@@ -193,6 +195,15 @@ pub struct Line<'a> {
     pub spans: Vec<Span<'a>>,
 }
 
+/// A change to a single column of a line, produced by [`Line::diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CellChange {
+    /// The column of the cell, relative to the start of the line
+    pub column: u16,
+    /// The new content of the cell
+    pub cell: Cell,
+}
+
 impl fmt::Debug for Line<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.spans.is_empty() {
@@ -298,6 +309,54 @@ impl<'a> Line<'a> {
         }
     }
 
+    /// Create a line with a foreground color gradient from `start` to `end`.
+    ///
+    /// Each grapheme of the content gets its own span, with a foreground color blended between
+    /// `start` and `end` (see [`Color::blend`]) according to the display column at which the
+    /// grapheme starts. The first grapheme has the `start` color and the last one has the `end`
+    /// color. As the columns are counted with the display width, wide graphemes (e.g. CJK
+    /// characters) advance the gradient by two columns. Any newlines in the content are removed.
+    ///
+    /// This is useful for titles and headers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Color, text::Line};
+    ///
+    /// let line = Line::gradient("Hello", Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255));
+    /// assert_eq!(line.spans.len(), 5);
+    /// assert_eq!(line.spans[0].style.fg, Some(Color::Rgb(255, 0, 0)));
+    /// assert_eq!(line.spans[2].style.fg, Some(Color::Rgb(128, 0, 128)));
+    /// assert_eq!(line.spans[4].style.fg, Some(Color::Rgb(0, 0, 255)));
+    /// ```
+    #[must_use]
+    pub fn gradient(content: &'a str, start: Color, end: Color) -> Self {
+        let graphemes = content
+            .graphemes(true)
+            .filter(|grapheme| !matches!(*grapheme, "\n" | "\r\n"))
+            .scan(0_u16, |column, grapheme| {
+                let start_column = *column;
+                let width = u16::try_from(grapheme.width()).unwrap_or(u16::MAX);
+                *column = column.saturating_add(width);
+                Some((grapheme, start_column))
+            })
+            .collect::<Vec<_>>();
+        let last_column = graphemes.last().map_or(0, |(_, column)| *column);
+        let spans = graphemes
+            .into_iter()
+            .map(|(grapheme, column)| {
+                let alpha = if last_column == 0 {
+                    0.0
+                } else {
+                    f32::from(column) / f32::from(last_column)
+                };
+                Span::styled(grapheme, start.blend(end, alpha))
+            })
+            .collect::<Vec<_>>();
+        Self::from(spans)
+    }
+
     /// Sets the spans of this line of text.
     ///
     /// `spans` accepts any iterator that yields items that are convertible to [`Span`] (e.g.
@@ -443,6 +502,75 @@ impl<'a> Line<'a> {
         self.spans.iter().map(Span::width).sum()
     }
 
+    /// Returns the display column of a cursor positioned before the grapheme at `grapheme_index`.
+    ///
+    /// The graphemes are counted across all the spans of the line. When `grapheme_index` is at or
+    /// past the end of the line, the width of the line is returned. This does not take the
+    /// alignment of the line into account.
+    ///
+    /// See [`cursor_column`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Line};
+    ///
+    /// let line = Line::from(vec!["> ".dark_gray(), "你好".into()]);
+    /// assert_eq!(line.cursor_column(3), 4);
+    /// assert_eq!(line.cursor_column(10), 6);
+    /// ```
+    ///
+    /// [`cursor_column`]: crate::text::cursor_column
+    #[must_use]
+    pub fn cursor_column(&self, grapheme_index: usize) -> u16 {
+        let graphemes = self
+            .spans
+            .iter()
+            .flat_map(|span| span.content.graphemes(true));
+        graphemes_column(graphemes, grapheme_index)
+    }
+
+    /// Returns the columns that need to be updated to change the rendered line from `self` to
+    /// `other`.
+    ///
+    /// Both lines are rendered into a single row as wide as the wider of the two lines, and the
+    /// rows are compared in the same way as [`Buffer::diff`] compares buffers. This means that the
+    /// alignment and style of the lines are taken into account, and that multi-width graphemes
+    /// are handled. Columns are relative to the start of the line.
+    ///
+    /// This is useful for widgets that cache rendered lines and only want to redraw the columns
+    /// that changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Line};
+    ///
+    /// let previous = Line::from("Hello world");
+    /// let next = Line::from(vec!["Hello ".into(), "World".red()]);
+    /// let changes = previous.diff(&next);
+    /// assert_eq!(changes.len(), 5);
+    /// assert_eq!(changes[0].column, 6);
+    /// assert_eq!(changes[0].cell.symbol(), "W");
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Line<'_>) -> Vec<CellChange> {
+        let width = u16::try_from(self.width().max(other.width())).unwrap_or(u16::MAX);
+        let area = Rect::new(0, 0, width, 1);
+        let mut previous = Buffer::empty(area);
+        let mut next = Buffer::empty(area);
+        Widget::render(self, area, &mut previous);
+        Widget::render(other, area, &mut next);
+        previous
+            .diff(&next)
+            .into_iter()
+            .map(|(column, _, cell)| CellChange {
+                column,
+                cell: cell.clone(),
+            })
+            .collect()
+    }
+
     /// Returns an iterator over the graphemes held by this line.
     ///
     /// `base_style` is the [`Style`] that will be patched with each grapheme [`Style`] to get
@@ -543,6 +671,88 @@ impl<'a> Line<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Returns a copy of this line with the content of each span converted to uppercase.
+    ///
+    /// See [`Span::to_uppercase`] for details. The style and alignment of the line and the style of
+    /// each span are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Line};
+    ///
+    /// let line = Line::from(vec!["Name".bold(), " (größe)".dim()]).centered();
+    /// assert_eq!(
+    ///     line.to_uppercase(),
+    ///     Line::from(vec!["NAME".bold(), " (GRÖSSE)".dim()]).centered()
+    /// );
+    /// ```
+    #[must_use = "`to_uppercase` returns a new line without modifying the original"]
+    pub fn to_uppercase(&self) -> Self {
+        Self {
+            spans: self.spans.iter().map(Span::to_uppercase).collect(),
+            ..*self
+        }
+    }
+
+    /// Returns a copy of this line with the content of each span converted to lowercase.
+    ///
+    /// See [`Span::to_lowercase`] for details. The style and alignment of the line and the style of
+    /// each span are kept.
+    #[must_use = "`to_lowercase` returns a new line without modifying the original"]
+    pub fn to_lowercase(&self) -> Self {
+        Self {
+            spans: self.spans.iter().map(Span::to_lowercase).collect(),
+            ..*self
+        }
+    }
+
+    /// Replaces the tab characters of this line with spaces up to the next tab stop.
+    ///
+    /// Tab stops are placed every `tab_width` columns, counted from the start of the line across
+    /// all of its spans, so each tab is replaced by one to `tab_width` spaces depending on the
+    /// column it is found at. Terminals usually use a tab width of 8. A `tab_width` of `0` removes
+    /// the tabs.
+    ///
+    /// Tabs are otherwise rendered as a single cell, which misaligns source code and command
+    /// output. The style of the spans is kept.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Line};
+    ///
+    /// let line = Line::from(vec!["a\tb".red(), "\tc".into()]).expand_tabs(4);
+    /// assert_eq!(line, Line::from(vec!["a   b".red(), "   c".into()]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expand_tabs(mut self, tab_width: usize) -> Self {
+        let mut column = 0_usize;
+        for span in &mut self.spans {
+            if !span.content.contains('\t') {
+                column = column.saturating_add(span.width());
+                continue;
+            }
+            let mut content = String::with_capacity(span.content.len());
+            for grapheme in span.content.graphemes(true) {
+                if grapheme == "\t" {
+                    let spaces = column
+                        .checked_rem(tab_width)
+                        .map_or(0, |rem| tab_width.saturating_sub(rem));
+                    content.extend(std::iter::repeat(' ').take(spaces));
+                    column = column.saturating_add(spaces);
+                } else {
+                    content.push_str(grapheme);
+                    column = column.saturating_add(grapheme.width());
+                }
+            }
+            span.content = Cow::Owned(content);
+        }
+        self
+    }
+
     /// Returns an iterator over the spans of this line.
     pub fn iter(&self) -> std::slice::Iter<Span<'a>> {
         self.spans.iter()
@@ -761,6 +971,8 @@ fn spans_after_width<'a>(
 ) -> impl Iterator<Item = (Span<'a>, usize, u16)> {
     spans
         .iter()
+        // Empty spans are never visible, so skip computing their width.
+        .filter(|span| !span.is_empty())
         .map(|span| (span, span.width()))
         // Filter non visible spans out.
         .filter_map(move |(span, span_width)| {
@@ -946,6 +1158,17 @@ mod tests {
         assert_eq!(0, empty_line.width());
     }
 
+    #[test]
+    fn cursor_column() {
+        let line = Line::from(vec![Span::raw("a😃"), Span::raw("b你")]);
+        assert_eq!(line.cursor_column(0), 0);
+        assert_eq!(line.cursor_column(2), 3);
+        assert_eq!(line.cursor_column(3), 4);
+        assert_eq!(line.cursor_column(4), 6);
+        assert_eq!(line.cursor_column(10), 6);
+        assert_eq!(Line::default().cursor_column(1), 0);
+    }
+
     #[test]
     fn patch_style() {
         let raw_line = Line::styled("foobar", Color::Yellow);
@@ -957,6 +1180,31 @@ mod tests {
         assert_eq!(raw_line, styled_line);
     }
 
+    #[test]
+    fn to_uppercase() {
+        let line = Line::from(vec!["naïve ".red(), "straße".bold()])
+            .style(Style::new().on_blue())
+            .right_aligned();
+        let upper = line.to_uppercase();
+        assert_eq!(
+            upper,
+            Line::from(vec!["NAÏVE ".red(), "STRASSE".bold()])
+                .style(Style::new().on_blue())
+                .right_aligned()
+        );
+        assert_eq!(upper.width(), 13);
+        assert_eq!(line.width(), 12);
+    }
+
+    #[test]
+    fn to_lowercase() {
+        let line = Line::from(vec!["ÀB".red(), "C".into()]).centered();
+        assert_eq!(
+            line.to_lowercase(),
+            Line::from(vec!["àb".red(), "c".into()]).centered()
+        );
+    }
+
     #[test]
     fn reset_style() {
         let line =
@@ -965,6 +1213,51 @@ mod tests {
         assert_eq!(Style::reset(), line.style);
     }
 
+    #[rstest]
+    #[case::no_tabs(vec!["abc".into()], 8, vec!["abc".into()])]
+    #[case::leading(vec!["\tabc".into()], 4, vec!["    abc".into()])]
+    #[case::to_next_stop(vec!["ab\tc".into()], 4, vec!["ab  c".into()])]
+    #[case::at_stop(vec!["abcd\te".into()], 4, vec!["abcd    e".into()])]
+    #[case::consecutive(vec!["a\t\tb".into()], 4, vec!["a       b".into()])]
+    #[case::across_spans(vec!["ab".red(), "c\td".into()], 8, vec!["ab".red(), "c     d".into()])]
+    #[case::wide_graphemes(vec!["你\tb".into()], 4, vec!["你  b".into()])]
+    #[case::zero_width(vec!["a\tb".into()], 0, vec!["ab".into()])]
+    fn expand_tabs(
+        #[case] spans: Vec<Span<'static>>,
+        #[case] tab_width: usize,
+        #[case] expected: Vec<Span<'static>>,
+    ) {
+        let line = Line::from(spans).expand_tabs(tab_width);
+        assert_eq!(line, Line::from(expected));
+    }
+
+    #[test]
+    fn gradient() {
+        let start = Color::Rgb(0, 0, 0);
+        let end = Color::Rgb(0, 0, 90);
+        // the wide grapheme takes two columns, so "b" is at column 3
+        let line = Line::gradient("a你b", start, end);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::styled("a", Color::Rgb(0, 0, 0)),
+                Span::styled("你", Color::Rgb(0, 0, 30)),
+                Span::styled("b", Color::Rgb(0, 0, 90)),
+            ])
+        );
+        assert_eq!(line.width(), 4);
+
+        assert_eq!(Line::gradient("", start, end), Line::default());
+        assert_eq!(
+            Line::gradient("a\nb", start, end),
+            Line::from(vec![Span::styled("a", start), Span::styled("b", end)])
+        );
+        assert_eq!(
+            Line::gradient("a", start, end),
+            Line::from(Span::styled("a", start))
+        );
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(Line::default().green().style, Color::Green.into());
@@ -1193,6 +1486,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn diff_unchanged() {
+        let line = Line::from(vec!["Hello ".red(), "world".into()]);
+        assert!(line.diff(&line.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_changed_symbols() {
+        let changes = Line::from("abcd").diff(&Line::from("abXd"));
+        assert_eq!(
+            changes,
+            [CellChange {
+                column: 2,
+                cell: Cell::new("X"),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_changed_style() {
+        let changes = Line::from("ab").diff(&Line::from(vec!["a".into(), "b".red()]));
+        let mut cell = Cell::new("b");
+        cell.set_fg(Color::Red);
+        assert_eq!(changes, [CellChange { column: 1, cell }]);
+    }
+
+    #[test]
+    fn diff_different_widths() {
+        let changes = Line::from("abc").diff(&Line::from("a"));
+        assert_eq!(
+            changes,
+            [
+                CellChange {
+                    column: 1,
+                    cell: Cell::new(" "),
+                },
+                CellChange {
+                    column: 2,
+                    cell: Cell::new(" "),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_alignment() {
+        let changes = Line::from("ab").diff(&Line::from("b").right_aligned());
+        assert_eq!(
+            changes,
+            [CellChange {
+                column: 0,
+                cell: Cell::new(" "),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_wide_grapheme() {
+        let changes = Line::from("ab").diff(&Line::from("コ"));
+        assert_eq!(
+            changes,
+            [CellChange {
+                column: 0,
+                cell: Cell::new("コ"),
+            }]
+        );
+    }
+
     mod widget {
         use unicode_segmentation::UnicodeSegmentation;
         use unicode_width::UnicodeWidthStr;
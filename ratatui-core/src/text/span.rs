@@ -102,6 +102,8 @@ pub struct Span<'a> {
     pub style: Style,
     /// The content of the span as a Clone-on-write string.
     pub content: Cow<'a, str>,
+    /// The URL the span links to, see [`Span::hyperlink`].
+    hyperlink: Option<Cow<'a, str>>,
 }
 
 impl fmt::Debug for Span<'_> {
@@ -114,6 +116,9 @@ impl fmt::Debug for Span<'_> {
         if self.style != Style::default() {
             self.style.fmt_stylize(f)?;
         }
+        if let Some(hyperlink) = &self.hyperlink {
+            write!(f, ".hyperlink({hyperlink:?})")?;
+        }
         Ok(())
     }
 }
@@ -136,6 +141,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            hyperlink: None,
         }
     }
 
@@ -169,6 +175,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: style.into(),
+            hyperlink: None,
         }
     }
 
@@ -223,6 +230,59 @@ impl<'a> Span<'a> {
         self
     }
 
+    /// Sets the URL the span links to.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// When the `hyperlinks` feature is enabled, the URL is set on each cell the span is rendered
+    /// to. Backends that support OSC 8 hyperlinks display the span as a clickable link, while
+    /// other backends display the text as usual. Without the feature, the URL is kept on the span
+    /// but not rendered.
+    ///
+    /// The URL is carried by the [`StyledGrapheme`]s of the span, so it is also rendered by
+    /// [`Buffer::set_span`], [`Buffer::set_line`] and the widgets that render text grapheme by
+    /// grapheme, such as `Paragraph`.
+    ///
+    /// Accepts any type that can be converted to [`Cow<str>`] (e.g. `&str`, `String`, `&String`,
+    /// etc.).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Span};
+    ///
+    /// let span = Span::raw("ratatui.rs")
+    ///     .underlined()
+    ///     .hyperlink("https://ratatui.rs");
+    /// ```
+    ///
+    /// [`Buffer::set_span`]: crate::buffer::Buffer::set_span
+    /// [`Buffer::set_line`]: crate::buffer::Buffer::set_line
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hyperlink<T>(mut self, url: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.hyperlink = Some(url.into());
+        self
+    }
+
+    /// Returns the URL the span links to, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Span;
+    ///
+    /// let span = Span::raw("ratatui.rs").hyperlink("https://ratatui.rs");
+    /// assert_eq!(span.link(), Some("https://ratatui.rs"));
+    /// assert_eq!(Span::raw("text").link(), None);
+    /// ```
+    #[must_use]
+    pub fn link(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+
     /// Patches the style of the Span, adding modifiers from the given style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -281,6 +341,70 @@ impl<'a> Span<'a> {
         self.content.width()
     }
 
+    /// Returns `true` if the content of this span is empty.
+    ///
+    /// This is cheaper than checking that the [width](Span::width) is `0`, as the width is
+    /// computed from the graphemes of the content on each call. Note that a span containing only
+    /// zero-width characters is not empty, but has a width of `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Span;
+    ///
+    /// assert!(Span::raw("").is_empty());
+    /// assert!(!Span::raw("test").is_empty());
+    /// assert!(!Span::raw("\u{200B}").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    /// Returns a copy of this span with its content converted to uppercase.
+    ///
+    /// The conversion uses the Unicode case mapping of [`str::to_uppercase`], which may change the
+    /// number of characters and therefore the width of the span (e.g. `ß` becomes `SS`). The style
+    /// of the span is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Span};
+    ///
+    /// let span = "straße".red().to_uppercase();
+    /// assert_eq!(span, "STRASSE".red());
+    /// ```
+    #[must_use = "`to_uppercase` returns a new span without modifying the original"]
+    pub fn to_uppercase(&self) -> Self {
+        Self {
+            style: self.style,
+            content: Cow::Owned(self.content.to_uppercase()),
+            hyperlink: self.hyperlink.clone(),
+        }
+    }
+
+    /// Returns a copy of this span with its content converted to lowercase.
+    ///
+    /// The conversion uses the Unicode case mapping of [`str::to_lowercase`], which may change the
+    /// number of characters and therefore the width of the span. The style of the span is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Span};
+    ///
+    /// let span = "ÉTÉ".bold().to_lowercase();
+    /// assert_eq!(span, "été".bold());
+    /// ```
+    #[must_use = "`to_lowercase` returns a new span without modifying the original"]
+    pub fn to_lowercase(&self) -> Self {
+        Self {
+            style: self.style,
+            content: Cow::Owned(self.content.to_lowercase()),
+            hyperlink: self.hyperlink.clone(),
+        }
+    }
+
     /// Returns an iterator over the graphemes held by this span.
     ///
     /// `base_style` is the [`Style`] that will be patched with the `Span`'s `style` to get the
@@ -323,7 +447,65 @@ impl<'a> Span<'a> {
             .as_ref()
             .graphemes(true)
             .filter(|g| !g.contains(char::is_control))
-            .map(move |g| StyledGrapheme { symbol: g, style })
+            .map(move |g| StyledGrapheme {
+                symbol: g,
+                style,
+                link: self.hyperlink.as_deref(),
+            })
+    }
+
+    /// Splits this span at the given display column.
+    ///
+    /// Returns the part of the span before the column and the part from the column onwards. Both
+    /// parts keep the style of this span. The span is only split between graphemes, so a
+    /// multi-width grapheme (e.g. `コ`) that the column falls inside of goes entirely to the
+    /// right part. If the column is past the end of the span, the right part is empty.
+    ///
+    /// This is useful for implementing a text cursor inside a span.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Span};
+    ///
+    /// let span = "aコb".red();
+    /// assert_eq!(span.split_at_width(1), ("a".red(), "コb".red()));
+    /// assert_eq!(span.split_at_width(2), ("a".red(), "コb".red()));
+    /// assert_eq!(span.split_at_width(3), ("aコ".red(), "b".red()));
+    /// ```
+    pub fn split_at_width(&self, column: u16) -> (Self, Self) {
+        let column = usize::from(column);
+        let mut width = 0;
+        let index = self
+            .content
+            .grapheme_indices(true)
+            .find(|(_, grapheme)| {
+                width += grapheme.width();
+                width > column
+            })
+            .map_or(self.content.len(), |(index, _)| index);
+        let (left, right) = match &self.content {
+            Cow::Borrowed(content) => {
+                let (left, right) = content.split_at(index);
+                (Cow::Borrowed(left), Cow::Borrowed(right))
+            }
+            Cow::Owned(content) => {
+                let (left, right) = content.split_at(index);
+                (Cow::Owned(left.to_string()), Cow::Owned(right.to_string()))
+            }
+        };
+        (
+            Self {
+                style: self.style,
+                content: left,
+                hyperlink: self.hyperlink.clone(),
+            },
+            Self {
+                style: self.style,
+                content: right,
+                hyperlink: self.hyperlink.clone(),
+            },
+        )
     }
 
     /// Converts this Span into a left-aligned [`Line`]
@@ -424,6 +606,9 @@ impl Widget for Span<'_> {
 
 impl Widget for &Span<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.is_empty() {
+            return;
+        }
         let area = area.intersection(buf.area);
         if area.is_empty() {
             return;
@@ -458,6 +643,10 @@ impl Widget for &Span<'_> {
                     .set_symbol(grapheme.symbol)
                     .set_style(grapheme.style);
             }
+            #[cfg(feature = "hyperlinks")]
+            if i == 0 || symbol_width > 0 {
+                buf[(x, y)].set_hyperlink(self.hyperlink.as_deref());
+            }
 
             // multi-width graphemes must clear the cells of characters that are hidden by the
             // grapheme, otherwise the hidden characters will be re-rendered if the grapheme is
@@ -618,6 +807,35 @@ mod tests {
         assert_eq!(span.style, Style::new().red().on_yellow().bold());
     }
 
+    #[rstest]
+    #[case::start("test", 0, "", "test")]
+    #[case::middle("test", 2, "te", "st")]
+    #[case::end("test", 4, "test", "")]
+    #[case::past_end("test", 10, "test", "")]
+    #[case::empty("", 1, "", "")]
+    #[case::before_wide("aコb", 1, "a", "コb")]
+    #[case::inside_wide("aコb", 2, "a", "コb")]
+    #[case::after_wide("aコb", 3, "aコ", "b")]
+    #[case::combining("e\u{303}x", 1, "e\u{303}", "x")]
+    fn split_at_width(
+        #[case] content: &str,
+        #[case] column: u16,
+        #[case] left: &str,
+        #[case] right: &str,
+    ) {
+        let style = Style::new().red().on_blue();
+        let span = Span::styled(content, style);
+        assert_eq!(
+            span.split_at_width(column),
+            (Span::styled(left, style), Span::styled(right, style))
+        );
+        let span = Span::styled(content.to_string(), style);
+        assert_eq!(
+            span.split_at_width(column),
+            (Span::styled(left, style), Span::styled(right, style))
+        );
+    }
+
     #[test]
     fn width() {
         assert_eq!(Span::raw("").width(), 0);
@@ -627,6 +845,36 @@ mod tests {
         assert_eq!(Span::raw("test\ncontent").width(), 12);
     }
 
+    #[test]
+    fn is_empty() {
+        assert!(Span::raw("").is_empty());
+        assert!(Span::default().is_empty());
+        assert!(!Span::raw(" ").is_empty());
+        assert!(!Span::raw("\u{200B}").is_empty());
+        assert!(!Span::raw("test").is_empty());
+    }
+
+    #[test]
+    fn to_uppercase() {
+        let span = Span::styled("héllo straße", Style::new().red().bold());
+        let upper = span.to_uppercase();
+        assert_eq!(
+            upper,
+            Span::styled("HÉLLO STRASSE", Style::new().red().bold())
+        );
+        assert_eq!(upper.width(), 13);
+        assert_eq!(span.content, "héllo straße");
+    }
+
+    #[test]
+    fn to_lowercase() {
+        let span = Span::styled("ÉTÉ ΣΑΣ", Style::new().italic());
+        assert_eq!(
+            span.to_lowercase(),
+            Span::styled("été σας", Style::new().italic())
+        );
+    }
+
     #[test]
     fn stylize() {
         let span = Span::raw("test content").green();
@@ -838,6 +1086,25 @@ mod tests {
             span.render(buf.area, &mut buf);
             assert_eq!(buf.content(), [Cell::new("a"), Cell::new("b")]);
         }
+
+        #[cfg(feature = "hyperlinks")]
+        #[test]
+        fn render_hyperlink() {
+            let span = Span::raw("ab").hyperlink("https://ratatui.rs");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+            buf[(2, 0)].set_hyperlink(Some("https://example.com"));
+            span.render(buf.area, &mut buf);
+            Span::raw("c").render(Rect::new(2, 0, 1, 1), &mut buf);
+            let hyperlinks: Vec<_> = buf
+                .content()
+                .iter()
+                .map(|cell| cell.hyperlink.as_deref())
+                .collect();
+            assert_eq!(
+                hyperlinks,
+                [Some("https://ratatui.rs"), Some("https://ratatui.rs"), None]
+            );
+        }
     }
 
     /// Regression test for <https://github.com/ratatui/ratatui/issues/1160> One line contains
@@ -897,4 +1164,13 @@ mod tests {
     fn debug(#[case] span: Span, #[case] expected: &str) {
         assert_eq!(format!("{span:?}"), expected);
     }
+
+    #[test]
+    fn debug_hyperlink() {
+        let span = Span::raw("test").green().hyperlink("https://ratatui.rs");
+        assert_eq!(
+            format!("{span:?}"),
+            r#"Span::from("test").green().hyperlink("https://ratatui.rs")"#
+        );
+    }
 }
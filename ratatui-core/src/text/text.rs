@@ -185,7 +185,7 @@ use crate::{
 /// # fn render(area: Rect, buf: &mut Buffer) {
 /// let text = Text::from("The first line\nThe second line");
 /// let paragraph = Paragraph::new(text)
-///     .wrap(Wrap { trim: true })
+///     .wrap(Wrap::new().trim(true))
 ///     .scroll((1, 1))
 ///     .render(area, buf);
 /// # }
@@ -401,6 +401,30 @@ impl<'a> Text<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Replaces the tab characters of each line with spaces up to the next tab stop.
+    ///
+    /// See [`Line::expand_tabs`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Text;
+    ///
+    /// let text = Text::from("a\tb\nab\tc").expand_tabs(4);
+    /// assert_eq!(text, Text::from("a   b\nab  c"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expand_tabs(mut self, tab_width: usize) -> Self {
+        self.lines = self
+            .lines
+            .into_iter()
+            .map(|line| line.expand_tabs(tab_width))
+            .collect();
+        self
+    }
+
     /// Sets the alignment for this text.
     ///
     /// Defaults to: [`None`], meaning the alignment is determined by the rendering widget.
@@ -813,6 +837,12 @@ mod tests {
         assert_eq!(text, expected_text);
     }
 
+    #[test]
+    fn expand_tabs() {
+        let text = Text::from("\tfn main() {\n\t\tprintln!();\n}").expand_tabs(4);
+        assert_eq!(text, Text::from("    fn main() {\n        println!();\n}"));
+    }
+
     #[test]
     fn reset_style() {
         let style = Style::new().yellow().italic();
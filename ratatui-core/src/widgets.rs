@@ -2,7 +2,11 @@
 //! The `widgets` module contains the `Widget` and `StatefulWidget` traits, which are used to
 //! render UI elements on the screen.
 
-pub use self::{stateful_widget::StatefulWidget, widget::Widget};
+pub use self::{
+    clipped::Clipped, size_hint::SizeHint, stateful_widget::StatefulWidget, widget::Widget,
+};
 
+mod clipped;
+mod size_hint;
 mod stateful_widget;
 mod widget;
@@ -0,0 +1,107 @@
+use std::mem;
+
+use crate::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// A widget adapter which guarantees that the wrapped widget does not draw outside of its area.
+///
+/// Most widgets only draw inside the area they are given, but some widgets (e.g. widgets from third
+/// party crates or widgets with bugs) may draw outside of it. `Clipped` renders the wrapped widget
+/// into a temporary [`Buffer`] which is the size of the area, and merges the result back into the
+/// buffer. As the area of the temporary buffer is the area of the widget, anything the widget
+/// draws based on the area of the buffer, or past the edges of the buffer using methods which clip
+/// their content (e.g. a string overflowing the right edge with [`Buffer::set_string`]), is
+/// discarded. Indexing the temporary buffer outside of its area still panics, as with any other
+/// buffer.
+///
+/// The temporary buffer is allocated on each render, so prefer rendering widgets directly when
+/// they are known to respect their area.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     style::{Style, Stylize},
+///     text::Line,
+///     widgets::{Clipped, Widget},
+/// };
+///
+/// /// A widget which styles the whole buffer instead of its area
+/// struct Unbounded;
+///
+/// impl Widget for Unbounded {
+///     fn render(self, _area: Rect, buf: &mut Buffer) {
+///         buf.set_style(buf.area, Style::new().red());
+///     }
+/// }
+///
+/// let mut buf = Buffer::with_lines(["abc"]);
+/// Clipped(Unbounded).render(Rect::new(1, 0, 1, 1), &mut buf);
+/// let expected = Line::from(vec!["a".into(), "b".red(), "c".into()]);
+/// assert_eq!(buf, Buffer::with_lines([expected]));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Clipped<W>(pub W);
+
+impl<W: Widget> Widget for Clipped<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        // the cells are moved into the temporary buffer so that the widget can draw over them
+        let mut clipped = Buffer::empty(area);
+        for position in area.positions() {
+            mem::swap(&mut buf[position], &mut clipped[position]);
+        }
+        self.0.render(area, &mut clipped);
+        for position in area.positions() {
+            mem::swap(&mut buf[position], &mut clipped[position]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        style::{Style, Stylize},
+        text::Line,
+    };
+
+    /// A widget which styles the whole buffer and draws a line wider than its area
+    struct Unbounded;
+
+    impl Widget for Unbounded {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            buf.set_style(buf.area, Style::new().red());
+            buf.set_string(area.x, area.y, "Hello", Style::new());
+        }
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::with_lines(["abcdef", "abcdef"]);
+        Clipped(Unbounded).render(Rect::new(1, 1, 3, 1), &mut buf);
+        let expected = Buffer::with_lines([
+            Line::from("abcdef"),
+            Line::from(vec!["a".into(), "Hel".red(), "ef".into()]),
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_keeps_existing_content() {
+        let mut buf = Buffer::with_lines(["abcdef"]);
+        Clipped(Line::from("x")).render(Rect::new(1, 0, 3, 1), &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["axcdef"]));
+    }
+
+    #[test]
+    fn render_outside_buffer() {
+        let mut buf = Buffer::with_lines(["abc"]);
+        Clipped(Unbounded).render(Rect::new(5, 5, 3, 1), &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["abc"]));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::layout::Constraint;
+
+/// A widget that can report the size it would like to be rendered at.
+///
+/// The size hint is returned as a pair of [`Constraint`]s for the width and the height of the
+/// widget, so that it can be passed directly to a [`Layout`]. This is useful for sizing areas
+/// based on their content, e.g. a popup that is just large enough for its text.
+///
+/// Implementing this trait is optional and does not change how a widget is rendered. The hint
+/// is only a preference, the widget is still expected to render in any area it is given.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::{
+///     layout::{Constraint, Layout, Rect},
+///     widgets::SizeHint,
+/// };
+///
+/// struct Logo;
+///
+/// impl SizeHint for Logo {
+///     fn size_hint(&self) -> (Constraint, Constraint) {
+///         (Constraint::Length(12), Constraint::Length(3))
+///     }
+/// }
+///
+/// let (width, height) = Logo.size_hint();
+/// let [row] = Layout::vertical([height]).areas(Rect::new(0, 0, 80, 24));
+/// let [area] = Layout::horizontal([width]).areas(row);
+/// assert_eq!(area, Rect::new(0, 0, 12, 3));
+/// ```
+///
+/// [`Layout`]: crate::layout::Layout
+pub trait SizeHint {
+    /// Returns the preferred width and height of the widget as constraints.
+    fn size_hint(&self) -> (Constraint, Constraint);
+}
+
+impl<W: SizeHint + ?Sized> SizeHint for &W {
+    fn size_hint(&self) -> (Constraint, Constraint) {
+        (**self).size_hint()
+    }
+}
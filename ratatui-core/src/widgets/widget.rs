@@ -61,6 +61,28 @@ pub trait Widget {
     fn render(self, area: Rect, buf: &mut Buffer)
     where
         Self: Sized;
+
+    /// Renders the widget into a new [`Buffer`] covering the given area and returns it.
+    ///
+    /// This is a shortcut for creating an empty buffer and rendering the widget into it, which is
+    /// useful in tests and for compositing widgets into separate layers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, widgets::Widget};
+    ///
+    /// let buffer = "Hello".render_buffer(Rect::new(0, 0, 5, 1));
+    /// assert_eq!(buffer, Buffer::with_lines(["Hello"]));
+    /// ```
+    fn render_buffer(self, area: Rect) -> Buffer
+    where
+        Self: Sized,
+    {
+        let mut buf = Buffer::empty(area);
+        self.render(area, &mut buf);
+        buf
+    }
 }
 
 /// Renders a string slice as a widget.
@@ -120,6 +142,14 @@ mod tests {
         assert_eq!(buf, Buffer::with_lines(["Hello               "]));
     }
 
+    #[test]
+    fn render_buffer() {
+        let buf = Greeting.render_buffer(Rect::new(2, 3, 7, 1));
+        let mut expected = Buffer::with_lines(["Hello  "]);
+        expected.area = Rect::new(2, 3, 7, 1);
+        assert_eq!(buf, expected);
+    }
+
     #[rstest]
     fn render_str(mut buf: Buffer) {
         "hello world".render(buf.area, &mut buf);
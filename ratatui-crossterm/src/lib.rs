@@ -29,7 +29,7 @@ use crossterm::{
     terminal::{self, Clear},
 };
 use ratatui_core::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{Backend, Capabilities, ClearType, WindowSize},
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -169,6 +169,8 @@ where
         #[cfg(feature = "underline-color")]
         let mut underline_color = Color::Reset;
         let mut modifier = Modifier::empty();
+        #[cfg(feature = "hyperlinks")]
+        let mut hyperlink: Option<&str> = None;
         let mut last_pos: Option<Position> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
@@ -201,10 +203,20 @@ where
                 queue!(self.writer, SetUnderlineColor(color))?;
                 underline_color = cell.underline_color;
             }
+            #[cfg(feature = "hyperlinks")]
+            if cell.hyperlink.as_deref() != hyperlink {
+                hyperlink = cell.hyperlink.as_deref();
+                queue!(self.writer, Print(Hyperlink(hyperlink)))?;
+            }
 
             queue!(self.writer, Print(cell.symbol()))?;
         }
 
+        #[cfg(feature = "hyperlinks")]
+        if hyperlink.is_some() {
+            queue!(self.writer, Print(Hyperlink(None)))?;
+        }
+
         #[cfg(feature = "underline-color")]
         return queue!(
             self.writer,
@@ -290,6 +302,17 @@ where
         self.writer.flush()
     }
 
+    /// Returns the capabilities of the terminal, detected from the environment variables.
+    ///
+    /// Underline colors and hyperlinks are only reported when the corresponding features of this
+    /// crate are enabled. See [`Capabilities::from_env`].
+    fn capabilities(&self) -> Capabilities {
+        let mut capabilities = Capabilities::from_env();
+        capabilities.underline_color &= cfg!(feature = "underline-color");
+        capabilities.hyperlinks &= cfg!(feature = "hyperlinks");
+        capabilities
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
@@ -387,6 +410,11 @@ impl FromCrossterm<CrosstermColor> for Color {
     }
 }
 
+/// The modifiers that underline the text, only one of which is displayed at a time
+const UNDERLINES: Modifier = Modifier::UNDERLINED
+    .union(Modifier::DOUBLE_UNDERLINE)
+    .union(Modifier::CURLY_UNDERLINE);
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -422,8 +450,19 @@ impl ModifierDiff {
         if removed.contains(Modifier::ITALIC) {
             queue!(w, SetAttribute(CrosstermAttribute::NoItalic))?;
         }
-        if removed.contains(Modifier::UNDERLINED) {
-            queue!(w, SetAttribute(CrosstermAttribute::NoUnderline))?;
+        let underline = self.to & UNDERLINES;
+        if self.from & UNDERLINES != underline {
+            // Only one underline style is displayed, so the most specific one is applied
+            let attribute = if underline.contains(Modifier::CURLY_UNDERLINE) {
+                CrosstermAttribute::Undercurled
+            } else if underline.contains(Modifier::DOUBLE_UNDERLINE) {
+                CrosstermAttribute::DoubleUnderlined
+            } else if underline.contains(Modifier::UNDERLINED) {
+                CrosstermAttribute::Underlined
+            } else {
+                CrosstermAttribute::NoUnderline
+            };
+            queue!(w, SetAttribute(attribute))?;
         }
         if removed.contains(Modifier::CROSSED_OUT) {
             queue!(w, SetAttribute(CrosstermAttribute::NotCrossedOut))?;
@@ -431,6 +470,9 @@ impl ModifierDiff {
         if removed.contains(Modifier::SLOW_BLINK) || removed.contains(Modifier::RAPID_BLINK) {
             queue!(w, SetAttribute(CrosstermAttribute::NoBlink))?;
         }
+        if removed.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CrosstermAttribute::NoHidden))?;
+        }
 
         let added = self.to - self.from;
         if added.contains(Modifier::REVERSED) {
@@ -442,9 +484,6 @@ impl ModifierDiff {
         if added.contains(Modifier::ITALIC) {
             queue!(w, SetAttribute(CrosstermAttribute::Italic))?;
         }
-        if added.contains(Modifier::UNDERLINED) {
-            queue!(w, SetAttribute(CrosstermAttribute::Underlined))?;
-        }
         if added.contains(Modifier::DIM) {
             queue!(w, SetAttribute(CrosstermAttribute::Dim))?;
         }
@@ -457,11 +496,28 @@ impl ModifierDiff {
         if added.contains(Modifier::RAPID_BLINK) {
             queue!(w, SetAttribute(CrosstermAttribute::RapidBlink))?;
         }
+        if added.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CrosstermAttribute::Hidden))?;
+        }
 
         Ok(())
     }
 }
 
+/// The OSC 8 escape sequence which starts a hyperlink to the given URL, or ends the current
+/// hyperlink when the URL is `None`.
+///
+/// See <https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda> for more information.
+#[cfg(feature = "hyperlinks")]
+struct Hyperlink<'a>(Option<&'a str>);
+
+#[cfg(feature = "hyperlinks")]
+impl std::fmt::Display for Hyperlink<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\x1b]8;;{}\x1b\\", self.0.unwrap_or_default())
+    }
+}
+
 impl FromCrossterm<CrosstermAttribute> for Modifier {
     fn from_crossterm(value: CrosstermAttribute) -> Self {
         // `Attribute*s*` (note the *s*) contains multiple `Attribute` We convert `Attribute` to
@@ -482,9 +538,13 @@ impl FromCrossterm<CrosstermAttributes> for Modifier {
         if value.has(CrosstermAttribute::Italic) {
             res |= Self::ITALIC;
         }
+        if value.has(CrosstermAttribute::DoubleUnderlined) {
+            res |= Self::DOUBLE_UNDERLINE;
+        }
+        if value.has(CrosstermAttribute::Undercurled) {
+            res |= Self::CURLY_UNDERLINE;
+        }
         if value.has(CrosstermAttribute::Underlined)
-            || value.has(CrosstermAttribute::DoubleUnderlined)
-            || value.has(CrosstermAttribute::Undercurled)
             || value.has(CrosstermAttribute::Underdotted)
             || value.has(CrosstermAttribute::Underdashed)
         {
@@ -522,7 +582,7 @@ impl FromCrossterm<ContentStyle> for Style {
             sub_modifier |= Modifier::CROSSED_OUT;
         }
         if value.attributes.has(CrosstermAttribute::NoUnderline) {
-            sub_modifier |= Modifier::UNDERLINED;
+            sub_modifier |= UNDERLINES;
         }
         if value.attributes.has(CrosstermAttribute::NoHidden) {
             sub_modifier |= Modifier::HIDDEN;
@@ -686,8 +746,8 @@ mod tests {
         #[case(CrosstermAttribute::NoUnderline, Modifier::empty())]
         #[case(CrosstermAttribute::OverLined, Modifier::empty())]
         #[case(CrosstermAttribute::NotOverLined, Modifier::empty())]
-        #[case(CrosstermAttribute::DoubleUnderlined, Modifier::UNDERLINED)]
-        #[case(CrosstermAttribute::Undercurled, Modifier::UNDERLINED)]
+        #[case(CrosstermAttribute::DoubleUnderlined, Modifier::DOUBLE_UNDERLINE)]
+        #[case(CrosstermAttribute::Undercurled, Modifier::CURLY_UNDERLINE)]
         #[case(CrosstermAttribute::Underdotted, Modifier::UNDERLINED)]
         #[case(CrosstermAttribute::Underdashed, Modifier::UNDERLINED)]
         #[case(CrosstermAttribute::Dim, Modifier::DIM)]
@@ -730,6 +790,36 @@ mod tests {
                 ratatui_modifier
             );
         }
+
+        #[rstest]
+        #[case::add_curly(Modifier::empty(), Modifier::CURLY_UNDERLINE, "\x1b[4:3m")]
+        #[case::change_style(Modifier::UNDERLINED, Modifier::DOUBLE_UNDERLINE, "\x1b[4:2m")]
+        #[case::remove(Modifier::CURLY_UNDERLINE, Modifier::empty(), "\x1b[24m")]
+        #[case::remove_one_style(
+            Modifier::UNDERLINED | Modifier::CURLY_UNDERLINE,
+            Modifier::UNDERLINED,
+            "\x1b[4m"
+        )]
+        #[case::unchanged(Modifier::DOUBLE_UNDERLINE, Modifier::DOUBLE_UNDERLINE | Modifier::BOLD, "\x1b[1m")]
+        fn queue_underline_styles(
+            #[case] from: Modifier,
+            #[case] to: Modifier,
+            #[case] expected: &str,
+        ) {
+            let mut output = Vec::new();
+            ModifierDiff { from, to }.queue(&mut output).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+        }
+
+        #[rstest]
+        #[case::add(Modifier::empty(), Modifier::HIDDEN, "\x1b[8m")]
+        #[case::remove(Modifier::HIDDEN, Modifier::empty(), "\x1b[28m")]
+        #[case::unchanged(Modifier::HIDDEN, Modifier::HIDDEN, "")]
+        fn queue_hidden(#[case] from: Modifier, #[case] to: Modifier, #[case] expected: &str) {
+            let mut output = Vec::new();
+            ModifierDiff { from, to }.queue(&mut output).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+        }
     }
 
     #[rstest]
@@ -814,4 +904,65 @@ mod tests {
             Style::default().underline_color(Color::Red)
         );
     }
+
+    #[test]
+    #[cfg(feature = "hyperlinks")]
+    fn draw_hyperlinks() {
+        let mut a = Cell::new("a");
+        a.set_hyperlink(Some("https://ratatui.rs"));
+        let mut b = a.clone();
+        b.set_symbol("b");
+        let c = Cell::new("c");
+        let cells = [(0, 0, &a), (1, 0, &b), (2, 0, &c)];
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw(cells.into_iter()).unwrap();
+
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains("\x1b]8;;https://ratatui.rs\x1b\\ab\x1b]8;;\x1b\\c"));
+    }
+
+    #[test]
+    #[cfg(feature = "underline-color")]
+    fn draw_underline_color() {
+        let mut red = Cell::new("a");
+        red.underline_color = Color::Red;
+        let mut also_red = red.clone();
+        also_red.set_symbol("b");
+        let mut blue = Cell::new("c");
+        blue.underline_color = Color::Blue;
+        let reset = Cell::new("d");
+        let cells = [
+            (0, 0, &red),
+            (1, 0, &also_red),
+            (2, 0, &blue),
+            (3, 0, &reset),
+        ];
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw(cells.into_iter()).unwrap();
+
+        // the underline color is only emitted when it changes between cells
+        let mut expected = Vec::new();
+        queue!(
+            expected,
+            MoveTo(0, 0),
+            SetUnderlineColor(CrosstermColor::DarkRed),
+            Print("a"),
+            Print("b"),
+            SetUnderlineColor(CrosstermColor::DarkBlue),
+            Print("c"),
+            SetUnderlineColor(CrosstermColor::Reset),
+            Print("d"),
+            SetForegroundColor(CrosstermColor::Reset),
+            SetBackgroundColor(CrosstermColor::Reset),
+            SetUnderlineColor(CrosstermColor::Reset),
+            SetAttribute(CrosstermAttribute::Reset),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(backend.writer).unwrap(),
+            String::from_utf8(expected).unwrap()
+        );
+    }
 }
@@ -20,7 +20,7 @@ use std::{
 };
 
 use ratatui_core::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{Backend, Capabilities, ClearType, WindowSize},
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -251,6 +251,18 @@ where
         self.writer.flush()
     }
 
+    /// Returns the capabilities of the terminal, detected from the environment variables.
+    ///
+    /// Underline colors, curly underlines and hyperlinks are not written by this backend, so they
+    /// are reported as unsupported. See [`Capabilities::from_env`].
+    fn capabilities(&self) -> Capabilities {
+        let mut capabilities = Capabilities::from_env();
+        capabilities.underline_color = false;
+        capabilities.undercurl = false;
+        capabilities.hyperlinks = false;
+        capabilities
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         write!(
@@ -451,7 +463,12 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(Modifier::ITALIC) {
             write!(f, "{}", termion::style::NoItalic)?;
         }
-        if remove.contains(Modifier::UNDERLINED) {
+        // termion doesn't support the underline styles, so they fall back to a plain underline
+        let underlines =
+            Modifier::UNDERLINED | Modifier::DOUBLE_UNDERLINE | Modifier::CURLY_UNDERLINE;
+        let was_underlined = self.from.intersects(underlines);
+        let is_underlined = self.to.intersects(underlines);
+        if was_underlined && !is_underlined {
             write!(f, "{}", termion::style::NoUnderline)?;
         }
         if remove.contains(Modifier::DIM) {
@@ -469,6 +486,10 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(Modifier::SLOW_BLINK) || remove.contains(Modifier::RAPID_BLINK) {
             write!(f, "{}", termion::style::NoBlink)?;
         }
+        if remove.contains(Modifier::HIDDEN) {
+            // termion has no conceal style, so the SGR sequence is written directly
+            write!(f, "\x1B[28m")?;
+        }
 
         let add = self.to - self.from;
         if add.contains(Modifier::REVERSED) {
@@ -480,7 +501,7 @@ impl fmt::Display for ModifierDiff {
         if add.contains(Modifier::ITALIC) {
             write!(f, "{}", termion::style::Italic)?;
         }
-        if add.contains(Modifier::UNDERLINED) {
+        if is_underlined && !was_underlined {
             write!(f, "{}", termion::style::Underline)?;
         }
         if add.contains(Modifier::DIM) {
@@ -492,6 +513,9 @@ impl fmt::Display for ModifierDiff {
         if add.contains(Modifier::SLOW_BLINK) || add.contains(Modifier::RAPID_BLINK) {
             write!(f, "{}", termion::style::Blink)?;
         }
+        if add.contains(Modifier::HIDDEN) {
+            write!(f, "\x1B[8m")?;
+        }
 
         Ok(())
     }
@@ -706,6 +730,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn underline_styles_fall_back_to_underline() {
+        let diff = |from, to| ModifierDiff { from, to }.to_string();
+        let underline = termion::style::Underline.to_string();
+        let no_underline = termion::style::NoUnderline.to_string();
+        assert_eq!(
+            diff(Modifier::empty(), Modifier::CURLY_UNDERLINE),
+            underline
+        );
+        assert_eq!(
+            diff(Modifier::DOUBLE_UNDERLINE, Modifier::empty()),
+            no_underline
+        );
+        assert_eq!(
+            diff(Modifier::UNDERLINED, Modifier::DOUBLE_UNDERLINE),
+            String::new()
+        );
+    }
+
+    #[test]
+    fn hidden() {
+        let diff = |from, to| ModifierDiff { from, to }.to_string();
+        assert_eq!(diff(Modifier::empty(), Modifier::HIDDEN), "\x1B[8m");
+        assert_eq!(diff(Modifier::HIDDEN, Modifier::empty()), "\x1B[28m");
+        assert_eq!(diff(Modifier::HIDDEN, Modifier::HIDDEN), "");
+    }
+
     #[test]
     fn from_termion_style() {
         assert_eq!(Modifier::from_termion(tstyle::Invert), Modifier::REVERSED);
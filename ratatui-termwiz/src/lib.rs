@@ -14,23 +14,37 @@
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
 
-use std::{error::Error, io};
+use std::{error::Error, io, sync::OnceLock};
 
 use ratatui_core::{
-    backend::{Backend, WindowSize},
+    backend::{Backend, Capabilities as BackendCapabilities, WindowSize},
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
 };
 pub use termwiz;
 use termwiz::{
-    caps::Capabilities,
+    caps::{Capabilities, ColorLevel},
     cell::{AttributeChange, Blink, CellAttributes, Intensity, Underline},
     color::{AnsiColor, ColorAttribute, ColorSpec, LinearRgba, RgbColor, SrgbaTuple},
     surface::{Change, CursorVisibility, Position as TermwizPosition},
     terminal::{buffered::BufferedTerminal, ScreenSize, SystemTerminal, Terminal},
 };
 
+/// Converts the capabilities detected by termwiz, if it could detect them, into the capabilities
+/// of the backend.
+///
+/// Termwiz doesn't detect curly underlines, so they are detected from the environment variables.
+fn backend_capabilities(caps: Option<&Capabilities>) -> BackendCapabilities {
+    let mut capabilities = BackendCapabilities::default();
+    capabilities.truecolor =
+        caps.is_some_and(|caps| matches!(caps.color_level(), ColorLevel::TrueColor));
+    capabilities.undercurl = BackendCapabilities::from_env().undercurl;
+    capabilities.hyperlinks =
+        cfg!(feature = "hyperlinks") && caps.is_some_and(Capabilities::hyperlinks);
+    capabilities
+}
+
 /// A [`Backend`] implementation that uses [Termwiz] to render to the terminal.
 ///
 /// The `TermwizBackend` struct is a wrapper around a [`BufferedTerminal`], which is used to send
@@ -70,6 +84,8 @@ use termwiz::{
 /// [Examples]: https://github.com/ratatui/ratatui/tree/main/ratatui/examples/README.md
 pub struct TermwizBackend {
     buffered_terminal: BufferedTerminal<SystemTerminal>,
+    /// The capabilities of the terminal, detected once when they are first needed
+    capabilities: OnceLock<BackendCapabilities>,
 }
 
 impl TermwizBackend {
@@ -94,17 +110,25 @@ impl TermwizBackend {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn new() -> Result<Self, Box<dyn Error>> {
-        let mut buffered_terminal =
-            BufferedTerminal::new(SystemTerminal::new(Capabilities::new_from_env()?)?)?;
+        let caps = Capabilities::new_from_env()?;
+        let capabilities = OnceLock::from(backend_capabilities(Some(&caps)));
+        let mut buffered_terminal = BufferedTerminal::new(SystemTerminal::new(caps)?)?;
         buffered_terminal.terminal().set_raw_mode()?;
         buffered_terminal.terminal().enter_alternate_screen()?;
-        Ok(Self { buffered_terminal })
+        Ok(Self {
+            buffered_terminal,
+            capabilities,
+        })
     }
 
     /// Creates a new Termwiz backend instance with the given buffered terminal.
+    ///
+    /// The capabilities of the terminal are detected from the environment the first time they are
+    /// queried, see [`Backend::capabilities`].
     pub const fn with_buffered_terminal(instance: BufferedTerminal<SystemTerminal>) -> Self {
         Self {
             buffered_terminal: instance,
+            capabilities: OnceLock::new(),
         }
     }
 
@@ -152,7 +176,11 @@ impl Backend for TermwizBackend {
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Underline(
-                    if cell.modifier.contains(Modifier::UNDERLINED) {
+                    if cell.modifier.contains(Modifier::CURLY_UNDERLINE) {
+                        Underline::Curly
+                    } else if cell.modifier.contains(Modifier::DOUBLE_UNDERLINE) {
+                        Underline::Double
+                    } else if cell.modifier.contains(Modifier::UNDERLINED) {
                         Underline::Single
                     } else {
                         Underline::None
@@ -185,6 +213,14 @@ impl Backend for TermwizBackend {
                     },
                 )));
 
+            #[cfg(feature = "hyperlinks")]
+            self.buffered_terminal
+                .add_change(Change::Attribute(AttributeChange::Hyperlink(
+                    cell.hyperlink
+                        .as_deref()
+                        .map(|url| std::sync::Arc::new(termwiz::hyperlink::Hyperlink::new(url))),
+                )));
+
             self.buffered_terminal.add_change(cell.symbol());
         }
         Ok(())
@@ -258,6 +294,18 @@ impl Backend for TermwizBackend {
         Ok(())
     }
 
+    /// Returns the capabilities of the terminal, detected by termwiz from the environment.
+    ///
+    /// The capabilities are detected when the backend is created with [`TermwizBackend::new`], or
+    /// the first time this is called for a backend created with a custom [`BufferedTerminal`].
+    /// Hyperlinks are only reported when the `hyperlinks` feature of this crate is enabled.
+    /// Underline colors are not written by this backend, so they are reported as unsupported.
+    fn capabilities(&self) -> BackendCapabilities {
+        *self
+            .capabilities
+            .get_or_init(|| backend_capabilities(Capabilities::new_from_env().ok().as_ref()))
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         // termwiz doesn't have a command to just set the scrolling region. Instead, setting the
@@ -387,6 +435,8 @@ impl FromTermwiz<Underline> for Modifier {
     fn from_termwiz(value: Underline) -> Self {
         match value {
             Underline::None => Self::empty(),
+            Underline::Double => Self::DOUBLE_UNDERLINE,
+            Underline::Curly => Self::CURLY_UNDERLINE,
             _ => Self::UNDERLINED,
         }
     }
@@ -743,11 +793,11 @@ mod tests {
             );
             assert_eq!(
                 Modifier::from_termwiz(Underline::Double),
-                Modifier::UNDERLINED
+                Modifier::DOUBLE_UNDERLINE
             );
             assert_eq!(
                 Modifier::from_termwiz(Underline::Curly),
-                Modifier::UNDERLINED
+                Modifier::CURLY_UNDERLINE
             );
             assert_eq!(
                 Modifier::from_termwiz(Underline::Dashed),
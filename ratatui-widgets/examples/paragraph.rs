@@ -74,7 +74,7 @@ pub fn render_wrapped_paragraph(frame: &mut Frame, area: Rect) {
     let paragraph = Paragraph::new(create_lines(area))
         .style(Color::White)
         .scroll((0, 0))
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new().trim(true));
 
     frame.render_widget(paragraph, area);
 }
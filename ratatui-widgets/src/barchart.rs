@@ -8,12 +8,14 @@ use ratatui_core::{
     text::Line,
     widgets::Widget,
 };
+use strum::{Display, EnumString};
 
-pub use self::{bar::Bar, bar_group::BarGroup};
+pub use self::{bar::Bar, bar_group::BarGroup, state::BarChartState};
 use crate::block::{Block, BlockExt};
 
 mod bar;
 mod bar_group;
+mod state;
 
 /// A chart showing values as [bars](Bar).
 ///
@@ -45,6 +47,11 @@ mod bar_group;
 /// Note: this is the only widget that doesn't implement `Widget` for `&T` because the current
 /// implementation modifies the internal state of self. This will be fixed in the future.
 ///
+/// The chart can also be rendered as a [`StatefulWidget`] with a [`BarChartState`], which animates
+/// the bars toward their values over several renders.
+///
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+///
 /// # Examples
 ///
 /// The following example creates a `BarChart` with two groups of bars.
@@ -106,6 +113,33 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// direction of the bars
     direction: Direction,
+    /// How to render the values which don't fit in their bar
+    label_overflow: LabelOverflow,
+}
+
+/// Defines how a [`BarChart`] renders the value of a bar that doesn't fit in the bar.
+///
+/// The value of a vertical bar is drawn in the bottom row of the bar, and doesn't fit when it is
+/// wider than the bar (or as wide as the bar, if the bar is less than one row tall). The value of
+/// a horizontal bar is drawn from the start of the bar, and doesn't fit when it is longer than the
+/// bar.
+///
+/// See [`BarChart::label_overflow`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LabelOverflow {
+    /// The value is hidden in a vertical bar, and overflows past the end of a horizontal bar
+    ///
+    /// This is the default.
+    #[default]
+    Auto,
+    /// The value is not displayed
+    Hide,
+    /// The value is truncated to the size of the bar
+    Truncate,
+    /// The value is displayed outside of the bar: above a vertical bar, or after a horizontal bar
+    ///
+    /// The value is not displayed if there is no room left outside of the bar.
+    Outside,
 }
 
 impl Default for BarChart<'_> {
@@ -123,6 +157,7 @@ impl Default for BarChart<'_> {
             bar_set: symbols::bar::NINE_LEVELS,
             style: Style::default(),
             direction: Direction::Vertical,
+            label_overflow: LabelOverflow::Auto,
         }
     }
 }
@@ -174,6 +209,36 @@ impl<'a> BarChart<'a> {
         }
     }
 
+    /// Creates a new `BarChart` widget with the given groups of bars.
+    ///
+    /// The `groups` parameter accepts any type that can be converted into a `Vec<BarGroup>`. The
+    /// bars of each group are rendered next to each other, with the group label beneath them. The
+    /// gap between groups is the [`bar_gap`](Self::bar_gap) plus the
+    /// [`group_gap`](Self::group_gap). The maximum value used to scale the bars is computed across
+    /// all the groups.
+    ///
+    /// Groups without any bars are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Bar, BarChart, BarGroup};
+    ///
+    /// BarChart::grouped(vec![
+    ///     BarGroup::new([Bar::with_label("A", 10), Bar::with_label("B", 20)]).label("Group 1"),
+    ///     BarGroup::new([Bar::with_label("C", 30), Bar::with_label("D", 40)]).label("Group 2"),
+    /// ])
+    /// .group_gap(2);
+    /// ```
+    pub fn grouped<T: Into<Vec<BarGroup<'a>>>>(groups: T) -> Self {
+        let mut data: Vec<BarGroup> = groups.into();
+        data.retain(|group| !group.bars.is_empty());
+        Self {
+            data,
+            ..Default::default()
+        }
+    }
+
     /// Add group of bars to the `BarChart`
     ///
     /// # Examples
@@ -388,6 +453,28 @@ impl<'a> BarChart<'a> {
         self.direction = direction;
         self
     }
+
+    /// Set how the values which don't fit in their bar are rendered.
+    ///
+    /// By default ([`LabelOverflow::Auto`]), the values that don't fit in a vertical bar are
+    /// hidden, and the values that don't fit in a horizontal bar overflow past the end of the bar.
+    /// See [`LabelOverflow`] for the available behaviors.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Bar, BarChart, LabelOverflow};
+    ///
+    /// let chart = BarChart::new([Bar::with_label("A", 1), Bar::with_label("B", 10000)])
+    ///     .label_overflow(LabelOverflow::Outside);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_overflow(mut self, overflow: LabelOverflow) -> Self {
+        self.label_overflow = overflow;
+        self
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -536,6 +623,7 @@ impl BarChart<'_> {
                     bar_length as usize,
                     self.value_style,
                     self.bar_style,
+                    self.label_overflow,
                 );
 
                 bar_y += self.bar_gap + self.bar_width;
@@ -603,6 +691,11 @@ impl BarChart<'_> {
         }
     }
 
+    /// Returns an iterator over the bars of all the groups
+    fn bars(&self) -> impl Iterator<Item = &Bar<'_>> {
+        self.data.iter().flat_map(|group| group.bars.iter())
+    }
+
     /// get the maximum data value. the returned value is always greater equal 1
     fn maximum_data_value(&self) -> u64 {
         self.max
@@ -626,6 +719,10 @@ impl BarChart<'_> {
         // print labels and values in one go
         let mut bar_x = area.left();
         let bar_y = area.bottom() - label_info.height - 1;
+        let chart_area = Rect {
+            height: area.height - label_info.height,
+            ..area
+        };
         for (group, ticks_vec) in self.data.iter().zip(group_ticks) {
             if group.bars.is_empty() {
                 continue;
@@ -649,7 +746,19 @@ impl BarChart<'_> {
                     bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                let bar_area = Rect {
+                    x: bar_x,
+                    width: self.bar_width,
+                    ..chart_area
+                };
+                bar.render_value(
+                    buf,
+                    bar_area,
+                    chart_area,
+                    self.value_style,
+                    *ticks,
+                    self.label_overflow,
+                );
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -682,6 +791,29 @@ impl Widget for &BarChart<'_> {
     }
 }
 
+impl ratatui_core::widgets::StatefulWidget for BarChart<'_> {
+    type State = BarChartState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        // the scale is based on the targets so that it doesn't change during the animation
+        self.max = Some(self.maximum_data_value());
+        state.animate(self.bars().map(|bar| bar.value));
+        let bars = self.data.iter_mut().flat_map(|group| group.bars.iter_mut());
+        for (bar, &value) in bars.zip(state.values()) {
+            bar.value = value;
+        }
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl ratatui_core::widgets::StatefulWidget for &BarChart<'_> {
+    type State = BarChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        ratatui_core::widgets::StatefulWidget::render(self.clone(), area, buf, state);
+    }
+}
+
 impl Styled for BarChart<'_> {
     type Item = Self;
     fn style(&self) -> Style {
@@ -701,6 +833,7 @@ mod tests {
         style::{Color, Modifier, Stylize},
         text::Span,
     };
+    use rstest::rstest;
 
     use super::*;
     use crate::borders::BorderType;
@@ -747,6 +880,26 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn grouped() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+        let widget = BarChart::grouped([
+            BarGroup::new([Bar::with_label("a", 1), Bar::with_label("b", 2)]).label("G1"),
+            BarGroup::default(),
+            BarGroup::new([Bar::with_label("c", 4)]).label("G2"),
+        ])
+        .group_gap(2);
+        widget.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::empty(buffer.area);
+        BarChart::default()
+            .data(BarGroup::new([Bar::with_label("a", 1), Bar::with_label("b", 2)]).label("G1"))
+            .data(BarGroup::new([Bar::with_label("c", 4)]).label("G2"))
+            .group_gap(2)
+            .render(expected.area, &mut expected);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn max() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1420,4 +1573,75 @@ mod tests {
         assert_eq!(updated_chart.data.len(), 2);
         assert_eq!(updated_chart.data[1].bars, [Bar::with_label("Blue", 3)]);
     }
+
+    #[test]
+    fn render_animated() {
+        use ratatui_core::widgets::StatefulWidget;
+
+        let render_static = |data: &[(&str, u64)]| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 10));
+            let chart = BarChart::default().data(data).max(8);
+            Widget::render(chart, buffer.area, &mut buffer);
+            buffer
+        };
+        let chart = BarChart::default().data(&[("a", 8), ("b", 4)]);
+        let mut state = BarChartState::new(3);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 10));
+
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.values(), &[3, 3]);
+        assert_eq!(buffer, render_static(&[("a", 3), ("b", 3)]));
+
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.values(), &[6, 4]);
+        assert_eq!(buffer, render_static(&[("a", 6), ("b", 4)]));
+
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.values(), &[8, 4]);
+        assert_eq!(buffer, render_static(&[("a", 8), ("b", 4)]));
+        assert!(state.is_settled([8, 4]));
+    }
+
+    #[rstest]
+    #[case::auto(LabelOverflow::Auto, ["long      ", "          ", "x█████████"])]
+    #[case::hide(LabelOverflow::Hide, ["█         ", "          ", "x█████████"])]
+    #[case::truncate(LabelOverflow::Truncate, ["l         ", "          ", "x█████████"])]
+    #[case::outside(LabelOverflow::Outside, ["█long     ", "          ", "x█████████"])]
+    fn horizontal_label_overflow(#[case] overflow: LabelOverflow, #[case] expected: [&str; 3]) {
+        let chart = BarChart::horizontal([
+            Bar::default().value(1).text_value("long"),
+            Bar::default().value(10).text_value("x"),
+        ])
+        .label_overflow(overflow);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::auto(LabelOverflow::Auto, ["   ██", "   ██", "   ██", "██ 4█"])]
+    #[case::hide(LabelOverflow::Hide, ["   ██", "   ██", "   ██", "██ 4█"])]
+    #[case::truncate(LabelOverflow::Truncate, ["   ██", "   ██", "   ██", "10 4█"])]
+    #[case::outside(LabelOverflow::Outside, ["   ██", "   ██", "100██", "██ 4█"])]
+    fn vertical_label_overflow(#[case] overflow: LabelOverflow, #[case] expected: [&str; 4]) {
+        let chart = BarChart::new([
+            Bar::default().value(1).text_value("100"),
+            Bar::default().value(4),
+        ])
+        .bar_width(2)
+        .label_overflow(overflow);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 4));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn vertical_label_overflow_outside_full_bar() {
+        let chart = BarChart::new([Bar::default().value(4).text_value("100")])
+            .bar_width(2)
+            .label_overflow(LabelOverflow::Outside);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 2));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["██", "██"]));
+    }
 }
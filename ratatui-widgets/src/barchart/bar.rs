@@ -7,6 +7,8 @@ use ratatui_core::{
 };
 use unicode_width::UnicodeWidthStr;
 
+use super::LabelOverflow;
+
 /// A bar to be shown by the [`BarChart`](super::BarChart) widget.
 ///
 /// Here is an explanation of a `Bar`'s components.
@@ -197,7 +199,8 @@ impl<'a> Bar<'a> {
     /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
     /// The value is rendered using `value_style`. If the value width is greater than the
     /// bar width, then the value is split into 2 parts. the first part is rendered in the bar
-    /// using `value_style`. The second part is rendered outside the bar using `bar_style`
+    /// using `value_style`. With [`LabelOverflow::Auto`], the second part is
+    /// rendered outside the bar using `bar_style`.
     pub(super) fn render_value_with_different_styles(
         &self,
         buf: &mut Buffer,
@@ -205,54 +208,100 @@ impl<'a> Bar<'a> {
         bar_length: usize,
         default_value_style: Style,
         bar_style: Style,
+        overflow: LabelOverflow,
     ) {
         let value = self.value.to_string();
         let text = self.text_value.as_ref().unwrap_or(&value);
 
-        if !text.is_empty() {
-            let style = default_value_style.patch(self.value_style);
-            // Since the value may be longer than the bar itself, we need to use 2 different styles
-            // while rendering. Render the first part with the default value style
-            buf.set_stringn(area.x, area.y, text, bar_length, style);
-            // render the second part with the bar_style
-            if text.len() > bar_length {
-                let (first, second) = text.split_at(bar_length);
+        if text.is_empty() {
+            return;
+        }
+        let style = default_value_style.patch(self.value_style);
+        let fits = text.width() <= bar_length;
+        match overflow {
+            LabelOverflow::Auto => {
+                // Since the value may be longer than the bar itself, we need to use 2 different
+                // styles while rendering. Render the first part with the default value style
+                buf.set_stringn(area.x, area.y, text, bar_length, style);
+                // render the second part with the bar_style
+                if text.len() > bar_length {
+                    let (first, second) = text.split_at(bar_length);
 
-                let style = bar_style.patch(self.style);
-                buf.set_stringn(
-                    area.x + first.len() as u16,
-                    area.y,
-                    second,
-                    area.width as usize - first.len(),
-                    style,
-                );
+                    let style = bar_style.patch(self.style);
+                    buf.set_stringn(
+                        area.x + first.len() as u16,
+                        area.y,
+                        second,
+                        area.width as usize - first.len(),
+                        style,
+                    );
+                }
+            }
+            LabelOverflow::Hide if !fits => {}
+            LabelOverflow::Outside if !fits => {
+                let x = area.x.saturating_add(bar_length as u16);
+                if x < area.right() {
+                    let style = bar_style.patch(self.style);
+                    let max_width = (area.right() - x) as usize;
+                    buf.set_stringn(x, area.y, text, max_width, style);
+                }
+            }
+            LabelOverflow::Hide | LabelOverflow::Truncate | LabelOverflow::Outside => {
+                buf.set_stringn(area.x, area.y, text, bar_length, style);
             }
         }
     }
 
+    /// Render the value at the bottom of a vertical bar.
+    ///
+    /// `bar_area` is the area of the bar and `chart_area` the area of all the bars, which bounds
+    /// the values rendered outside of their bar.
     pub(super) fn render_value(
         &self,
         buf: &mut Buffer,
-        max_width: u16,
-        x: u16,
-        y: u16,
+        bar_area: Rect,
+        chart_area: Rect,
         default_value_style: Style,
         ticks: u64,
+        overflow: LabelOverflow,
     ) {
-        if self.value != 0 {
-            const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
-            let value_label = self.text_value.as_ref().unwrap_or(&value);
-            let width = value_label.width() as u16;
-            // if we have enough space or the ticks are greater equal than 1 cell (8)
-            // then print the value
-            if width < max_width || (width == max_width && ticks >= TICKS_PER_LINE) {
-                buf.set_string(
-                    x + (max_width.saturating_sub(value_label.len() as u16) >> 1),
-                    y,
-                    value_label,
-                    default_value_style.patch(self.value_style),
-                );
+        const TICKS_PER_LINE: u64 = 8;
+        if self.value == 0 {
+            return;
+        }
+        let value = self.value.to_string();
+        let value_label = self.text_value.as_ref().unwrap_or(&value);
+        let width = value_label.width() as u16;
+        let max_width = bar_area.width;
+        let (x, y) = (bar_area.x, bar_area.bottom() - 1);
+        let style = default_value_style.patch(self.value_style);
+        // if we have enough space or the ticks are greater equal than 1 cell (8)
+        // then print the value
+        if width < max_width || (width == max_width && ticks >= TICKS_PER_LINE) {
+            buf.set_string(
+                x + (max_width.saturating_sub(value_label.len() as u16) >> 1),
+                y,
+                value_label,
+                style,
+            );
+            return;
+        }
+        match overflow {
+            LabelOverflow::Auto | LabelOverflow::Hide => {}
+            LabelOverflow::Truncate => {
+                buf.set_stringn(x, y, value_label, max_width as usize, style);
+            }
+            LabelOverflow::Outside => {
+                // render the value centered on the bar, in the row above the top of the bar
+                let bar_height = u16::try_from(ticks.div_ceil(TICKS_PER_LINE)).unwrap_or(u16::MAX);
+                if bar_height >= bar_area.height {
+                    return;
+                }
+                let x = (x + max_width / 2)
+                    .saturating_sub(width / 2)
+                    .max(chart_area.x);
+                let max_width = chart_area.right().saturating_sub(x) as usize;
+                buf.set_stringn(x, y - bar_height, value_label, max_width, style);
             }
         }
     }
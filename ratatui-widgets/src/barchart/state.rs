@@ -0,0 +1,136 @@
+/// State of a [`BarChart`] widget used to animate its bars
+///
+/// The state holds the values currently displayed by the bars. When the chart is rendered as a
+/// stateful widget, each value moves toward the value of its [`Bar`] (the target) by at most
+/// `step` and the bars are drawn with the current values, so that the bars grow and shrink over
+/// a few frames instead of jumping to their new values. The scale of the chart is computed from
+/// the targets, so it doesn't change during the animation.
+///
+/// Bars that are added start from `0`. A `step` of `0` disables the animation.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::{BarChart, BarChartState, StatefulWidget},
+/// };
+///
+/// let mut state = BarChartState::new(2);
+/// let area = Rect::new(0, 0, 10, 5);
+/// let mut buf = Buffer::empty(area);
+/// let chart = BarChart::default().data(&[("A", 5), ("B", 1)]);
+/// chart.clone().render(area, &mut buf, &mut state);
+/// assert_eq!(state.values(), &[2, 1]);
+/// chart.render(area, &mut buf, &mut state);
+/// assert_eq!(state.values(), &[4, 1]);
+/// ```
+///
+/// [`BarChart`]: super::BarChart
+/// [`Bar`]: super::Bar
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BarChartState {
+    values: Vec<u64>,
+    step: u64,
+}
+
+impl BarChartState {
+    /// Creates a new state that moves the values of the bars by at most `step` on each render
+    pub const fn new(step: u64) -> Self {
+        Self {
+            values: Vec::new(),
+            step,
+        }
+    }
+
+    /// Sets the maximum change of the values of the bars on each render
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_step(mut self, step: u64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// The maximum change of the values of the bars on each render
+    pub const fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// The values currently displayed by the bars, in the order of the bars of the chart
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+
+    /// Moves each value toward its target by at most the step of the state
+    ///
+    /// This is called when rendering the chart with the values of its bars as targets. The number
+    /// of values is adjusted to the number of targets, new values starting from `0`.
+    pub fn animate<I: IntoIterator<Item = u64>>(&mut self, targets: I) {
+        let mut count = 0;
+        for (index, target) in targets.into_iter().enumerate() {
+            if index == self.values.len() {
+                self.values.push(0);
+            }
+            let value = &mut self.values[index];
+            *value = if self.step == 0 {
+                target
+            } else if *value < target {
+                value.saturating_add(self.step).min(target)
+            } else {
+                value.saturating_sub(self.step).max(target)
+            };
+            count = index + 1;
+        }
+        self.values.truncate(count);
+    }
+
+    /// Whether the values have all reached the given targets
+    ///
+    /// This can be used to keep redrawing the chart until the animation is finished.
+    pub fn is_settled<I: IntoIterator<Item = u64>>(&self, targets: I) -> bool {
+        let mut targets = targets.into_iter();
+        self.values
+            .iter()
+            .all(|&value| targets.next() == Some(value))
+            && targets.next().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let state = BarChartState::new(3);
+        assert_eq!(state.step(), 3);
+        assert!(state.values().is_empty());
+        assert_eq!(state.with_step(5).step(), 5);
+    }
+
+    #[test]
+    fn animate() {
+        let mut state = BarChartState::new(3);
+        state.animate([5, 2]);
+        assert_eq!(state.values(), &[3, 2]);
+        state.animate([5, 2, 4]);
+        assert_eq!(state.values(), &[5, 2, 3]);
+        state.animate([0, 2]);
+        assert_eq!(state.values(), &[2, 2]);
+        assert!(!state.is_settled([0, 2]));
+        state.animate([0, 2]);
+        assert_eq!(state.values(), &[0, 2]);
+        assert!(state.is_settled([0, 2]));
+        assert!(!state.is_settled([0, 2, 1]));
+    }
+
+    #[test]
+    fn animate_without_step() {
+        let mut state = BarChartState::new(0);
+        state.animate([100, 7]);
+        assert_eq!(state.values(), &[100, 7]);
+    }
+}
@@ -6,22 +6,20 @@
 //! [title](Block::title) and [padding](Block::padding).
 
 use itertools::Itertools;
+pub use ratatui_core::layout::Padding;
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
-    style::{Style, Styled},
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Style, Styled},
     symbols::border,
     text::Line,
-    widgets::Widget,
+    widgets::{SizeHint, Widget},
 };
 
-pub use self::{
-    padding::Padding,
-    title::{Position, Title},
-};
+pub use self::title::{Position, Title};
 use crate::borders::{BorderType, Borders};
 
-mod padding;
+mod junction;
 pub mod title;
 
 /// Base widget to be used to display a box border around all other built-in widgets.
@@ -47,6 +45,7 @@ pub mod title;
 ///
 /// - [`Block::new`] creates a new [`Block`] with no border or paddings.
 /// - [`Block::bordered`] Create a new block with all borders shown.
+/// - [`Block::titled`] Create a new block with all borders shown and a title.
 ///
 /// # Setter methods
 ///
@@ -54,6 +53,8 @@ pub mod title;
 ///
 /// - [`Block::borders`] Defines which borders to display.
 /// - [`Block::border_style`] Defines the style of the borders.
+/// - [`Block::focus_border_style`] Defines the style of the borders when the block is focused.
+/// - [`Block::focused`] Sets whether the block is focused.
 /// - [`Block::border_type`] Sets the symbols used to display the border (e.g. single line, double
 ///   line, thick or rounded borders).
 /// - [`Block::padding`] Defines the padding inside a [`Block`].
@@ -120,10 +121,18 @@ pub struct Block<'a> {
     titles_alignment: Alignment,
     /// The default position of the titles that don't have one
     titles_position: Position,
+    /// The symbol filling the title rows around the titles
+    title_fill: Option<&'a str>,
     /// Visible borders
     borders: Borders,
     /// Border style
     border_style: Style,
+    /// Border style applied on top of `border_style` when the block is focused
+    focus_border_style: Style,
+    /// Whether the block is focused
+    focused: bool,
+    /// Start and end colors of the gradient of the borders along each edge
+    border_gradient: Option<(Color, Color)>,
     /// The symbols used to render the border. The default is plain lines but one can choose to
     /// have rounded or doubled lines instead or a custom set of symbols
     border_set: border::Set,
@@ -131,6 +140,10 @@ pub struct Block<'a> {
     style: Style,
     /// Block padding
     padding: Padding,
+    /// Whether the borders are connected to the borders already in the buffer
+    connect_borders: bool,
+    /// Style and offset of the drop shadow
+    shadow: Option<(Style, (i16, i16))>,
 }
 
 impl<'a> Block<'a> {
@@ -141,11 +154,17 @@ impl<'a> Block<'a> {
             titles_style: Style::new(),
             titles_alignment: Alignment::Left,
             titles_position: Position::Top,
+            title_fill: None,
             borders: Borders::NONE,
             border_style: Style::new(),
+            focus_border_style: Style::new(),
+            focused: false,
+            border_gradient: None,
             border_set: BorderType::Plain.to_border_set(),
             style: Style::new(),
             padding: Padding::ZERO,
+            connect_borders: false,
+            shadow: None,
         }
     }
 
@@ -162,6 +181,25 @@ impl<'a> Block<'a> {
         block
     }
 
+    /// Create a new block with [all borders](Borders::ALL) shown and the given title
+    ///
+    /// This is a shortcut for `Block::bordered().title(title)`. The title is left aligned unless
+    /// it has its own alignment (e.g. a right aligned [`Line`]).
+    ///
+    /// ```
+    /// use ratatui::{text::Line, widgets::Block};
+    ///
+    /// assert_eq!(Block::titled("Title"), Block::bordered().title("Title"));
+    ///
+    /// let block = Block::titled(Line::from("Title").right_aligned());
+    /// ```
+    pub fn titled<T>(title: T) -> Self
+    where
+        T: Into<Title<'a>>,
+    {
+        Self::bordered().title(title)
+    }
+
     /// Adds a title to the block.
     ///
     /// The `title` function allows you to add a title to the block. You can call this function
@@ -368,6 +406,37 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Fills the rows that hold titles with the given symbol.
+    ///
+    /// By default, the space of a title row that is not covered by titles shows the top or bottom
+    /// border, or is left blank if there is no border on that side. When a fill symbol is set, the
+    /// title row is filled with it between the left and right borders before the titles are
+    /// rendered, using the border style. Rows without titles are not affected. This makes it
+    /// possible to render section separators with a block that has no borders.
+    ///
+    /// The symbol should be a single cell wide, such as [`symbols::line::HORIZONTAL`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{symbols, text::Line, widgets::Block};
+    ///
+    /// let separator = Block::new()
+    ///     .title(Line::from(" Section ").centered())
+    ///     .title_fill(symbols::line::HORIZONTAL);
+    /// // Renders
+    /// // ──── Section ────
+    /// ```
+    ///
+    /// [`symbols::line::HORIZONTAL`]: ratatui_core::symbols::line::HORIZONTAL
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn title_fill(mut self, symbol: &'a str) -> Self {
+        self.title_fill = Some(symbol);
+        self
+    }
+
     /// Defines the style of the borders.
     ///
     /// This style is applied only to the areas covered by borders, and is applied to the block
@@ -396,6 +465,89 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Defines the style of the borders when the block is [focused](Block::focused).
+    ///
+    /// When the block is focused, this style is applied on top of the [`Block::border_style`]. When
+    /// the block is not focused, this style is ignored.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// This example shows a `Block` with yellow borders when focused and blue borders otherwise.
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Block,
+    /// };
+    ///
+    /// let panel = |focused: bool| {
+    ///     Block::bordered()
+    ///         .border_style(Style::new().blue())
+    ///         .focus_border_style(Style::new().yellow())
+    ///         .focused(focused)
+    /// };
+    /// let focused_panel = panel(true);
+    /// let unfocused_panel = panel(false);
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focus_border_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focus_border_style = style.into();
+        self
+    }
+
+    /// Draws the borders with a foreground color gradient from `start` to `end` along each edge.
+    ///
+    /// The color of each border cell is blended between `start` and `end` (see [`Color::blend`])
+    /// according to its position along its edge, from the start (left or top) to the end (right or
+    /// bottom). The color is applied on top of the [`Block::border_style`] (and the
+    /// [`Block::focus_border_style`] if the block is focused). The corners use their position along
+    /// the top and bottom edges.
+    ///
+    /// By default, the borders have no gradient.
+    ///
+    /// # Example
+    ///
+    /// This example draws borders that are red at the start of each edge and blue at the end.
+    /// ```
+    /// use ratatui::{style::Color, widgets::Block};
+    ///
+    /// Block::bordered().border_gradient(Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_gradient(mut self, start: Color, end: Color) -> Self {
+        self.border_gradient = Some((start, end));
+        self
+    }
+
+    /// Sets whether the block is focused.
+    ///
+    /// When the block is focused, the borders are rendered using the
+    /// [`Block::focus_border_style`] applied on top of the [`Block::border_style`]. This avoids
+    /// having to build a different block at every call site depending on the focus state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Block,
+    /// };
+    ///
+    /// let is_focused = true;
+    /// Block::bordered()
+    ///     .focus_border_style(Style::new().yellow())
+    ///     .focused(is_focused);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     /// Defines the style of the entire block.
     ///
     /// This is the most generic [`Style`] a block can receive, it will be merged with any other
@@ -504,6 +656,133 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets only the corner symbols of the border, keeping the current edges.
+    ///
+    /// The four corners are taken from the given [`ratatui_core::symbols::border::Set`] and the
+    /// edges of the set are ignored. This makes it possible to mix border styles, e.g. rounded
+    /// corners with thick edges. Call this after [`border_type`](Block::border_type) or
+    /// [`border_set`](Block::border_set), as those replace the corners as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     symbols,
+    ///     widgets::{Block, BorderType},
+    /// };
+    ///
+    /// Block::bordered()
+    ///     .border_type(BorderType::Double)
+    ///     .corner_set(symbols::border::PLAIN)
+    ///     .title("Block");
+    /// // Renders
+    /// // ┌Block┐
+    /// // ║     ║
+    /// // └═════┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn corner_set(mut self, corner_set: border::Set) -> Self {
+        self.border_set.top_left = corner_set.top_left;
+        self.border_set.top_right = corner_set.top_right;
+        self.border_set.bottom_left = corner_set.bottom_left;
+        self.border_set.bottom_right = corner_set.bottom_right;
+        self
+    }
+
+    /// Uses rounded corners for the border, keeping the current edges.
+    ///
+    /// This is a shortcut for `corner_set(symbols::border::ROUNDED)`. See
+    /// [`corner_set`](Block::corner_set) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Block, BorderType};
+    ///
+    /// Block::bordered()
+    ///     .border_type(BorderType::Thick)
+    ///     .rounded_corners()
+    ///     .title("Block");
+    /// // Renders
+    /// // ╭Block╮
+    /// // ┃     ┃
+    /// // ╰━━━━━╯
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn rounded_corners(self) -> Self {
+        self.corner_set(border::ROUNDED)
+    }
+
+    /// Connects the borders to the borders that are already in the buffer.
+    ///
+    /// When two blocks share an edge, the block rendered last overwrites the border of the other
+    /// one, which leaves the lines of the first block disconnected. With this option enabled, each
+    /// border cell of the block is merged with the box drawing symbol that was in the cell before
+    /// rendering, so that the borders are joined with the appropriate junction (e.g. `┬`, `├` or
+    /// `┼`).
+    ///
+    /// Only lines of the same weight are connected: plain and rounded borders connect with each
+    /// other, thick borders with thick borders, and double borders with double borders. Other
+    /// symbols are overwritten as usual.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{Block, Widget},
+    /// };
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+    /// Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buf);
+    /// Block::bordered()
+    ///     .connect_borders(true)
+    ///     .render(Rect::new(3, 0, 4, 3), &mut buf);
+    /// // Renders
+    /// // ┌──┬──┐
+    /// // │  │  │
+    /// // └──┴──┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn connect_borders(mut self, connect: bool) -> Self {
+        self.connect_borders = connect;
+        self
+    }
+
+    /// Adds a drop shadow behind the block.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// When the block is rendered, `style` is applied to the cells covered by the area of the
+    /// block moved by `offset` (`(x, y)`, positive values move to the right and bottom), except
+    /// for the cells of the block itself. The content of these cells is kept, so a dim or dark
+    /// style gives the impression of a shadow. The shadow is clipped to the buffer. This is
+    /// typically used for popups and modal dialogs.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Block,
+    /// };
+    ///
+    /// let block = Block::bordered().shadow(Style::new().on_black().dim(), (2, 1));
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shadow<S: Into<Style>>(mut self, style: S, offset: (i16, i16)) -> Self {
+        self.shadow = Some((style.into(), offset));
+        self
+    }
+
     /// Defines the padding inside a `Block`.
     ///
     /// See [`Padding`] for more information.
@@ -538,6 +817,69 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Defines the top padding inside a `Block`, keeping the padding of the other sides.
+    ///
+    /// See [`Block::padding`] to set the padding of all the sides at once. The padding of each
+    /// side can also be set with [`Block::padding_right`], [`Block::padding_bottom`] and
+    /// [`Block::padding_left`]. This is useful to configure the padding incrementally.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Block, Padding};
+    ///
+    /// # let has_header = true;
+    /// let mut block = Block::bordered().padding(Padding::horizontal(1));
+    /// if has_header {
+    ///     block = block.padding_top(1);
+    /// }
+    /// // Renders
+    /// // ┌─────────┐
+    /// // │         │
+    /// // │ content │
+    /// // └─────────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn padding_top(mut self, top: u16) -> Self {
+        self.padding.top = top;
+        self
+    }
+
+    /// Defines the right padding inside a `Block`, keeping the padding of the other sides.
+    ///
+    /// See [`Block::padding`] to set the padding of all the sides at once.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn padding_right(mut self, right: u16) -> Self {
+        self.padding.right = right;
+        self
+    }
+
+    /// Defines the bottom padding inside a `Block`, keeping the padding of the other sides.
+    ///
+    /// See [`Block::padding`] to set the padding of all the sides at once.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn padding_bottom(mut self, bottom: u16) -> Self {
+        self.padding.bottom = bottom;
+        self
+    }
+
+    /// Defines the left padding inside a `Block`, keeping the padding of the other sides.
+    ///
+    /// See [`Block::padding`] to set the padding of all the sides at once.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn padding_left(mut self, left: u16) -> Self {
+        self.padding.left = left;
+        self
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     ///
     /// # Examples
@@ -581,17 +923,7 @@ impl<'a> Block<'a> {
             inner.height = inner.height.saturating_sub(1);
         }
 
-        inner.x = inner.x.saturating_add(self.padding.left);
-        inner.y = inner.y.saturating_add(self.padding.top);
-
-        inner.width = inner
-            .width
-            .saturating_sub(self.padding.left + self.padding.right);
-        inner.height = inner
-            .height
-            .saturating_sub(self.padding.top + self.padding.bottom);
-
-        inner
+        inner.inset(self.padding)
     }
 
     fn has_title_at_position(&self, position: Position) -> bool {
@@ -599,6 +931,47 @@ impl<'a> Block<'a> {
             .iter()
             .any(|(pos, _)| pos.unwrap_or(self.titles_position) == position)
     }
+
+    /// Renders only the borders and titles of the block, keeping the content of the area.
+    ///
+    /// Rendering a block as a [`Widget`] applies [`Block::style`] to the whole area, which
+    /// overwrites the style of any content rendered in the area beforehand. This method instead
+    /// applies the block style to the border cells only, so that a block can decorate content
+    /// that was already rendered. The shadow of the block is not rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     style::{Style, Stylize},
+    ///     widgets::{Block, Paragraph, Widget},
+    /// };
+    ///
+    /// let area = Rect::new(0, 0, 9, 3);
+    /// let mut buf = Buffer::empty(area);
+    /// let block = Block::bordered().title("Title").on_blue();
+    /// Paragraph::new("content")
+    ///     .on_red()
+    ///     .render(block.inner(area), &mut buf);
+    /// block.render_border_only(area, &mut buf);
+    /// // Renders the borders on a blue background around the content on a red background
+    /// // ┌Title──┐
+    /// // │content│
+    /// // └───────┘
+    /// ```
+    pub fn render_border_only(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        for position in self.border_positions(area) {
+            buf[position].set_style(self.style);
+        }
+        self.render_borders(area, buf);
+        self.render_titles(area, buf);
+    }
 }
 
 impl Widget for Block<'_> {
@@ -609,6 +982,7 @@ impl Widget for Block<'_> {
 
 impl Widget for &Block<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_shadow(area, buf);
         let area = area.intersection(buf.area);
         if area.is_empty() {
             return;
@@ -619,8 +993,100 @@ impl Widget for &Block<'_> {
     }
 }
 
+impl SizeHint for Block<'_> {
+    /// Returns the space taken up by the borders, padding and titles of the block, widened to fit
+    /// the longest title between the corners.
+    fn size_hint(&self) -> (Constraint, Constraint) {
+        let (left, right) = self.horizontal_space();
+        let (top, bottom) = self.vertical_space();
+        let corners = u16::from(self.borders.contains(Borders::LEFT))
+            + u16::from(self.borders.contains(Borders::RIGHT));
+        let title_width = self
+            .titles
+            .iter()
+            .map(|(_, title)| title.width())
+            .max()
+            .unwrap_or_default();
+        let title_width = u16::try_from(title_width)
+            .unwrap_or(u16::MAX)
+            .saturating_add(corners);
+        (
+            Constraint::Length(left.saturating_add(right).max(title_width)),
+            Constraint::Length(top.saturating_add(bottom)),
+        )
+    }
+}
+
 impl Block<'_> {
+    /// Returns the style of the borders, taking into account whether the block is focused.
+    fn current_border_style(&self) -> Style {
+        if self.focused {
+            self.border_style.patch(self.focus_border_style)
+        } else {
+            self.border_style
+        }
+    }
+
+    /// Returns the style of the border cell at the given position along its edge.
+    fn border_style_at(&self, position: f32) -> Style {
+        let style = self.current_border_style();
+        match self.border_gradient {
+            Some((start, end)) => style.fg(start.blend(end, position)),
+            None => style,
+        }
+    }
+
+    /// Applies the shadow style to the cells of the area moved by the shadow offset, except for
+    /// the cells of the area itself.
+    fn render_shadow(&self, area: Rect, buf: &mut Buffer) {
+        let Some((style, (dx, dy))) = self.shadow else {
+            return;
+        };
+        if area.is_empty() {
+            return;
+        }
+        // The shadow is computed with signed coordinates so that it is clipped rather than moved
+        // when it extends past the edges of the buffer.
+        let clip = |start: u16, end: u16, offset: i16, min: u16, max: u16| {
+            let start = (i32::from(start) + i32::from(offset)).max(i32::from(min));
+            let end = (i32::from(end) + i32::from(offset)).min(i32::from(max));
+            let start = u16::try_from(start).unwrap_or(max);
+            let end = u16::try_from(end).unwrap_or(min);
+            start..end
+        };
+        let columns = clip(
+            area.left(),
+            area.right(),
+            dx,
+            buf.area.left(),
+            buf.area.right(),
+        );
+        let rows = clip(
+            area.top(),
+            area.bottom(),
+            dy,
+            buf.area.top(),
+            buf.area.bottom(),
+        );
+        for y in rows {
+            for x in columns.clone() {
+                if !area.contains((x, y).into()) {
+                    buf[(x, y)].set_style(style);
+                }
+            }
+        }
+    }
+
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
+        // The symbols are merged once all the borders are rendered, so that the sides of this
+        // block are not connected with each other.
+        let previous_symbols = self.connect_borders.then(|| {
+            self.border_positions(area)
+                .into_iter()
+                .map(|position| (position, buf[position].symbol().to_string()))
+                .collect_vec()
+        });
+
         self.render_left_side(area, buf);
         self.render_top_side(area, buf);
         self.render_right_side(area, buf);
@@ -630,6 +1096,30 @@ impl Block<'_> {
         self.render_top_right_corner(buf, area);
         self.render_bottom_left_corner(buf, area);
         self.render_top_left_corner(buf, area);
+
+        for (position, previous) in previous_symbols.into_iter().flatten() {
+            if let Some(symbol) = junction::connect(&previous, buf[position].symbol()) {
+                buf[position].set_symbol(symbol);
+            }
+        }
+    }
+
+    /// Returns the positions of the border cells of the block, corners being returned twice.
+    fn border_positions(&self, area: Rect) -> Vec<(u16, u16)> {
+        let mut positions = vec![];
+        if self.borders.contains(Borders::TOP) {
+            positions.extend((area.left()..area.right()).map(|x| (x, area.top())));
+        }
+        if self.borders.contains(Borders::BOTTOM) {
+            positions.extend((area.left()..area.right()).map(|x| (x, area.bottom() - 1)));
+        }
+        if self.borders.contains(Borders::LEFT) {
+            positions.extend((area.top()..area.bottom()).map(|y| (area.left(), y)));
+        }
+        if self.borders.contains(Borders::RIGHT) {
+            positions.extend((area.top()..area.bottom()).map(|y| (area.right() - 1, y)));
+        }
+        positions
     }
 
     fn render_titles(&self, area: Rect, buf: &mut Buffer) {
@@ -638,18 +1128,38 @@ impl Block<'_> {
     }
 
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        self.render_title_fill(position, area, buf);
         // NOTE: the order in which these functions are called defines the overlapping behavior
         self.render_right_titles(position, area, buf);
         self.render_center_titles(position, area, buf);
         self.render_left_titles(position, area, buf);
     }
 
+    /// Fills the title row at the given position with the title fill symbol, if the row holds
+    /// titles.
+    fn render_title_fill(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        let Some(symbol) = self.title_fill else {
+            return;
+        };
+        if !self.has_title_at_position(position) {
+            return;
+        }
+        let titles_area = self.titles_area(area, position);
+        for x in titles_area.left()..titles_area.right() {
+            let position = edge_position(x - area.left(), area.width);
+            buf[(x, titles_area.top())]
+                .set_symbol(symbol)
+                .set_style(self.border_style_at(position));
+        }
+    }
+
     fn render_left_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::LEFT) {
             for y in area.top()..area.bottom() {
+                let position = edge_position(y - area.top(), area.height);
                 buf[(area.left(), y)]
                     .set_symbol(self.border_set.vertical_left)
-                    .set_style(self.border_style);
+                    .set_style(self.border_style_at(position));
             }
         }
     }
@@ -657,9 +1167,10 @@ impl Block<'_> {
     fn render_top_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::TOP) {
             for x in area.left()..area.right() {
+                let position = edge_position(x - area.left(), area.width);
                 buf[(x, area.top())]
                     .set_symbol(self.border_set.horizontal_top)
-                    .set_style(self.border_style);
+                    .set_style(self.border_style_at(position));
             }
         }
     }
@@ -668,9 +1179,10 @@ impl Block<'_> {
         if self.borders.contains(Borders::RIGHT) {
             let x = area.right() - 1;
             for y in area.top()..area.bottom() {
+                let position = edge_position(y - area.top(), area.height);
                 buf[(x, y)]
                     .set_symbol(self.border_set.vertical_right)
-                    .set_style(self.border_style);
+                    .set_style(self.border_style_at(position));
             }
         }
     }
@@ -679,9 +1191,10 @@ impl Block<'_> {
         if self.borders.contains(Borders::BOTTOM) {
             let y = area.bottom() - 1;
             for x in area.left()..area.right() {
+                let position = edge_position(x - area.left(), area.width);
                 buf[(x, y)]
                     .set_symbol(self.border_set.horizontal_bottom)
-                    .set_style(self.border_style);
+                    .set_style(self.border_style_at(position));
             }
         }
     }
@@ -690,7 +1203,7 @@ impl Block<'_> {
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
             buf[(area.right() - 1, area.bottom() - 1)]
                 .set_symbol(self.border_set.bottom_right)
-                .set_style(self.border_style);
+                .set_style(self.border_style_at(1.0));
         }
     }
 
@@ -698,7 +1211,7 @@ impl Block<'_> {
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
             buf[(area.right() - 1, area.top())]
                 .set_symbol(self.border_set.top_right)
-                .set_style(self.border_style);
+                .set_style(self.border_style_at(1.0));
         }
     }
 
@@ -706,7 +1219,7 @@ impl Block<'_> {
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
             buf[(area.left(), area.bottom() - 1)]
                 .set_symbol(self.border_set.bottom_left)
-                .set_style(self.border_style);
+                .set_style(self.border_style_at(0.0));
         }
     }
 
@@ -714,7 +1227,7 @@ impl Block<'_> {
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
             buf[(area.left(), area.top())]
                 .set_symbol(self.border_set.top_left)
-                .set_style(self.border_style);
+                .set_style(self.border_style_at(0.0));
         }
     }
 
@@ -878,6 +1391,16 @@ impl Block<'_> {
     }
 }
 
+/// Returns the normalized position (`0.0..=1.0`) of the cell at `offset` along an edge of the
+/// given length.
+fn edge_position(offset: u16, length: u16) -> f32 {
+    if length <= 1 {
+        0.0
+    } else {
+        f32::from(offset) / f32::from(length - 1)
+    }
+}
+
 /// An extension trait for [`Block`] that provides some convenience methods.
 ///
 /// This is implemented for [`Option<Block>`](Option) to simplify the common case of having a
@@ -921,6 +1444,20 @@ mod tests {
         assert_eq!(block.borders, Borders::all());
     }
 
+    #[test]
+    fn create_titled() {
+        let block = Block::titled("Title");
+        assert_eq!(block.borders, Borders::all());
+        assert_eq!(block, Block::bordered().title("Title"));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::titled(Line::from("Title").right_aligned()).render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌───Title┐", "│        │", "└────────┘"])
+        );
+    }
+
     #[rstest]
     #[case::none_0(Borders::NONE, Rect::ZERO, Rect::ZERO)]
     #[case::none_1(Borders::NONE, Rect::new(0, 0, 1, 1), Rect::new(0, 0, 1, 1))]
@@ -1149,11 +1686,17 @@ mod tests {
                 titles_style: Style::new(),
                 titles_alignment: Alignment::Left,
                 titles_position: Position::Top,
+                title_fill: None,
                 borders: Borders::NONE,
                 border_style: Style::new(),
+                focus_border_style: Style::new(),
+                focused: false,
+                border_gradient: None,
                 border_set: BorderType::Plain.to_border_set(),
                 style: Style::new(),
                 padding: Padding::ZERO,
+                connect_borders: false,
+                shadow: None,
             }
         );
     }
@@ -1169,7 +1712,26 @@ mod tests {
             // .title_style(_DEFAULT_STYLE)     // no longer const
             .title_alignment(Alignment::Left)
             .title_position(Position::Top)
-            .padding(_DEFAULT_PADDING);
+            .padding(_DEFAULT_PADDING)
+            .padding_top(2);
+    }
+
+    #[test]
+    fn padding_sides() {
+        let block = Block::new()
+            .padding_top(1)
+            .padding_right(2)
+            .padding_bottom(3)
+            .padding_left(4);
+        assert_eq!(block.padding, Padding::new(4, 2, 1, 3));
+
+        // the last value set for each side wins
+        let block = block
+            .padding(Padding::uniform(1))
+            .padding_left(0)
+            .padding_left(5);
+        assert_eq!(block.padding, Padding::new(5, 1, 1, 1));
+        assert_eq!(block.inner(Rect::new(0, 0, 10, 10)), Rect::new(5, 1, 4, 8));
     }
 
     /// Ensure Style from/into works the way a user would use it.
@@ -1365,6 +1927,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn title_fill() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 2));
+        Block::new()
+            .title(Line::from(" Section ").centered())
+            .title_fill("─")
+            .border_style(Style::new().dark_gray())
+            .render(buffer.area, &mut buffer);
+        // the titles are rendered on top of the fill, like titles rendered on a border
+        let mut expected = Buffer::with_lines(["─── Section ───", "               "]);
+        expected.set_style(Rect::new(0, 0, 15, 1), Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_fill_multiple_titles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Block::new()
+            .title("ab")
+            .title("cd")
+            .title(Line::from("ef").right_aligned())
+            .title_fill("=")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["ab=cd=====ef"]));
+    }
+
+    #[test]
+    fn title_fill_between_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .title_bottom("test")
+            .title_fill("·")
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌────────┐",
+            "│        │",
+            "└test····┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_fill_without_titles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Block::bordered()
+            .title_fill("·")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌──┐", "│  │", "└──┘"]));
+    }
+
     #[test]
     fn title_border_style() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1383,6 +1996,67 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_without_bg_keeps_existing_bg() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        buffer.set_style(buffer.area, Style::new().on_blue());
+        Block::bordered()
+            .border_style(Style::new().yellow())
+            .render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        expected.set_style(Rect::new(0, 0, 4, 3), Style::new().yellow());
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::new().fg(Color::Reset));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn border_gradient() {
+        let block = Block::bordered()
+            .border_style(Style::new().on_black())
+            .border_gradient(Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        block.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["┌───┐", "│   │", "└───┘"]);
+        let colors = [
+            Color::Rgb(255, 0, 0),
+            Color::Rgb(191, 0, 64),
+            Color::Rgb(128, 0, 128),
+            Color::Rgb(64, 0, 191),
+            Color::Rgb(0, 0, 255),
+        ];
+        for (x, color) in (0..).zip(colors) {
+            expected.set_style(Rect::new(x, 0, 1, 1), Style::new().fg(color).on_black());
+            expected.set_style(Rect::new(x, 2, 1, 1), Style::new().fg(color).on_black());
+        }
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().fg(colors[2]).on_black());
+        expected.set_style(Rect::new(4, 1, 1, 1), Style::new().fg(colors[2]).on_black());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn focused_border_style() {
+        let block = Block::bordered()
+            .border_style(Style::new().blue().bold())
+            .focus_border_style(Style::new().yellow());
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        block.clone().focused(true).render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(Rect::new(0, 0, 4, 3), Style::new().yellow().bold());
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::reset());
+        assert_eq!(buffer, expected);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        block.focused(false).render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(Rect::new(0, 0, 4, 3), Style::new().blue().bold());
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::reset());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn border_type_to_string() {
         assert_eq!(format!("{}", BorderType::Plain), "Plain");
@@ -1413,6 +2087,7 @@ mod tests {
             format!("{}", BorderType::HeavyQuadrupleDashed),
             "HeavyQuadrupleDashed"
         );
+        assert_eq!(format!("{}", BorderType::HalfBlock), "HalfBlock");
     }
 
     #[test]
@@ -1445,6 +2120,7 @@ mod tests {
             "HeavyQuadrupleDashed".parse(),
             Ok(BorderType::HeavyQuadrupleDashed)
         );
+        assert_eq!("HalfBlock".parse(), Ok(BorderType::HalfBlock));
         assert_eq!("".parse::<BorderType>(), Err(ParseError::VariantNotFound));
     }
 
@@ -1523,6 +2199,21 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_border_half_block() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_type(BorderType::HalfBlock)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "▄▄▄▄▄▄▄▄▄▄",
+            "█        █",
+            "▀▀▀▀▀▀▀▀▀▀",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn render_solid_border() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1651,4 +2342,170 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn size_hint() {
+        assert_eq!(
+            Block::new().size_hint(),
+            (Constraint::Length(0), Constraint::Length(0))
+        );
+        assert_eq!(
+            Block::bordered().size_hint(),
+            (Constraint::Length(2), Constraint::Length(2))
+        );
+        assert_eq!(
+            Block::bordered().padding(Padding::uniform(1)).size_hint(),
+            (Constraint::Length(4), Constraint::Length(4))
+        );
+        assert_eq!(
+            Block::bordered()
+                .title("Title")
+                .title_bottom("Longer title")
+                .size_hint(),
+            (Constraint::Length(14), Constraint::Length(2))
+        );
+        assert_eq!(
+            Block::new().title("Title").size_hint(),
+            (Constraint::Length(5), Constraint::Length(1))
+        );
+    }
+
+    #[test]
+    fn render_corner_set() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_type(BorderType::Double)
+            .corner_set(border::PLAIN)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌════════┐",
+            "║        ║",
+            "└════════┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_rounded_corners() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .rounded_corners()
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "╭────────╮",
+            "│        │",
+            "╰────────╯",
+        ]);
+        assert_eq!(buffer, expected);
+
+        // setting the border type afterwards replaces the corners
+        let block = Block::bordered()
+            .rounded_corners()
+            .border_type(BorderType::Plain);
+        assert_eq!(block, Block::bordered());
+    }
+
+    #[test]
+    fn render_connected_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 5));
+        let block = Block::bordered().connect_borders(true);
+        for (x, y) in [(0, 0), (3, 0), (0, 2), (3, 2)] {
+            (&block).render(Rect::new(x, y, 4, 3), &mut buffer);
+        }
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┬──┐",
+            "│  │  │",
+            "├──┼──┤",
+            "│  │  │",
+            "└──┴──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_without_connected_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        Block::bordered().render(Rect::new(3, 0, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┌──┐",
+            "│  │  │",
+            "└──└──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_connected_borders_of_different_weights() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        Block::bordered()
+            .border_type(BorderType::Thick)
+            .connect_borders(true)
+            .render(Rect::new(3, 0, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┏━━┓",
+            "│  ┃  ┃",
+            "└──┗━━┛",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow() {
+        let mut buffer = Buffer::with_lines(["abcdef", "abcdef", "abcdef", "abcdef"]);
+        Block::bordered()
+            .shadow(Style::new().on_black(), (2, 1))
+            .render(Rect::new(0, 0, 4, 3), &mut buffer);
+        let mut expected = Buffer::with_lines(["┌──┐ef", "│bc│ef", "└──┘ef", "abcdef"]);
+        expected.set_style(Rect::new(4, 1, 2, 2), Style::new().on_black());
+        expected.set_style(Rect::new(2, 3, 4, 1), Style::new().on_black());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow_clipped_to_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Block::bordered()
+            .shadow(Style::new().on_black(), (-1, 2))
+            .render(Rect::new(1, 0, 3, 2), &mut buffer);
+        let mut expected = Buffer::with_lines([" ┌─┐", " └─┘", "    "]);
+        expected.set_style(Rect::new(0, 2, 3, 1), Style::new().on_black());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_border_only_keeps_content() {
+        let mut buffer = Buffer::with_lines(["abcde", "abcde", "abcde"]);
+        buffer.set_style(buffer.area, Style::new().red());
+        Block::bordered()
+            .title("T")
+            .on_blue()
+            .render_border_only(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌T──┐", "│bcd│", "└───┘"]);
+        expected.set_style(expected.area, Style::new().red());
+        for border in [
+            Rect::new(0, 0, 5, 1),
+            Rect::new(0, 1, 1, 1),
+            Rect::new(4, 1, 1, 1),
+            Rect::new(0, 2, 5, 1),
+        ] {
+            expected.set_style(border, Style::new().on_blue());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_border_only_without_borders() {
+        let mut buffer = Buffer::with_lines(["abc"]);
+        Block::new()
+            .on_blue()
+            .render_border_only(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["abc"]));
+    }
 }
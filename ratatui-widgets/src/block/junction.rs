@@ -0,0 +1,139 @@
+//! Connects the borders of adjacent blocks by merging box drawing symbols into junctions.
+
+use ratatui_core::symbols::line;
+
+/// The directions in which a box drawing symbol has a line, stored as bit flags
+type Arms = u8;
+
+const UP: Arms = 1;
+const DOWN: Arms = 1 << 1;
+const LEFT: Arms = 1 << 2;
+const RIGHT: Arms = 1 << 3;
+
+/// The weight of the lines of a box drawing symbol
+///
+/// Only symbols of the same weight are merged, as there are no junctions that mix weights in the
+/// symbols used by the blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Weight {
+    Light,
+    Thick,
+    Double,
+}
+
+/// The box drawing symbols that can be merged, with their weight and arms
+///
+/// When looking up a symbol by its arms, the first match is used, so the rounded corners come
+/// after the plain ones.
+const SYMBOLS: [(&str, Weight, Arms); 37] = [
+    (line::VERTICAL, Weight::Light, UP | DOWN),
+    (line::HORIZONTAL, Weight::Light, LEFT | RIGHT),
+    (line::TOP_LEFT, Weight::Light, DOWN | RIGHT),
+    (line::TOP_RIGHT, Weight::Light, DOWN | LEFT),
+    (line::BOTTOM_LEFT, Weight::Light, UP | RIGHT),
+    (line::BOTTOM_RIGHT, Weight::Light, UP | LEFT),
+    (line::VERTICAL_LEFT, Weight::Light, UP | DOWN | LEFT),
+    (line::VERTICAL_RIGHT, Weight::Light, UP | DOWN | RIGHT),
+    (line::HORIZONTAL_DOWN, Weight::Light, LEFT | RIGHT | DOWN),
+    (line::HORIZONTAL_UP, Weight::Light, LEFT | RIGHT | UP),
+    (line::CROSS, Weight::Light, UP | DOWN | LEFT | RIGHT),
+    (line::ROUNDED_TOP_LEFT, Weight::Light, DOWN | RIGHT),
+    (line::ROUNDED_TOP_RIGHT, Weight::Light, DOWN | LEFT),
+    (line::ROUNDED_BOTTOM_LEFT, Weight::Light, UP | RIGHT),
+    (line::ROUNDED_BOTTOM_RIGHT, Weight::Light, UP | LEFT),
+    (line::THICK_VERTICAL, Weight::Thick, UP | DOWN),
+    (line::THICK_HORIZONTAL, Weight::Thick, LEFT | RIGHT),
+    (line::THICK_TOP_LEFT, Weight::Thick, DOWN | RIGHT),
+    (line::THICK_TOP_RIGHT, Weight::Thick, DOWN | LEFT),
+    (line::THICK_BOTTOM_LEFT, Weight::Thick, UP | RIGHT),
+    (line::THICK_BOTTOM_RIGHT, Weight::Thick, UP | LEFT),
+    (line::THICK_VERTICAL_LEFT, Weight::Thick, UP | DOWN | LEFT),
+    (line::THICK_VERTICAL_RIGHT, Weight::Thick, UP | DOWN | RIGHT),
+    (
+        line::THICK_HORIZONTAL_DOWN,
+        Weight::Thick,
+        LEFT | RIGHT | DOWN,
+    ),
+    (line::THICK_HORIZONTAL_UP, Weight::Thick, LEFT | RIGHT | UP),
+    (line::THICK_CROSS, Weight::Thick, UP | DOWN | LEFT | RIGHT),
+    (line::DOUBLE_VERTICAL, Weight::Double, UP | DOWN),
+    (line::DOUBLE_HORIZONTAL, Weight::Double, LEFT | RIGHT),
+    (line::DOUBLE_TOP_LEFT, Weight::Double, DOWN | RIGHT),
+    (line::DOUBLE_TOP_RIGHT, Weight::Double, DOWN | LEFT),
+    (line::DOUBLE_BOTTOM_LEFT, Weight::Double, UP | RIGHT),
+    (line::DOUBLE_BOTTOM_RIGHT, Weight::Double, UP | LEFT),
+    (line::DOUBLE_VERTICAL_LEFT, Weight::Double, UP | DOWN | LEFT),
+    (
+        line::DOUBLE_VERTICAL_RIGHT,
+        Weight::Double,
+        UP | DOWN | RIGHT,
+    ),
+    (
+        line::DOUBLE_HORIZONTAL_DOWN,
+        Weight::Double,
+        LEFT | RIGHT | DOWN,
+    ),
+    (
+        line::DOUBLE_HORIZONTAL_UP,
+        Weight::Double,
+        LEFT | RIGHT | UP,
+    ),
+    (line::DOUBLE_CROSS, Weight::Double, UP | DOWN | LEFT | RIGHT),
+];
+
+/// Returns the weight and arms of the given box drawing symbol
+fn parse(symbol: &str) -> Option<(Weight, Arms)> {
+    SYMBOLS
+        .iter()
+        .find(|(s, _, _)| *s == symbol)
+        .map(|&(_, weight, arms)| (weight, arms))
+}
+
+/// Returns the box drawing symbol with the given weight and arms
+fn lookup(weight: Weight, arms: Arms) -> Option<&'static str> {
+    SYMBOLS
+        .iter()
+        .find(|&&(_, w, a)| w == weight && a == arms)
+        .map(|&(symbol, _, _)| symbol)
+}
+
+/// Returns the junction that connects the `previous` symbol of a cell with the `current` one.
+///
+/// Returns `None` when the symbols can't be connected (e.g. one of them is not a box drawing
+/// symbol or they have different weights), or when `current` already has all the arms of
+/// `previous`, in which case the current symbol should be kept.
+pub(super) fn connect(previous: &str, current: &str) -> Option<&'static str> {
+    let (previous_weight, previous_arms) = parse(previous)?;
+    let (weight, arms) = parse(current)?;
+    if previous_weight != weight || arms | previous_arms == arms {
+        return None;
+    }
+    lookup(weight, arms | previous_arms)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::corners("┐", "┌", Some("┬"))]
+    #[case::tee("├", "┤", Some("┼"))]
+    #[case::crossing_lines("─", "│", Some("┼"))]
+    #[case::rounded("╯", "╰", Some("┴"))]
+    #[case::thick("┃", "┗", Some("┣"))]
+    #[case::double("╦", "╩", Some("╬"))]
+    #[case::same_arms("│", "│", None)]
+    #[case::fewer_arms("┼", "│", Some("┼"))]
+    #[case::different_weights("─", "┃", None)]
+    #[case::not_a_border(" ", "│", None)]
+    #[case::dashed("╎", "─", None)]
+    fn connect_symbols(
+        #[case] previous: &str,
+        #[case] current: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(connect(previous, current), expected);
+    }
+}
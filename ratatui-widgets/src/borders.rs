@@ -143,6 +143,21 @@ pub enum BorderType {
     /// ▌       ▐
     /// ▙▄▄▄▄▄▄▄▟
     QuadrantOutside,
+
+    /// A border drawn with half blocks so that it sits between the border cells and the content.
+    ///
+    /// The top and bottom edges use half blocks and the left and right edges use full blocks,
+    /// which makes the horizontal and vertical edges appear to be the same thickness. This gives a
+    /// tighter look than [`QuadrantOutside`](BorderType::QuadrantOutside).
+    ///
+    /// # Example
+    ///
+    /// ```plain
+    /// ▄▄▄▄▄▄▄▄▄
+    /// █       █
+    /// █       █
+    /// ▀▀▀▀▀▀▀▀▀
+    HalfBlock,
 }
 
 impl BorderType {
@@ -161,6 +176,7 @@ impl BorderType {
             Self::HeavyQuadrupleDashed => border::HEAVY_QUADRUPLE_DASHED,
             Self::QuadrantInside => border::QUADRANT_INSIDE,
             Self::QuadrantOutside => border::QUADRANT_OUTSIDE,
+            Self::HalfBlock => border::PROPORTIONAL_WIDE,
         }
     }
 
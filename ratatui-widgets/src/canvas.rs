@@ -13,17 +13,18 @@
 //!
 //! You can also implement your own custom [`Shape`]s.
 
-use std::{fmt, iter::zip};
+use std::{cell::Cell, fmt, iter::zip, sync::Arc};
 
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Position, Rect},
     style::{Color, Style},
     symbols::{self, Marker},
     text::Line as TextLine,
     widgets::Widget,
 };
+use strum::{Display, EnumString};
 
 pub use self::{
     circle::Circle,
@@ -73,6 +74,33 @@ struct Layer {
     colors: Vec<(Color, Color)>,
 }
 
+/// A named layer of a [`Canvas`] painted by its own closure, see [`Canvas::layer`].
+#[derive(Clone)]
+struct NamedLayer<'a> {
+    name: String,
+    z_index: i32,
+    paint: Arc<dyn Fn(&mut Context) + Send + Sync + 'a>,
+}
+
+impl fmt::Debug for NamedLayer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NamedLayer")
+            .field("name", &self.name)
+            .field("z_index", &self.z_index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for NamedLayer<'_> {
+    /// Closures can't be compared by value, so layers are only equal if they share the same
+    /// closure (e.g. one is a clone of the other).
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.z_index == other.z_index
+            && Arc::ptr_eq(&self.paint, &other.paint)
+    }
+}
+
 /// A grid of cells that can be painted on.
 ///
 /// The grid represents a particular screen region measured in rows and columns. The underlying
@@ -363,7 +391,8 @@ impl Painter<'_, '_> {
     /// `(x, y)` coordinates to the location of a point on the grid.
     ///
     /// Points are rounded to the nearest grid cell (with points exactly in the center of a cell
-    /// rounding up).
+    /// rounding up). Points outside of the bounds are handled according to the [`ClipMode`] of the
+    /// canvas: they are either dropped (`None` is returned) or clamped to the nearest edge.
     ///
     /// # Examples
     ///
@@ -392,11 +421,14 @@ impl Painter<'_, '_> {
     /// assert_eq!(point, Some((0, 0)));
     /// ```
     pub fn get_point(&self, x: f64, y: f64) -> Option<(usize, usize)> {
-        let [left, right] = self.context.x_bounds;
-        let [bottom, top] = self.context.y_bounds;
-        if x < left || x > right || y < bottom || y > top {
-            return None;
+        if let Some(extent) = &self.context.extent {
+            extent.set(extend(extent.get(), x, y));
+            return Some((0, 0));
         }
+        let (x_bounds, y_bounds) = self.bounds();
+        let (x, y) = self.context.clip_mode.apply(x, y, x_bounds, y_bounds)?;
+        let [left, right] = *x_bounds;
+        let [bottom, top] = *y_bounds;
         let width = right - left;
         let height = top - bottom;
         if width <= 0.0 || height <= 0.0 {
@@ -444,6 +476,13 @@ impl Painter<'_, '_> {
     pub fn bounds(&self) -> (&[f64; 2], &[f64; 2]) {
         (&self.context.x_bounds, &self.context.y_bounds)
     }
+
+    /// How the points outside of the bounds of the canvas are handled.
+    ///
+    /// See [`Canvas::clip_mode`].
+    pub const fn clip_mode(&self) -> ClipMode {
+        self.context.clip_mode
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
@@ -464,10 +503,13 @@ impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
 pub struct Context<'a> {
     x_bounds: [f64; 2],
     y_bounds: [f64; 2],
+    clip_mode: ClipMode,
     grid: Box<dyn Grid>,
     dirty: bool,
     layers: Vec<Layer>,
     labels: Vec<Label<'a>>,
+    /// The extent of the points drawn so far, when measuring the shapes to fit the bounds
+    extent: Option<Cell<Option<Extent>>>,
 }
 
 impl<'a> Context<'a> {
@@ -513,13 +555,35 @@ impl<'a> Context<'a> {
         Self {
             x_bounds,
             y_bounds,
+            clip_mode: ClipMode::Drop,
             grid,
             dirty: false,
             layers: Vec::new(),
             labels: Vec::new(),
+            extent: None,
+        }
+    }
+
+    /// Create a context that records the extent of the drawn shapes instead of painting them.
+    ///
+    /// The bounds of this context cover every finite coordinate, so that shapes are not clipped,
+    /// and every point is mapped to the single point of the grid.
+    fn measuring() -> Self {
+        let bounds = [f64::MIN, f64::MAX];
+        Self {
+            extent: Some(Cell::new(None)),
+            ..Self::new(1, 1, bounds, bounds, Marker::Dot)
         }
     }
 
+    /// Returns the extent of the shapes and labels drawn on a measuring context
+    fn extent(&self) -> Option<Extent> {
+        let extent = self.extent.as_ref()?.get();
+        self.labels
+            .iter()
+            .fold(extent, |extent, label| extend(extent, label.x, label.y))
+    }
+
     /// Draw the given [`Shape`] in this context
     pub fn draw<S>(&mut self, shape: &S)
     where
@@ -568,6 +632,74 @@ impl<'a> Context<'a> {
     }
 }
 
+/// The ranges `([left, right], [bottom, top])` covered by the points drawn on a canvas
+type Extent = ([f64; 2], [f64; 2]);
+
+/// Extends the extent to include the point `(x, y)`.
+///
+/// Points with a non-finite coordinate are ignored.
+fn extend(extent: Option<Extent>, x: f64, y: f64) -> Option<Extent> {
+    if !x.is_finite() || !y.is_finite() {
+        return extent;
+    }
+    match extent {
+        Some(([left, right], [bottom, top])) => {
+            Some(([left.min(x), right.max(x)], [bottom.min(y), top.max(y)]))
+        }
+        None => Some(([x, x], [y, y])),
+    }
+}
+
+/// Fits the bounds of an axis to the range `[min, max]` of the drawn points.
+///
+/// The range is widened by `padding` (a fraction of the range) on both sides. A single value is
+/// widened by [`AUTOFIT_MARGIN`] on both sides (or by its precision for huge values) so that the
+/// range is never empty. Without any value, the `fallback` bounds are used, or `[0.0, 1.0]` if
+/// these are empty.
+fn fit_axis(range: Option<[f64; 2]>, padding: f64, fallback: [f64; 2]) -> [f64; 2] {
+    match range {
+        Some([min, max]) if max > min => {
+            let padding = (max - min) * padding;
+            [min - padding, max + padding]
+        }
+        Some([value, _]) => {
+            let margin = AUTOFIT_MARGIN.max(value.abs() * f64::EPSILON);
+            [value - margin, value + margin]
+        }
+        None if fallback[1] > fallback[0] => fallback,
+        None => [0.0, 1.0],
+    }
+}
+
+/// Margin added around the value of an axis when all the points have the same coordinate
+const AUTOFIT_MARGIN: f64 = 0.5;
+
+/// Defines how the points outside of the bounds of a [`Canvas`] are handled.
+///
+/// See [`Canvas::clip_mode`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ClipMode {
+    /// Points outside of the bounds are not drawn
+    #[default]
+    Drop,
+    /// Points outside of the bounds are moved to the nearest edge of the canvas
+    Clamp,
+}
+
+impl ClipMode {
+    /// Applies the clip mode to the point `(x, y)`, returning `None` if it is dropped.
+    fn apply(self, x: f64, y: f64, x_bounds: &[f64; 2], y_bounds: &[f64; 2]) -> Option<(f64, f64)> {
+        let [left, right] = *x_bounds;
+        let [bottom, top] = *y_bounds;
+        match self {
+            Self::Drop if x < left || x > right || y < bottom || y > top => None,
+            Self::Drop => Some((x, y)),
+            // not using `f64::clamp` as it panics when the bounds are inverted
+            Self::Clamp => Some((x.max(left).min(right), y.max(bottom).min(top))),
+        }
+    }
+}
+
 /// The Canvas widget provides a means to draw shapes (Lines, Rectangles, Circles, etc.) on a grid.
 ///
 /// By default the grid is made of Braille patterns but you may change the marker to use a different
@@ -594,6 +726,12 @@ impl<'a> Context<'a> {
 /// used to print text on the canvas. Note that the text is always printed on top of the canvas and
 /// is not affected by the layers.
 ///
+/// Shapes can also be grouped in named layers with [`Canvas::layer`]. Each named layer is painted
+/// by its own closure, and the layers are painted in the order of their z-index, the closure given
+/// to [`Canvas::paint`] having a z-index of `0`. A named layer can be replaced or removed between
+/// frames (see [`Canvas::set_layer`] and [`Canvas::remove_layer`]) without changing the other
+/// layers, e.g. to redraw the units of a map on top of a static terrain.
+///
 /// # Examples
 ///
 /// ```
@@ -642,8 +780,12 @@ where
     x_bounds: [f64; 2],
     y_bounds: [f64; 2],
     paint_func: Option<F>,
+    layers: Vec<NamedLayer<'a>>,
     background_color: Color,
     marker: Marker,
+    clip_mode: ClipMode,
+    autofit: bool,
+    autofit_padding: f64,
 }
 
 impl<F> Default for Canvas<'_, F>
@@ -656,8 +798,12 @@ where
             x_bounds: [0.0, 0.0],
             y_bounds: [0.0, 0.0],
             paint_func: None,
+            layers: Vec::new(),
             background_color: Color::Reset,
             marker: Marker::Braille,
+            clip_mode: ClipMode::Drop,
+            autofit: false,
+            autofit_padding: 0.0,
         }
     }
 }
@@ -699,6 +845,66 @@ where
         self
     }
 
+    /// Fit the bounds of the canvas to the shapes that are drawn on it.
+    ///
+    /// When enabled, the painting closures are run once before rendering to measure the minimum
+    /// and maximum coordinates of the drawn shapes and labels on each axis, and these are used as
+    /// the bounds of the canvas instead of the ones given to [`Canvas::x_bounds`] and
+    /// [`Canvas::y_bounds`]. The bounds can be widened with [`Canvas::autofit_padding`].
+    ///
+    /// The fitted bounds are not cached, so the closures are also run on each call to
+    /// [`Canvas::world_to_cell`] and [`Canvas::cell_to_world`]. The closures should therefore be
+    /// free of side effects, and converting many points is cheaper with autofit disabled and the
+    /// bounds set explicitly.
+    ///
+    /// When all the points have the same coordinate on an axis (e.g. a single point), a small
+    /// range centered on that coordinate is used. When nothing is drawn, the bounds given to
+    /// [`Canvas::x_bounds`] and [`Canvas::y_bounds`] are used, or `[0.0, 1.0]` if they are empty.
+    ///
+    /// The shapes are measured using the points they pass to [`Painter::get_point`], with
+    /// unbounded axes. Custom shapes which use [`Painter::bounds`] to decide what to draw should
+    /// not be used with this option.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     widgets::canvas::{Canvas, Points},
+    /// };
+    ///
+    /// let data = [(1.0, 20.0), (2.0, 35.0), (3.0, 30.0)];
+    /// let canvas = Canvas::default()
+    ///     .autofit(true)
+    ///     .autofit_padding(0.1)
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Points {
+    ///             coords: &data,
+    ///             color: Color::Red,
+    ///         });
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn autofit(mut self, autofit: bool) -> Self {
+        self.autofit = autofit;
+        self
+    }
+
+    /// Set the padding added around the drawn shapes when the bounds are fitted to them.
+    ///
+    /// The padding is a fraction of the range of each axis which is added on both sides, e.g.
+    /// `0.1` widens each axis by 10% of the range of the drawn points before and after them. This
+    /// has no effect unless [`Canvas::autofit`] is enabled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn autofit_padding(mut self, padding: f64) -> Self {
+        self.autofit_padding = padding;
+        self
+    }
+
     /// Store the closure that will be used to draw to the [`Canvas`]
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -708,6 +914,81 @@ where
         self
     }
 
+    /// Adds a named layer that is painted by the given closure.
+    ///
+    /// Layers are painted in increasing order of `z_index`, so that the shapes of a layer are drawn
+    /// over the shapes of the layers with a lower z-index. The closure given to
+    /// [`Canvas::paint`] is painted as a layer with a z-index of `0`. Layers with the same z-index
+    /// are painted in the order in which they were added, after the closure given to
+    /// [`Canvas::paint`]. Labels are always printed on top of all the layers.
+    ///
+    /// If a layer with the same name already exists, it is replaced. The closure must be [`Send`]
+    /// and [`Sync`] so that the canvas can still be sent to and shared with other threads.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     widgets::canvas::{Canvas, Map, MapResolution, Points},
+    /// };
+    ///
+    /// let canvas = Canvas::default()
+    ///     .x_bounds([-180.0, 180.0])
+    ///     .y_bounds([-90.0, 90.0])
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Map {
+    ///             resolution: MapResolution::High,
+    ///             color: Color::Green,
+    ///         });
+    ///     })
+    ///     .layer("units", 1, |ctx| {
+    ///         ctx.draw(&Points {
+    ///             coords: &[(2.35, 48.85)],
+    ///             color: Color::Red,
+    ///         });
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn layer<N, P>(mut self, name: N, z_index: i32, paint: P) -> Self
+    where
+        N: Into<String>,
+        P: Fn(&mut Context) + Send + Sync + 'a,
+    {
+        self.set_layer(name, z_index, paint);
+        self
+    }
+
+    /// Adds or replaces the named layer that is painted by the given closure.
+    ///
+    /// This is the same as [`Canvas::layer`], but modifies the canvas in place. This is useful to
+    /// replace a layer of a canvas that is kept between frames, without rebuilding the other
+    /// layers.
+    pub fn set_layer<N, P>(&mut self, name: N, z_index: i32, paint: P)
+    where
+        N: Into<String>,
+        P: Fn(&mut Context) + Send + Sync + 'a,
+    {
+        let layer = NamedLayer {
+            name: name.into(),
+            z_index,
+            paint: Arc::new(paint),
+        };
+        match self.layers.iter_mut().find(|l| l.name == layer.name) {
+            Some(existing) => *existing = layer,
+            None => self.layers.push(layer),
+        }
+    }
+
+    /// Removes the named layer, returning whether it existed.
+    pub fn remove_layer(&mut self, name: &str) -> bool {
+        let len = self.layers.len();
+        self.layers.retain(|layer| layer.name != name);
+        self.layers.len() != len
+    }
+
     /// Change the background [`Color`] of the entire canvas
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -760,6 +1041,245 @@ where
         self.marker = marker;
         self
     }
+
+    /// Set how the points outside of the bounds of the canvas are handled.
+    ///
+    /// With [`ClipMode::Drop`] (the default), these points are not drawn. With [`ClipMode::Clamp`],
+    /// they are moved to the nearest edge of the canvas, so that e.g. the data points of a chart
+    /// which exceed its bounds are still visible. [`Line`]s are still clipped to the bounds, but a
+    /// line which does not cross the canvas is drawn along its nearest edges, through the corner
+    /// between them, unless both of its endpoints are outside on the same side, in which case it is
+    /// not drawn.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     widgets::canvas::{Canvas, ClipMode, Points},
+    /// };
+    ///
+    /// let canvas = Canvas::default()
+    ///     .x_bounds([0.0, 10.0])
+    ///     .y_bounds([0.0, 10.0])
+    ///     .clip_mode(ClipMode::Clamp)
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Points {
+    ///             coords: &[(5.0, 20.0)],
+    ///             color: Color::Red,
+    ///         });
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn clip_mode(mut self, clip_mode: ClipMode) -> Self {
+        self.clip_mode = clip_mode;
+        self
+    }
+
+    /// Returns the resolution of the canvas in number of points when rendered in the given area.
+    ///
+    /// The resolution depends on the [`Marker`] used by the canvas: [`Braille`] has 2x4 points per
+    /// cell, [`HalfBlock`] has 1x2 points per cell and the other markers have a single point per
+    /// cell. The area taken by the block of the canvas (if any) is excluded.
+    ///
+    /// This is useful to know how many data points map to a single point of the canvas, e.g. to
+    /// downsample the data to avoid over-plotting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{layout::Rect, symbols, widgets::canvas::Canvas};
+    ///
+    /// let canvas = Canvas::default()
+    ///     .marker(symbols::Marker::Braille)
+    ///     .paint(|_ctx| {});
+    /// assert_eq!(canvas.resolution(Rect::new(0, 0, 10, 5)), (20.0, 20.0));
+    /// ```
+    ///
+    /// [`Braille`]: ratatui_core::symbols::Marker::Braille
+    /// [`HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
+    pub fn resolution(&self, area: Rect) -> (f64, f64) {
+        let area = self.block.inner_if_some(area);
+        let (points_per_column, points_per_row) = self.points_per_cell();
+        (
+            f64::from(area.width) * points_per_column,
+            f64::from(area.height) * points_per_row,
+        )
+    }
+
+    /// Returns the position of the cell in which the point `(x, y)` is drawn when the canvas is
+    /// rendered in the given area.
+    ///
+    /// This uses the same mapping as the [`Painter`] uses to draw the shapes, so the returned cell
+    /// is the one that a [`Points`] shape at `(x, y)` would be drawn in. Returns `None` if the
+    /// point is outside of the bounds of the canvas (unless the [`ClipMode`] is
+    /// [`ClipMode::Clamp`]), if the bounds are empty, or if the area
+    /// (without the block of the canvas) is empty. When [`Canvas::autofit`] is enabled, the bounds
+    /// fitted to the drawn shapes are used.
+    ///
+    /// See [`Canvas::cell_to_world`] for the inverse conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     layout::{Position, Rect},
+    ///     symbols::Marker,
+    ///     widgets::canvas::Canvas,
+    /// };
+    ///
+    /// let canvas = Canvas::default()
+    ///     .marker(Marker::Block)
+    ///     .x_bounds([0.0, 9.0])
+    ///     .y_bounds([0.0, 4.0])
+    ///     .paint(|_ctx| {});
+    /// let area = Rect::new(10, 10, 10, 5);
+    /// assert_eq!(
+    ///     canvas.world_to_cell(area, 0.0, 4.0),
+    ///     Some(Position::new(10, 10))
+    /// );
+    /// assert_eq!(
+    ///     canvas.world_to_cell(area, 9.0, 0.0),
+    ///     Some(Position::new(19, 14))
+    /// );
+    /// assert_eq!(canvas.world_to_cell(area, 10.0, 0.0), None);
+    /// ```
+    pub fn world_to_cell(&self, area: Rect, x: f64, y: f64) -> Option<Position> {
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() {
+            return None;
+        }
+        let (x_bounds, y_bounds) = self.bounds();
+        let (x, y) = self.clip_mode.apply(x, y, &x_bounds, &y_bounds)?;
+        let ([left, right], [bottom, top]) = (x_bounds, y_bounds);
+        let width = right - left;
+        let height = top - bottom;
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+        let (points_per_column, points_per_row) = self.points_per_cell();
+        let (resolution_x, resolution_y) = self.resolution(area);
+        let point_x = ((x - left) * (resolution_x - 1.0) / width).round();
+        let point_y = ((top - y) * (resolution_y - 1.0) / height).round();
+        let column = (point_x / points_per_column) as u16;
+        let row = (point_y / points_per_row) as u16;
+        Some(Position::new(area.x + column, area.y + row))
+    }
+
+    /// Returns the point at the center of the given cell when the canvas is rendered in the given
+    /// area.
+    ///
+    /// This is the inverse of [`Canvas::world_to_cell`] and can be used to convert the position of
+    /// a mouse click to the coordinates of the canvas. When a cell holds several points (e.g. with
+    /// the [`Braille`] marker), the coordinates of the middle of these points are returned, so
+    /// converting them back with [`Canvas::world_to_cell`] returns the same cell. Returns `None` if
+    /// the position is outside of the area (without the block of the canvas) or if the bounds are
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     layout::{Position, Rect},
+    ///     symbols::Marker,
+    ///     widgets::canvas::Canvas,
+    /// };
+    ///
+    /// let canvas = Canvas::default()
+    ///     .marker(Marker::Block)
+    ///     .x_bounds([0.0, 9.0])
+    ///     .y_bounds([0.0, 4.0])
+    ///     .paint(|_ctx| {});
+    /// let area = Rect::new(10, 10, 10, 5);
+    /// assert_eq!(
+    ///     canvas.cell_to_world(area, Position::new(10, 10)),
+    ///     Some((0.0, 4.0))
+    /// );
+    /// assert_eq!(
+    ///     canvas.cell_to_world(area, Position::new(19, 14)),
+    ///     Some((9.0, 0.0))
+    /// );
+    /// assert_eq!(canvas.cell_to_world(area, Position::new(20, 14)), None);
+    /// ```
+    ///
+    /// [`Braille`]: ratatui_core::symbols::Marker::Braille
+    pub fn cell_to_world(&self, area: Rect, position: Position) -> Option<(f64, f64)> {
+        let area = self.block.inner_if_some(area);
+        if !area.contains(position) {
+            return None;
+        }
+        let ([left, right], [bottom, top]) = self.bounds();
+        let width = right - left;
+        let height = top - bottom;
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+        let (points_per_column, points_per_row) = self.points_per_cell();
+        let (resolution_x, resolution_y) = self.resolution(area);
+        let point_x = f64::from(position.x - area.x)
+            .mul_add(points_per_column, (points_per_column - 1.0) / 2.0);
+        let point_y =
+            f64::from(position.y - area.y).mul_add(points_per_row, (points_per_row - 1.0) / 2.0);
+        let x = if resolution_x > 1.0 {
+            point_x.mul_add(width / (resolution_x - 1.0), left)
+        } else {
+            left + width / 2.0
+        };
+        let y = if resolution_y > 1.0 {
+            point_y.mul_add(-height / (resolution_y - 1.0), top)
+        } else {
+            bottom + height / 2.0
+        };
+        Some((x, y))
+    }
+
+    /// Returns the number of points in each cell, horizontally and vertically
+    const fn points_per_cell(&self) -> (f64, f64) {
+        match self.marker {
+            Marker::Braille => (2.0, 4.0),
+            Marker::HalfBlock => (1.0, 2.0),
+            Marker::Dot | Marker::Block | Marker::Bar => (1.0, 1.0),
+        }
+    }
+
+    /// Returns the painting closures, in the order in which they are painted.
+    ///
+    /// The closure given to `paint` is the layer with a z-index of 0. The sort is stable, so
+    /// layers with the same z-index are painted in the order they were added.
+    fn painters(&self) -> Vec<&dyn Fn(&mut Context)> {
+        self.paint_func
+            .iter()
+            .map(|paint| (0, paint as &dyn Fn(&mut Context)))
+            .chain(
+                self.layers
+                    .iter()
+                    .map(|layer| (layer.z_index, &*layer.paint as &dyn Fn(&mut Context))),
+            )
+            .sorted_by_key(|(z_index, _)| *z_index)
+            .map(|(_, painter)| painter)
+            .collect_vec()
+    }
+
+    /// Returns the `x` and `y` bounds used to render the canvas.
+    ///
+    /// These are the configured bounds, or the bounds fitted to the drawn shapes when
+    /// [`Canvas::autofit`] is enabled.
+    fn bounds(&self) -> ([f64; 2], [f64; 2]) {
+        if !self.autofit {
+            return (self.x_bounds, self.y_bounds);
+        }
+        let mut ctx = Context::measuring();
+        for painter in self.painters() {
+            painter(&mut ctx);
+        }
+        let extent = ctx.extent();
+        (
+            fit_axis(extent.map(|(x, _)| x), self.autofit_padding, self.x_bounds),
+            fit_axis(extent.map(|(_, y)| y), self.autofit_padding, self.y_bounds),
+        )
+    }
 }
 
 impl<F> Widget for Canvas<'_, F>
@@ -786,21 +1306,26 @@ where
 
         let width = canvas_area.width as usize;
 
-        let Some(ref painter) = self.paint_func else {
+        let painters = self.painters();
+        if painters.is_empty() {
             return;
-        };
+        }
 
         // Create a blank context that match the size of the canvas
+        let (x_bounds, y_bounds) = self.bounds();
         let mut ctx = Context::new(
             canvas_area.width,
             canvas_area.height,
-            self.x_bounds,
-            self.y_bounds,
+            x_bounds,
+            y_bounds,
             self.marker,
         );
-        // Paint to this context
-        painter(&mut ctx);
-        ctx.finish();
+        ctx.clip_mode = self.clip_mode;
+        // Paint each layer to this context
+        for painter in painters {
+            painter(&mut ctx);
+            ctx.finish();
+        }
 
         // Retrieve painted points for each layer
         for layer in ctx.layers {
@@ -822,12 +1347,12 @@ where
         }
 
         // Finally draw the labels
-        let left = self.x_bounds[0];
-        let right = self.x_bounds[1];
-        let top = self.y_bounds[1];
-        let bottom = self.y_bounds[0];
-        let width = (self.x_bounds[1] - self.x_bounds[0]).abs();
-        let height = (self.y_bounds[1] - self.y_bounds[0]).abs();
+        let left = x_bounds[0];
+        let right = x_bounds[1];
+        let top = y_bounds[1];
+        let bottom = y_bounds[0];
+        let width = (x_bounds[1] - x_bounds[0]).abs();
+        let height = (y_bounds[1] - y_bounds[0]).abs();
         let resolution = {
             let width = f64::from(canvas_area.width - 1);
             let height = f64::from(canvas_area.height - 1);
@@ -849,6 +1374,7 @@ where
 mod tests {
     use indoc::indoc;
     use ratatui_core::buffer::Cell;
+    use rstest::rstest;
 
     use super::*;
 
@@ -942,4 +1468,254 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn render_layers_in_z_order() {
+        let area = Rect::new(0, 0, 5, 5);
+        let line = |x2, y2, color| Line {
+            x1: 0.0,
+            y1: 0.0,
+            x2,
+            y2,
+            color,
+        };
+        let mut canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|ctx| ctx.draw(&line(10.0, 0.0, Color::Red)))
+            .layer("overlay", 1, move |ctx| {
+                ctx.draw(&line(0.0, 10.0, Color::Blue));
+            })
+            .layer("base", -1, move |ctx| {
+                ctx.draw(&line(10.0, 0.0, Color::Green));
+            });
+
+        let mut buf = Buffer::empty(area);
+        Widget::render(&canvas, area, &mut buf);
+        let mut expected = Buffer::with_lines(["█    ", "█    ", "█    ", "█    ", "█████"]);
+        expected.set_style(Rect::new(0, 0, 1, 5), Color::Blue);
+        expected.set_style(Rect::new(1, 4, 4, 1), Color::Red);
+        assert_eq!(buf, expected);
+
+        // replacing a layer keeps the other layers
+        canvas.set_layer("overlay", 1, |_ctx| {});
+        let mut buf = Buffer::empty(area);
+        Widget::render(&canvas, area, &mut buf);
+        let mut expected = Buffer::with_lines(["     ", "     ", "     ", "     ", "█████"]);
+        expected.set_style(Rect::new(0, 4, 5, 1), Color::Red);
+        assert_eq!(buf, expected);
+
+        // moving the base layer above the default layer
+        assert!(canvas.remove_layer("overlay"));
+        assert!(!canvas.remove_layer("overlay"));
+        canvas.set_layer("base", 1, move |ctx| {
+            ctx.draw(&line(10.0, 0.0, Color::Green));
+        });
+        let mut buf = Buffer::empty(area);
+        Widget::render(&canvas, area, &mut buf);
+        let mut expected = Buffer::with_lines(["     ", "     ", "     ", "     ", "█████"]);
+        expected.set_style(Rect::new(0, 4, 5, 1), Color::Green);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn resolution() {
+        let area = Rect::new(0, 0, 10, 5);
+        let canvas = |marker| Canvas::default().marker(marker).paint(|_ctx| {});
+        assert_eq!(canvas(Marker::Braille).resolution(area), (20.0, 20.0));
+        assert_eq!(canvas(Marker::HalfBlock).resolution(area), (10.0, 10.0));
+        assert_eq!(canvas(Marker::Dot).resolution(area), (10.0, 5.0));
+        assert_eq!(canvas(Marker::Block).resolution(area), (10.0, 5.0));
+        assert_eq!(canvas(Marker::Bar).resolution(area), (10.0, 5.0));
+
+        let canvas = canvas(Marker::Braille).block(Block::bordered());
+        assert_eq!(canvas.resolution(area), (16.0, 12.0));
+    }
+
+    #[rstest]
+    #[case::braille(Marker::Braille)]
+    #[case::half_block(Marker::HalfBlock)]
+    #[case::dot(Marker::Dot)]
+    #[case::block(Marker::Block)]
+    #[case::bar(Marker::Bar)]
+    fn cell_to_world_round_trip(#[case] marker: Marker) {
+        let area = Rect::new(2, 1, 10, 5);
+        let canvas = Canvas::default()
+            .marker(marker)
+            .x_bounds([-5.0, 5.0])
+            .y_bounds([100.0, 200.0])
+            .paint(|_ctx| {});
+        for position in area.positions() {
+            let (x, y) = canvas.cell_to_world(area, position).unwrap();
+            assert_eq!(canvas.world_to_cell(area, x, y), Some(position));
+        }
+    }
+
+    #[test]
+    fn world_to_cell() {
+        let area = Rect::new(2, 1, 10, 5);
+        let canvas = Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([0.0, 19.0])
+            .y_bounds([0.0, 19.0])
+            .paint(|_ctx| {});
+        assert_eq!(
+            canvas.world_to_cell(area, 0.0, 19.0),
+            Some(Position::new(2, 1))
+        );
+        assert_eq!(
+            canvas.world_to_cell(area, 1.0, 16.0),
+            Some(Position::new(2, 1))
+        );
+        assert_eq!(
+            canvas.world_to_cell(area, 2.0, 15.0),
+            Some(Position::new(3, 2))
+        );
+        assert_eq!(
+            canvas.world_to_cell(area, 19.0, 0.0),
+            Some(Position::new(11, 5))
+        );
+        assert_eq!(canvas.world_to_cell(area, -1.0, 0.0), None);
+        assert_eq!(canvas.world_to_cell(area, 0.0, 20.0), None);
+        assert_eq!(canvas.world_to_cell(Rect::ZERO, 0.0, 0.0), None);
+
+        let canvas = canvas.block(Block::bordered());
+        assert_eq!(
+            canvas.world_to_cell(area, 0.0, 19.0),
+            Some(Position::new(3, 2))
+        );
+    }
+
+    #[test]
+    fn world_to_cell_clamp() {
+        let area = Rect::new(0, 0, 10, 5);
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 4.0])
+            .clip_mode(ClipMode::Clamp)
+            .paint(|_ctx| {});
+        assert_eq!(
+            canvas.world_to_cell(area, -5.0, 10.0),
+            Some(Position::new(0, 0))
+        );
+        assert_eq!(
+            canvas.world_to_cell(area, 20.0, 2.0),
+            Some(Position::new(9, 2))
+        );
+        assert_eq!(
+            canvas.world_to_cell(area, 4.0, -1.0),
+            Some(Position::new(4, 4))
+        );
+    }
+
+    #[rstest]
+    #[case::drop(ClipMode::Drop, ["     ", "     ", "     "])]
+    #[case::clamp(ClipMode::Clamp, ["  • •", "     ", "•    "])]
+    fn render_clip_mode(#[case] clip_mode: ClipMode, #[case] expected: [&str; 3]) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 2.0])
+            .clip_mode(clip_mode)
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &[(2.0, 5.0), (10.0, 10.0), (-3.0, -3.0)],
+                    color: Color::Reset,
+                });
+            })
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn cell_to_world() {
+        let area = Rect::new(2, 1, 10, 5);
+        let canvas = Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([0.0, 19.0])
+            .y_bounds([0.0, 19.0])
+            .paint(|_ctx| {});
+        assert_eq!(
+            canvas.cell_to_world(area, Position::new(2, 1)),
+            Some((0.5, 17.5))
+        );
+        assert_eq!(
+            canvas.cell_to_world(area, Position::new(11, 5)),
+            Some((18.5, 1.5))
+        );
+        assert_eq!(canvas.cell_to_world(area, Position::new(1, 1)), None);
+        assert_eq!(canvas.cell_to_world(area, Position::new(12, 1)), None);
+
+        let canvas = canvas.x_bounds([0.0, 0.0]);
+        assert_eq!(canvas.cell_to_world(area, Position::new(2, 1)), None);
+    }
+
+    #[test]
+    fn render_autofit() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Canvas::default()
+            .marker(Marker::Block)
+            .autofit(true)
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &[(10.0, 100.0), (20.0, 200.0)],
+                    color: Color::Reset,
+                });
+            })
+            .render(buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "    █", //
+            "     ", "█    ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn autofit_bounds() {
+        let canvas = Canvas::default()
+            .autofit(true)
+            .autofit_padding(0.5)
+            .paint(|ctx| {
+                ctx.draw(&Line::new(0.0, 10.0, 4.0, 20.0, Color::Reset));
+                ctx.print(-2.0, 15.0, "label");
+            });
+        assert_eq!(canvas.bounds(), ([-5.0, 7.0], [5.0, 25.0]));
+
+        let canvas = canvas.autofit(false).x_bounds([0.0, 1.0]);
+        assert_eq!(canvas.bounds(), ([0.0, 1.0], [0.0, 0.0]));
+    }
+
+    #[test]
+    fn autofit_bounds_ignores_non_finite_points() {
+        let canvas = Canvas::default().autofit(true).paint(|ctx| {
+            ctx.draw(&Points {
+                coords: &[(f64::NAN, 1.0), (0.0, 1.0), (2.0, f64::INFINITY)],
+                color: Color::Reset,
+            });
+        });
+        assert_eq!(canvas.bounds(), ([-0.5, 0.5], [0.5, 1.5]));
+    }
+
+    #[rstest]
+    #[case::range(Some([1.0, 3.0]), 0.0, [1.0, 3.0])]
+    #[case::padding(Some([1.0, 3.0]), 0.25, [0.5, 3.5])]
+    #[case::single_value(Some([2.0, 2.0]), 0.25, [1.5, 2.5])]
+    #[case::fallback(None, 0.25, [-1.0, 1.0])]
+    fn fit_axis(#[case] range: Option<[f64; 2]>, #[case] padding: f64, #[case] expected: [f64; 2]) {
+        let [min, max] = super::fit_axis(range, padding, [-1.0, 1.0]);
+        assert!(
+            (min - expected[0]).abs() < 1e-9 && (max - expected[1]).abs() < 1e-9,
+            "{:?} != {expected:?}",
+            [min, max]
+        );
+    }
+
+    #[test]
+    fn fit_axis_empty_fallback() {
+        let [min, max] = super::fit_axis(None, 0.0, [0.0, 0.0]);
+        assert!(min.abs() < 1e-9 && (max - 1.0).abs() < 1e-9);
+    }
 }
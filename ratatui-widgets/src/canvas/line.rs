@@ -1,7 +1,7 @@
 use line_clipping::{cohen_sutherland, LineSegment, Point, Window};
 use ratatui_core::style::Color;
 
-use crate::canvas::{Painter, Shape};
+use crate::canvas::{ClipMode, Painter, Shape};
 
 /// A line from `(x1, y1)` to `(x2, y2)` with the given color
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -32,51 +32,66 @@ impl Line {
 }
 
 impl Shape for Line {
-    #[allow(clippy::similar_names)]
     fn draw(&self, painter: &mut Painter) {
         let (x_bounds, y_bounds) = painter.bounds();
-        let Some((world_x1, world_y1, world_x2, world_y2)) =
+        if let Some((x1, y1, x2, y2)) =
             clip_line(x_bounds, y_bounds, self.x1, self.y1, self.x2, self.y2)
-        else {
+        {
+            draw_segment(painter, (x1, y1), (x2, y2), self.color);
             return;
-        };
-        let Some((x1, y1)) = painter.get_point(world_x1, world_y1) else {
-            return;
-        };
-        let Some((x2, y2)) = painter.get_point(world_x2, world_y2) else {
-            return;
-        };
+        }
+        // a clamped line which misses the canvas is drawn along the edges nearest to it, going
+        // through the corner between them, unless it is entirely on one side of the canvas
+        let clamp = painter.clip_mode() == ClipMode::Clamp
+            && !same_side(x_bounds, self.x1, self.x2)
+            && !same_side(y_bounds, self.y1, self.y2);
+        if clamp {
+            let corner = nearest_corner(x_bounds, y_bounds, self);
+            draw_segment(painter, (self.x1, self.y1), corner, self.color);
+            draw_segment(painter, corner, (self.x2, self.y2), self.color);
+        }
+    }
+}
 
-        let (dx, x_range) = if x2 >= x1 {
-            (x2 - x1, x1..=x2)
-        } else {
-            (x1 - x2, x2..=x1)
-        };
-        let (dy, y_range) = if y2 >= y1 {
-            (y2 - y1, y1..=y2)
-        } else {
-            (y1 - y2, y2..=y1)
-        };
+/// Draws the segment between two points, expressed in the coordinates of the canvas.
+#[allow(clippy::similar_names)]
+fn draw_segment(painter: &mut Painter, from: (f64, f64), to: (f64, f64), color: Color) {
+    let Some((x1, y1)) = painter.get_point(from.0, from.1) else {
+        return;
+    };
+    let Some((x2, y2)) = painter.get_point(to.0, to.1) else {
+        return;
+    };
 
-        if dx == 0 {
-            for y in y_range {
-                painter.paint(x1, y, self.color);
-            }
-        } else if dy == 0 {
-            for x in x_range {
-                painter.paint(x, y1, self.color);
-            }
-        } else if dy < dx {
-            if x1 > x2 {
-                draw_line_low(painter, x2, y2, x1, y1, self.color);
-            } else {
-                draw_line_low(painter, x1, y1, x2, y2, self.color);
-            }
-        } else if y1 > y2 {
-            draw_line_high(painter, x2, y2, x1, y1, self.color);
+    let (dx, x_range) = if x2 >= x1 {
+        (x2 - x1, x1..=x2)
+    } else {
+        (x1 - x2, x2..=x1)
+    };
+    let (dy, y_range) = if y2 >= y1 {
+        (y2 - y1, y1..=y2)
+    } else {
+        (y1 - y2, y2..=y1)
+    };
+
+    if dx == 0 {
+        for y in y_range {
+            painter.paint(x1, y, color);
+        }
+    } else if dy == 0 {
+        for x in x_range {
+            painter.paint(x, y1, color);
+        }
+    } else if dy < dx {
+        if x1 > x2 {
+            draw_line_low(painter, x2, y2, x1, y1, color);
         } else {
-            draw_line_high(painter, x1, y1, x2, y2, self.color);
+            draw_line_low(painter, x1, y1, x2, y2, color);
         }
+    } else if y1 > y2 {
+        draw_line_high(painter, x2, y2, x1, y1, color);
+    } else {
+        draw_line_high(painter, x1, y1, x2, y2, color);
     }
 }
 
@@ -101,6 +116,28 @@ fn clip_line(
     }
 }
 
+/// Returns whether both coordinates are outside of the bounds, on the same side.
+fn same_side(&[min, max]: &[f64; 2], a: f64, b: f64) -> bool {
+    (a < min && b < min) || (a > max && b > max)
+}
+
+/// Returns the corner of the bounds which is the nearest to the (infinite) line.
+fn nearest_corner(x_bounds: &[f64; 2], y_bounds: &[f64; 2], line: &Line) -> (f64, f64) {
+    let (dx, dy) = (line.x2 - line.x1, line.y2 - line.y1);
+    // the distance to the line, scaled by the length of the line
+    let distance = |&(x, y): &(f64, f64)| (dx * (y - line.y1) - dy * (x - line.x1)).abs();
+    let corners = [
+        (x_bounds[0], y_bounds[0]),
+        (x_bounds[0], y_bounds[1]),
+        (x_bounds[1], y_bounds[0]),
+        (x_bounds[1], y_bounds[1]),
+    ];
+    corners
+        .into_iter()
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .unwrap_or(corners[0])
+}
+
 fn draw_line_low(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usize, color: Color) {
     let dx = (x2 - x1) as isize;
     let dy = (y2 as isize - y1 as isize).abs();
@@ -305,4 +342,66 @@ mod tests {
         }
         assert_eq!(buffer, expected);
     }
+
+    #[rstest]
+    #[case::same_side_x(&Line::new(-10.0, 0.0, -1.0, 10.0, Color::Red), ["          "; 10])]
+    #[case::same_side_y(&Line::new(0.0, 11.0, 10.0, 20.0, Color::Red), ["          "; 10])]
+    #[case::crossing(&Line::new(-10.0, 0.0, 5.0, 0.0, Color::Red), [
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "••••••    ",
+    ])]
+    #[case::missing_corner(&Line::new(-5.0, 8.0, 2.0, 15.0, Color::Red), [
+        "•••       ",
+        "•         ",
+        "•         ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+    ])]
+    #[case::missing_opposite_corners(&Line::new(-1.0, 30.0, 30.0, -1.0, Color::Red), [
+        "••••••••••",
+        "         •",
+        "         •",
+        "         •",
+        "         •",
+        "         •",
+        "         •",
+        "         •",
+        "         •",
+        "         •",
+    ])]
+    fn clamp<'expected_line, ExpectedLines>(#[case] line: &Line, #[case] expected: ExpectedLines)
+    where
+        ExpectedLines: IntoIterator,
+        ExpectedLines::Item: Into<ratatui_core::text::Line<'expected_line>>,
+    {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let canvas = Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .clip_mode(ClipMode::Clamp)
+            .paint(|context| context.draw(line));
+        canvas.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(expected);
+        for cell in &mut expected.content {
+            if cell.symbol() == "•" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
 }
@@ -6,7 +6,7 @@ use ratatui_core::{
     layout::{Alignment, Constraint, Flex, Layout, Position, Rect},
     style::{Color, Style, Styled},
     symbols::{self},
-    text::Line,
+    text::{Line, Span},
     widgets::Widget,
 };
 use strum::{Display, EnumString};
@@ -332,6 +332,8 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Labels drawn next to specific data points
+    annotations: Vec<(f64, f64, Span<'a>)>,
 }
 
 impl<'a> Dataset<'a> {
@@ -370,6 +372,37 @@ impl<'a> Dataset<'a> {
         self
     }
 
+    /// Sets the annotations of this dataset
+    ///
+    /// Each annotation is a `(x, y, label)` tuple. The label is drawn on top of the chart, starting
+    /// at the cell the `(x, y)` coordinate maps to, in the same way as the data points. Labels are
+    /// clipped to the plot area, and annotations whose coordinate lies outside the axis bounds are
+    /// not drawn. As the plot area excludes the axes, annotations never overlap the axes.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Stylize, widgets::Dataset};
+    ///
+    /// let dataset = Dataset::default()
+    ///     .data(&[(1.0, 1.0), (5.0, 5.0)])
+    ///     .annotations([(5.0, 5.0, "peak".bold())]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn annotations<T, S>(mut self, annotations: T) -> Self
+    where
+        T: IntoIterator<Item = (f64, f64, S)>,
+        S: Into<Span<'a>>,
+    {
+        self.annotations = annotations
+            .into_iter()
+            .map(|(x, y, label)| (x, y, label.into()))
+            .collect();
+        self
+    }
+
     /// Sets the kind of character to use to display this dataset
     ///
     /// You can use dots (`•`), blocks (`█`), bars (`▄`), braille (`⠓`, `⣇`, `⣿`) or half-blocks
@@ -1055,6 +1088,10 @@ impl Widget for &Chart<'_> {
                         }
                         GraphType::Scatter => {}
                     }
+                    for (x, y, label) in &dataset.annotations {
+                        let label = Span::styled(label.content.to_string(), label.style);
+                        ctx.print(*x, *y, label);
+                    }
                 })
                 .render(graph_area, buf);
         }
@@ -1170,6 +1207,35 @@ mod tests {
         legend_area: Option<Rect>,
     }
 
+    #[test]
+    fn dataset_annotations() {
+        let dataset = Dataset::default().annotations([(1.0, 2.0, "a"), (3.0, 4.0, "b")]);
+        assert_eq!(
+            dataset.annotations,
+            [(1.0, 2.0, Span::raw("a")), (3.0, 4.0, Span::raw("b"))]
+        );
+    }
+
+    #[test]
+    fn render_annotations() {
+        let chart = Chart::new(vec![Dataset::default().annotations([
+            (0.0, 2.0, "A".red()),
+            (3.0, 0.0, "long".into()),
+            (5.0, 1.0, "X".into()),
+        ])])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        chart.render(buffer.area, &mut buffer);
+        // the annotation outside of the bounds is not drawn and the long one is clipped
+        let expected = Buffer::with_lines([
+            Line::from(vec!["A".red(), "    ".into()]),
+            Line::from("     "),
+            Line::from("   lo"),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn it_should_hide_the_legend() {
         let data = [(0.0, 5.0), (1.0, 6.0), (3.0, 7.0)];
@@ -577,6 +577,23 @@ mod tests {
         assert_eq!(gauge.unfilled_symbol, symbols::line::DOUBLE.horizontal);
     }
 
+    #[test]
+    fn line_gauge_render_half_filled_with_distinct_styles() {
+        let gauge = LineGauge::default()
+            .ratio(0.5)
+            .label("")
+            .filled_symbol(symbols::line::THICK_HORIZONTAL)
+            .unfilled_symbol(symbols::line::HORIZONTAL)
+            .filled_style(Color::Red)
+            .unfilled_style(Color::DarkGray);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        gauge.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([" ━━━━━─────"]);
+        expected.set_style(Rect::new(1, 0, 5, 1), Color::Red);
+        expected.set_style(Rect::new(6, 0, 5, 1), Color::DarkGray);
+        assert_eq!(buf, expected);
+    }
+
     #[test]
     fn line_gauge_default() {
         assert_eq!(
@@ -42,13 +42,16 @@
 //! - [`Gauge`]: displays progress percentage using block characters.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`VirtualList`]: displays a list of items that are rendered on demand.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PropertyList`]: displays a list of key/value pairs with aligned labels.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`Throbber`]: displays an animated activity indicator with an optional label.
 //!
 //! [`BarChart`]: crate::barchart::BarChart
 //! [`Block`]: crate::block::Block
@@ -59,13 +62,16 @@
 //! [`Gauge`]: crate::gauge::Gauge
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
+//! [`VirtualList`]: crate::list::VirtualList
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`RatatuiMascot`]: crate::mascot::RatatuiMascot
 //! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`PropertyList`]: crate::property_list::PropertyList
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
 //! [`Sparkline`]: crate::sparkline::Sparkline
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`Throbber`]: crate::throbber::Throbber
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
@@ -90,11 +96,14 @@ pub mod list;
 pub mod logo;
 pub mod mascot;
 pub mod paragraph;
+pub mod property_list;
 pub mod scrollbar;
 pub mod sparkline;
 pub mod table;
 pub mod tabs;
+pub mod throbber;
 
+mod nav;
 mod reflow;
 
 #[cfg(feature = "calendar")]
@@ -1,14 +1,22 @@
 //! The [`List`] widget is used to display a list of items and allows selecting one or multiple
 //! items.
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::{
+    layout::Constraint,
+    style::{Style, Styled},
+    text::Span,
+    widgets::SizeHint,
+};
 use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
 
-pub use self::{item::ListItem, state::ListState};
+pub use self::{item::ListItem, state::ListState, virtual_list::VirtualList};
+pub use crate::nav::NavPolicy;
 use crate::{block::Block, table::HighlightSpacing};
 
 mod item;
 mod rendering;
 mod state;
+mod virtual_list;
 
 /// A widget to display several items among which one can be selected (optional)
 ///
@@ -36,7 +44,9 @@ mod state;
 /// - [`List::highlight_symbol`] sets the symbol to be displayed in front of the selected item.
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 ///   multi-line items
+/// - [`List::highlight_symbol_position`] sets on which side of the items the symbol is displayed
 /// - [`List::direction`] sets the list direction
+/// - [`List::flow`] sets whether the items flow from left to right, wrapping to the next row
 ///
 /// # Examples
 ///
@@ -121,8 +131,14 @@ pub struct List<'a> {
     pub(crate) repeat_highlight_symbol: bool,
     /// Decides when to allocate spacing for the selection symbol
     pub(crate) highlight_spacing: HighlightSpacing,
+    /// On which side of the items the selection symbol is displayed
+    pub(crate) highlight_symbol_position: HighlightSymbolPosition,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// Whether the items flow from left to right, wrapping to the next row when a row is full
+    pub(crate) flow: bool,
+    /// Row drawn between consecutive items
+    pub(crate) item_separator: Option<Span<'a>>,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -139,6 +155,18 @@ pub enum ListDirection {
     BottomToTop,
 }
 
+/// Defines on which side of the items the highlight symbol of a [`List`] is displayed.
+///
+/// See [`List::highlight_symbol_position`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum HighlightSymbolPosition {
+    /// The symbol is displayed at the left edge of the items, shifting them to the right
+    #[default]
+    Left,
+    /// The symbol is displayed at the right edge of the items, which are shortened on the right
+    Right,
+}
+
 impl<'a> List<'a> {
     /// Creates a new list from [`ListItem`]s
     ///
@@ -376,6 +404,35 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set on which side of the items the highlight symbol is displayed
+    ///
+    /// With [`HighlightSymbolPosition::Left`] (the default), the symbol is displayed at the left
+    /// edge of the items and the content of the items is shifted to the right. With
+    /// [`HighlightSymbolPosition::Right`], the symbol is displayed at the right edge of the items
+    /// and the content keeps its position but is shortened by the width of the symbol. This is
+    /// useful for trailing indicators or right-to-left lists.
+    ///
+    /// The spacing allocated for the symbol (see [`List::highlight_spacing`]) is on the same side
+    /// as the symbol.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{HighlightSymbolPosition, List};
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .highlight_symbol(" <")
+    ///     .highlight_symbol_position(HighlightSymbolPosition::Right);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_symbol_position(mut self, position: HighlightSymbolPosition) -> Self {
+        self.highlight_symbol_position = position;
+        self
+    }
+
     /// Defines the list direction (up or down)
     ///
     /// Defines if the `List` is displayed *top to bottom* (default) or *bottom to top*.
@@ -419,6 +476,96 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets whether the items flow from left to right instead of being rendered one per row
+    ///
+    /// In flow mode, the items are laid out next to each other, separated by a single column, and
+    /// wrap to the next row when the row is full. This is useful for displaying a grid of short
+    /// items (e.g. an app launcher). Each row is as tall as its tallest item, and the selected
+    /// item is highlighted over its own area only.
+    ///
+    /// The [`ListState::offset`] is the index of the first item of the first visible row. The
+    /// [`List::scroll_padding`] is ignored in flow mode.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Files", "Music", "Photos", "Terminal", "Settings"];
+    /// let list = List::new(items).flow(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn flow(mut self, flow: bool) -> Self {
+        self.flow = flow;
+        self
+    }
+
+    /// Sets a separator to draw on a row between consecutive items
+    ///
+    /// The content of the [`Span`] is repeated to fill the width of the list (e.g. `"─"` draws a
+    /// horizontal line), and its style is applied on top of the [list style](List::style). Each
+    /// separator takes up a row, so fewer items fit in the list. No separator is drawn after the
+    /// last visible item. Pass `None` to remove the separator.
+    ///
+    /// The separator is ignored in [flow mode](List::flow).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Stylize, text::Span, widgets::List};
+    ///
+    /// let items = ["Item 1", "Item 2", "Item 3"];
+    /// let list = List::new(items).item_separator(Span::from("─").dark_gray());
+    /// // Renders
+    /// // Item 1
+    /// // ──────────
+    /// // Item 2
+    /// // ──────────
+    /// // Item 3
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn item_separator<T: Into<Option<Span<'a>>>>(mut self, separator: T) -> Self {
+        self.item_separator = separator.into();
+        self
+    }
+
+    /// Returns a mutable reference to the [`ListItem`]s of the list
+    ///
+    /// This allows a list that is kept between frames to be updated incrementally (e.g. to push,
+    /// remove or replace a single item) instead of being rebuilt from all its items. Such a list
+    /// can be rendered by reference, as `&List` implements [`StatefulWidget`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{List, ListItem, ListState, StatefulWidget},
+    /// };
+    ///
+    /// let mut list = List::new(["Item 1", "Item 2"]);
+    /// let mut state = ListState::default();
+    ///
+    /// list.items_mut().push(ListItem::new("Item 3"));
+    /// list.items_mut().remove(0);
+    /// if let Some(item) = list.items_mut().get_mut(0) {
+    ///     *item = ListItem::new("Item 2 (updated)");
+    /// }
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+    /// StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+    /// ```
+    ///
+    /// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+    pub fn items_mut(&mut self) -> &mut Vec<ListItem<'a>> {
+        &mut self.items
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
         self.items.len()
@@ -430,6 +577,57 @@ impl<'a> List<'a> {
     }
 }
 
+impl SizeHint for List<'_> {
+    /// Returns the width of the widest item and the total height of the items, including the
+    /// highlight symbol (unless the [`HighlightSpacing`] is [`Never`](HighlightSpacing::Never)),
+    /// the [item separators](List::item_separator) and the [`Block`] if one is set. Items are
+    /// assumed to be laid out one below the other, as when [`List::flow`] is not set.
+    fn size_hint(&self) -> (Constraint, Constraint) {
+        let (left, right) = self
+            .block
+            .as_ref()
+            .map(Block::horizontal_space)
+            .unwrap_or_default();
+        let (top, bottom) = self
+            .block
+            .as_ref()
+            .map(Block::vertical_space)
+            .unwrap_or_default();
+        let highlight_width = if self.highlight_spacing == HighlightSpacing::Never {
+            0
+        } else {
+            self.highlight_symbol.map_or(0, UnicodeWidthStr::width)
+        };
+        let width = self
+            .items
+            .iter()
+            .map(ListItem::width)
+            .max()
+            .unwrap_or_default()
+            .saturating_add(highlight_width);
+        let separators_height = if self.item_separator.is_some() {
+            self.items.len().saturating_sub(1)
+        } else {
+            0
+        };
+        let height = self.items.iter().map(ListItem::height).sum::<usize>() + separators_height;
+        (
+            Constraint::Length(
+                u16::try_from(width)
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(left)
+                    .saturating_add(right),
+            ),
+            Constraint::Length(
+                u16::try_from(height)
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(top)
+                    .saturating_add(bottom),
+            ),
+        )
+    }
+}
+
 impl Styled for List<'_> {
     type Item = Self;
 
@@ -477,6 +675,41 @@ mod tests {
         assert_eq!(collected, expected);
     }
 
+    #[test]
+    fn size_hint() {
+        let list = List::new(["Item 1", "Item 10", "Multi\nline"]);
+        assert_eq!(
+            list.size_hint(),
+            (Constraint::Length(7), Constraint::Length(4))
+        );
+
+        let list = list.highlight_symbol(">> ").block(Block::bordered());
+        assert_eq!(
+            list.size_hint(),
+            (Constraint::Length(12), Constraint::Length(6))
+        );
+
+        let list = list.highlight_spacing(HighlightSpacing::Never);
+        assert_eq!(
+            list.size_hint(),
+            (Constraint::Length(9), Constraint::Length(6))
+        );
+
+        assert_eq!(
+            List::default().size_hint(),
+            (Constraint::Length(0), Constraint::Length(0))
+        );
+    }
+
+    #[test]
+    fn items_mut() {
+        let mut list = List::new(["Item 1", "Item 2"]);
+        list.items_mut().push(ListItem::new("Item 3"));
+        list.items_mut().remove(0);
+        list.items_mut()[0] = ListItem::new("Item 2 (updated)");
+        assert_eq!(list, List::new(["Item 2 (updated)", "Item 3"]));
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -1,15 +1,21 @@
+use std::ops::Range;
+
 use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
+    text::Span,
     widgets::{StatefulWidget, Widget},
 };
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
     block::BlockExt,
-    list::{List, ListDirection, ListState},
+    list::{HighlightSymbolPosition, List, ListDirection, ListItem, ListState},
 };
 
+/// The number of columns between two items of the same row in flow mode
+const FLOW_SPACING: usize = 1;
+
 impl Widget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -48,9 +54,12 @@ impl StatefulWidget for &List<'_> {
             return;
         }
 
-        // If the selected index is out of bounds, set it to the last item
-        if state.selected.is_some_and(|s| s >= self.items.len()) {
-            state.select(Some(self.items.len().saturating_sub(1)));
+        // Apply any wrapping move and set an out of bounds selection to the last item
+        state.clamp_selection(self.items.len());
+
+        if self.flow {
+            self.render_flow(list_area, buf, state);
+            return;
         }
 
         let list_height = list_area.height as usize;
@@ -67,6 +76,11 @@ impl StatefulWidget for &List<'_> {
 
         let mut current_height = 0;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
+        let symbol_width = if selection_spacing {
+            highlight_symbol.width() as u16
+        } else {
+            0
+        };
         for (i, item) in self
             .items
             .iter()
@@ -95,16 +109,7 @@ impl StatefulWidget for &List<'_> {
 
             let is_selected = state.selected == Some(i);
 
-            let item_area = if selection_spacing {
-                let highlight_symbol_width = self.highlight_symbol.unwrap_or("").width() as u16;
-                Rect {
-                    x: row_area.x + highlight_symbol_width,
-                    width: row_area.width.saturating_sub(highlight_symbol_width),
-                    ..row_area
-                }
-            } else {
-                row_area
-            };
+            let (item_area, symbol_x) = self.split_highlight_symbol(row_area, symbol_width);
             Widget::render(&item.content, item_area, buf);
 
             if selection_spacing {
@@ -118,10 +123,10 @@ impl StatefulWidget for &List<'_> {
                         &blank_symbol
                     };
                     buf.set_stringn(
-                        x,
+                        symbol_x,
                         y + j as u16,
                         symbol,
-                        list_area.width as usize,
+                        usize::from(row_area.right() - symbol_x),
                         item_style,
                     );
                 }
@@ -130,11 +135,197 @@ impl StatefulWidget for &List<'_> {
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
             }
+
+            // Separator between this item and the next one, if the next one is visible
+            if let Some(separator) = &self.item_separator {
+                if i + 1 < last_visible_index {
+                    let y = if self.direction == ListDirection::BottomToTop {
+                        current_height += 1;
+                        list_area.bottom() - current_height
+                    } else {
+                        let y = list_area.top() + current_height;
+                        current_height += 1;
+                        y
+                    };
+                    let separator_area = Rect {
+                        y,
+                        height: 1,
+                        ..list_area
+                    };
+                    self.render_separator(separator, separator_area, buf);
+                }
+            }
         }
     }
 }
 
 impl List<'_> {
+    /// Splits the area of an item into the area of its content and the x position of the
+    /// highlight symbol, depending on the position of the symbol
+    fn split_highlight_symbol(&self, area: Rect, symbol_width: u16) -> (Rect, u16) {
+        let symbol_width = symbol_width.min(area.width);
+        let content_width = area.width - symbol_width;
+        match self.highlight_symbol_position {
+            HighlightSymbolPosition::Left => {
+                let content_area = Rect {
+                    x: area.x + symbol_width,
+                    width: content_width,
+                    ..area
+                };
+                (content_area, area.x)
+            }
+            HighlightSymbolPosition::Right => {
+                let content_area = Rect {
+                    width: content_width,
+                    ..area
+                };
+                (content_area, area.x + content_width)
+            }
+        }
+    }
+
+    /// Renders the separator by repeating its content to fill the area
+    fn render_separator(&self, separator: &Span<'_>, area: Rect, buf: &mut Buffer) {
+        let style = self.style.patch(separator.style);
+        buf.set_style(area, style);
+        let width = separator.width();
+        if width == 0 {
+            return;
+        }
+        let repeat = usize::from(area.width) / width + 1;
+        buf.set_stringn(
+            area.x,
+            area.y,
+            separator.content.repeat(repeat),
+            usize::from(area.width),
+            style,
+        );
+    }
+
+    /// Renders the items from left to right, wrapping to the next row when a row is full
+    fn render_flow(&self, area: Rect, buf: &mut Buffer, state: &mut ListState) {
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let blank_symbol = " ".repeat(highlight_symbol.width());
+        let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
+        let symbol_width = if selection_spacing {
+            highlight_symbol.width()
+        } else {
+            0
+        };
+
+        let rows = self.flow_rows(area.width as usize, symbol_width);
+        let row_of = |index: usize| {
+            rows.iter()
+                .position(|row| row.contains(&index))
+                .unwrap_or_default()
+        };
+
+        // Keep the row of the offset visible, unless the selected item is in another row that
+        // would not be visible from there
+        let mut first_row = row_of(state.offset.min(self.items.len() - 1));
+        if let Some(selected) = state.selected {
+            let selected_row = row_of(selected);
+            first_row = first_row.min(selected_row);
+            while first_row < selected_row
+                && rows[first_row..=selected_row]
+                    .iter()
+                    .map(|row| self.flow_row_height(row))
+                    .sum::<usize>()
+                    > area.height as usize
+            {
+                first_row += 1;
+            }
+        }
+
+        // Important: this changes the state's offset to be the first item of the first visible row
+        state.offset = rows[first_row].start;
+
+        let mut current_height = 0;
+        for row in &rows[first_row..] {
+            let row_height = self.flow_row_height(row);
+            if current_height + row_height > area.height as usize {
+                break;
+            }
+            let y = if self.direction == ListDirection::BottomToTop {
+                area.bottom() - (current_height + row_height) as u16
+            } else {
+                area.top() + current_height as u16
+            };
+            current_height += row_height;
+
+            let mut x = area.left();
+            for index in row.clone() {
+                let item = &self.items[index];
+                let width = (symbol_width + item.width()).min(usize::from(area.right() - x));
+                let item_area = Rect::new(x, y, width as u16, item.height() as u16);
+                x = x
+                    .saturating_add((width + FLOW_SPACING) as u16)
+                    .min(area.right());
+
+                let item_style = self.style.patch(item.style);
+                buf.set_style(item_area, item_style);
+
+                let (content_area, symbol_x) =
+                    self.split_highlight_symbol(item_area, symbol_width as u16);
+                Widget::render(&item.content, content_area, buf);
+
+                let is_selected = state.selected == Some(index);
+                if selection_spacing {
+                    for j in 0..item.content.height() {
+                        let symbol = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
+                            highlight_symbol
+                        } else {
+                            &blank_symbol
+                        };
+                        buf.set_stringn(
+                            symbol_x,
+                            item_area.y + j as u16,
+                            symbol,
+                            usize::from(item_area.right() - symbol_x),
+                            item_style,
+                        );
+                    }
+                }
+
+                if is_selected {
+                    buf.set_style(item_area, self.highlight_style);
+                }
+            }
+        }
+    }
+
+    /// Splits the items into rows of items that fit in the given width in flow mode
+    ///
+    /// An item that is wider than the width is placed alone on its row.
+    fn flow_rows(&self, max_width: usize, symbol_width: usize) -> Vec<Range<usize>> {
+        let mut rows = vec![];
+        let mut start = 0;
+        let mut row_width = 0;
+        for (index, item) in self.items.iter().enumerate() {
+            let width = symbol_width + item.width();
+            if index == start {
+                row_width = width;
+            } else if row_width + FLOW_SPACING + width <= max_width {
+                row_width += FLOW_SPACING + width;
+            } else {
+                rows.push(start..index);
+                start = index;
+                row_width = width;
+            }
+        }
+        rows.push(start..self.items.len());
+        rows
+    }
+
+    /// The height of a row in flow mode, which is the height of its tallest item
+    fn flow_row_height(&self, row: &Range<usize>) -> usize {
+        self.items[row.clone()]
+            .iter()
+            .map(ListItem::height)
+            .max()
+            .unwrap_or_default()
+    }
+
     /// Given an offset, calculate which items can fit in a given area
     fn get_items_bounds(
         &self,
@@ -142,125 +333,152 @@ impl List<'_> {
         offset: usize,
         max_height: usize,
     ) -> (usize, usize) {
-        let offset = offset.min(self.items.len().saturating_sub(1));
+        // Each separator is counted as part of the item above it. As there is no separator after
+        // the last visible item, the area is one row taller to make room for it.
+        let separator_height = usize::from(self.item_separator.is_some());
+        items_bounds(
+            self.items.len(),
+            |index| self.items[index].height() + separator_height,
+            selected,
+            offset,
+            max_height + separator_height,
+            self.scroll_padding,
+        )
+    }
+}
 
-        // Note: visible here implies visible in the given area
-        let mut first_visible_index = offset;
-        let mut last_visible_index = offset;
+/// Given an offset, calculate which items can fit in a given area
+///
+/// This is shared between the rendering of the [`List`] and [`ListState::visible_range`] so that
+/// they agree on which items are visible. `item_height` returns the height of the item at the
+/// given index, which must be less than `item_count`, and `item_count` must not be zero.
+pub(super) fn items_bounds(
+    item_count: usize,
+    item_height: impl Fn(usize) -> usize,
+    selected: Option<usize>,
+    offset: usize,
+    max_height: usize,
+    scroll_padding: usize,
+) -> (usize, usize) {
+    let offset = offset.min(item_count.saturating_sub(1));
+
+    // Note: visible here implies visible in the given area
+    let mut first_visible_index = offset;
+    let mut last_visible_index = offset;
+
+    // Current height of all items in the list to render, beginning at the offset
+    let mut height_from_offset = 0;
+
+    // Calculate the last visible index and total height of the items
+    // that will fit in the available space
+    for index in offset..item_count {
+        if height_from_offset + item_height(index) > max_height {
+            break;
+        }
 
-        // Current height of all items in the list to render, beginning at the offset
-        let mut height_from_offset = 0;
+        height_from_offset += item_height(index);
 
-        // Calculate the last visible index and total height of the items
-        // that will fit in the available space
-        for item in self.items.iter().skip(offset) {
-            if height_from_offset + item.height() > max_height {
-                break;
-            }
+        last_visible_index += 1;
+    }
 
-            height_from_offset += item.height();
+    // Get the selected index and apply scroll_padding to it, but still honor the offset if
+    // nothing is selected. This allows for the list to stay at a position after select()ing
+    // None.
+    let index_to_display = apply_scroll_padding_to_selected_index(
+        item_count,
+        &item_height,
+        selected,
+        max_height,
+        first_visible_index,
+        last_visible_index,
+        scroll_padding,
+    )
+    .unwrap_or(offset);
+
+    // Recall that last_visible_index is the index of what we
+    // can render up to in the given space after the offset
+    // If we have an item selected that is out of the viewable area (or
+    // the offset is still set), we still need to show this item
+    while index_to_display >= last_visible_index {
+        height_from_offset = height_from_offset.saturating_add(item_height(last_visible_index));
+
+        last_visible_index += 1;
+
+        // Now we need to hide previous items since we didn't have space
+        // for the selected/offset item
+        while height_from_offset > max_height {
+            height_from_offset =
+                height_from_offset.saturating_sub(item_height(first_visible_index));
 
-            last_visible_index += 1;
+            // Remove this item to view by starting at the next item index
+            first_visible_index += 1;
         }
+    }
 
-        // Get the selected index and apply scroll_padding to it, but still honor the offset if
-        // nothing is selected. This allows for the list to stay at a position after select()ing
-        // None.
-        let index_to_display = self
-            .apply_scroll_padding_to_selected_index(
-                selected,
-                max_height,
-                first_visible_index,
-                last_visible_index,
-            )
-            .unwrap_or(offset);
-
-        // Recall that last_visible_index is the index of what we
-        // can render up to in the given space after the offset
-        // If we have an item selected that is out of the viewable area (or
-        // the offset is still set), we still need to show this item
-        while index_to_display >= last_visible_index {
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[last_visible_index].height());
+    // Here we're doing something similar to what we just did above
+    // If the selected item index is not in the viewable area, let's try to show the item
+    while index_to_display < first_visible_index {
+        first_visible_index -= 1;
 
-            last_visible_index += 1;
+        height_from_offset = height_from_offset.saturating_add(item_height(first_visible_index));
 
-            // Now we need to hide previous items since we didn't have space
-            // for the selected/offset item
-            while height_from_offset > max_height {
-                height_from_offset =
-                    height_from_offset.saturating_sub(self.items[first_visible_index].height());
+        // Don't show an item if it is beyond our viewable height
+        while height_from_offset > max_height {
+            last_visible_index -= 1;
 
-                // Remove this item to view by starting at the next item index
-                first_visible_index += 1;
-            }
+            height_from_offset = height_from_offset.saturating_sub(item_height(last_visible_index));
         }
+    }
 
-        // Here we're doing something similar to what we just did above
-        // If the selected item index is not in the viewable area, let's try to show the item
-        while index_to_display < first_visible_index {
-            first_visible_index -= 1;
-
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[first_visible_index].height());
-
-            // Don't show an item if it is beyond our viewable height
-            while height_from_offset > max_height {
-                last_visible_index -= 1;
+    (first_visible_index, last_visible_index)
+}
 
-                height_from_offset =
-                    height_from_offset.saturating_sub(self.items[last_visible_index].height());
-            }
+/// Applies scroll padding to the selected index, reducing the padding value to keep the
+/// selected item on screen even with items of inconsistent sizes
+///
+/// This function is sensitive to how the bounds checking function handles item height
+fn apply_scroll_padding_to_selected_index(
+    item_count: usize,
+    item_height: impl Fn(usize) -> usize,
+    selected: Option<usize>,
+    max_height: usize,
+    first_visible_index: usize,
+    last_visible_index: usize,
+    scroll_padding: usize,
+) -> Option<usize> {
+    let last_valid_index = item_count.saturating_sub(1);
+    let selected = selected?.min(last_valid_index);
+
+    // The bellow loop handles situations where the list item sizes may not be consistent,
+    // where the offset would have excluded some items that we want to include, or could
+    // cause the offset value to be set to an inconsistent value each time we render.
+    // The padding value will be reduced in case any of these issues would occur
+    let mut scroll_padding = scroll_padding;
+    while scroll_padding > 0 {
+        let mut height_around_selected = 0;
+        for index in selected.saturating_sub(scroll_padding)
+            ..=selected
+                .saturating_add(scroll_padding)
+                .min(last_valid_index)
+        {
+            height_around_selected += item_height(index);
         }
-
-        (first_visible_index, last_visible_index)
+        if height_around_selected <= max_height {
+            break;
+        }
+        scroll_padding -= 1;
     }
 
-    /// Applies scroll padding to the selected index, reducing the padding value to keep the
-    /// selected item on screen even with items of inconsistent sizes
-    ///
-    /// This function is sensitive to how the bounds checking function handles item height
-    fn apply_scroll_padding_to_selected_index(
-        &self,
-        selected: Option<usize>,
-        max_height: usize,
-        first_visible_index: usize,
-        last_visible_index: usize,
-    ) -> Option<usize> {
-        let last_valid_index = self.items.len().saturating_sub(1);
-        let selected = selected?.min(last_valid_index);
-
-        // The bellow loop handles situations where the list item sizes may not be consistent,
-        // where the offset would have excluded some items that we want to include, or could
-        // cause the offset value to be set to an inconsistent value each time we render.
-        // The padding value will be reduced in case any of these issues would occur
-        let mut scroll_padding = self.scroll_padding;
-        while scroll_padding > 0 {
-            let mut height_around_selected = 0;
-            for index in selected.saturating_sub(scroll_padding)
-                ..=selected
-                    .saturating_add(scroll_padding)
-                    .min(last_valid_index)
-            {
-                height_around_selected += self.items[index].height();
-            }
-            if height_around_selected <= max_height {
-                break;
-            }
-            scroll_padding -= 1;
+    Some(
+        if (selected + scroll_padding).min(last_valid_index) >= last_visible_index {
+            selected + scroll_padding
+        } else if selected.saturating_sub(scroll_padding) < first_visible_index {
+            selected.saturating_sub(scroll_padding)
+        } else {
+            selected
         }
-
-        Some(
-            if (selected + scroll_padding).min(last_valid_index) >= last_visible_index {
-                selected + scroll_padding
-            } else if selected.saturating_sub(scroll_padding) < first_visible_index {
-                selected.saturating_sub(scroll_padding)
-            } else {
-                selected
-            }
-            .min(last_valid_index),
-        )
-    }
+        .min(last_valid_index),
+    )
 }
 
 #[cfg(test)]
@@ -275,7 +493,11 @@ mod tests {
     use rstest::{fixture, rstest};
 
     use super::*;
-    use crate::{block::Block, list::ListItem, table::HighlightSpacing};
+    use crate::{
+        block::Block,
+        list::{ListItem, NavPolicy},
+        table::HighlightSpacing,
+    };
 
     #[fixture]
     fn single_line_buf() -> Buffer {
@@ -804,6 +1026,51 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn highlight_symbol_position_right() {
+        let list = List::new(["Item 0\nLine 2", "Item 1", "Item 2"])
+            .highlight_symbol(" <")
+            .highlight_style(Style::default().fg(Color::Yellow))
+            .highlight_symbol_position(HighlightSymbolPosition::Right);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let buffer = stateful_widget(list, &mut state, 10, 5);
+        let expected = Buffer::with_lines([
+            "Item 0   <".yellow(),
+            "Line 2    ".yellow(),
+            "Item 1    ".into(),
+            "Item 2    ".into(),
+            "          ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_symbol_position_right_reserves_spacing_on_the_right() {
+        let list = List::new(["Item 0 long", "Item 1"])
+            .highlight_symbol(" <")
+            .highlight_spacing(HighlightSpacing::Always)
+            .highlight_symbol_position(HighlightSymbolPosition::Right);
+        let mut state = ListState::default();
+        let buffer = stateful_widget(list.clone(), &mut state, 10, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Item 0 l  ", "Item 1    "]));
+
+        state.select(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Item 0 l  ", "Item 1   <"]));
+    }
+
+    #[test]
+    fn highlight_symbol_position_right_in_flow() {
+        let list = List::new(["a", "b", "c"])
+            .flow(true)
+            .highlight_symbol("<")
+            .highlight_symbol_position(HighlightSymbolPosition::Right);
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 1);
+        assert_eq!(buffer, Buffer::with_lines(["a  b< c   "]));
+    }
+
     #[rstest]
     #[case::top_to_bottom(ListDirection::TopToBottom, [
         "Item 0    ",
@@ -929,6 +1196,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrap_navigation_updates_selection_and_offset() {
+        let items = ["Item 0", "Item 1", "Item 2", "Item 3", "Item 4"];
+        let list = List::new(items).highlight_symbol(">>");
+        let mut state = ListState::default()
+            .with_navigation(NavPolicy::Wrap)
+            .with_selected(Some(4));
+        let buffer = stateful_widget(list.clone(), &mut state, 10, 3);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["  Item 2  ", "  Item 3  ", ">>Item 4  "])
+        );
+        assert_eq!(state.offset, 2);
+
+        // moving past the last item wraps to the first one and scrolls to the top
+        state.select_next();
+        let buffer = stateful_widget(list.clone(), &mut state, 10, 3);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([">>Item 0  ", "  Item 1  ", "  Item 2  "])
+        );
+        assert_eq!(state.selected, Some(0));
+        assert_eq!(state.offset, 0);
+
+        // moving before the first item wraps to the last one and scrolls to the bottom
+        state.select_previous();
+        let buffer = stateful_widget(list.clone(), &mut state, 10, 3);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["  Item 2  ", "  Item 3  ", ">>Item 4  "])
+        );
+        assert_eq!(state.selected, Some(4));
+        assert_eq!(state.offset, 2);
+
+        // the default policy keeps the selection on the last item
+        state.set_navigation(NavPolicy::Clamp);
+        state.select_next();
+        stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(state.selected, Some(4));
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -1235,6 +1543,134 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_without_bg_keeps_existing_bg() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_style(buffer.area, Style::new().on_blue());
+        let mut state = ListState::default().with_selected(Some(0));
+        let list = List::new(["Item 0", "Item 1"]).highlight_style(Style::new().bold());
+        StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+
+        let mut expected = Buffer::with_lines(["Item 0", "Item 1"]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn flow() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        let list = List::new(["a", "bb", "ccc", "d"]).flow(true);
+        Widget::render(list, buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a bb   ",
+            "ccc d  ",
+            "       ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn flow_bottom_to_top() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        let list = List::new(["a", "bb", "ccc", "d"])
+            .flow(true)
+            .direction(ListDirection::BottomToTop);
+        Widget::render(list, buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "       ",
+            "ccc d  ",
+            "a bb   ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn flow_scrolls_to_selected_row() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        let mut state = ListState::default().with_selected(Some(2));
+        let list = List::new(["a", "bb", "ccc", "d"])
+            .flow(true)
+            .highlight_symbol(">")
+            .highlight_style(Modifier::REVERSED);
+        StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines([">ccc  d"]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Modifier::REVERSED);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset, 2);
+    }
+
+    #[test]
+    fn flow_wide_item() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let list = List::new(["a", "abcdefg", "b"]).flow(true);
+        Widget::render(list, buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a    ",
+            "abcde",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn item_separator() {
+        let list = List::new(["a", "b", "c"]).item_separator(Span::from("─"));
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a    ",
+            "─────",
+            "b    ",
+            "─────",
+            "c    ",
+            "     ",
+        ]);
+        assert_eq!(widget(list.clone(), 5, 6), expected);
+
+        // no separator after the last visible item
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a    ",
+            "─────",
+            "b    ",
+            "     ",
+        ]);
+        assert_eq!(widget(list.clone(), 5, 4), expected);
+
+        // removing the separator
+        let list = list.item_separator(None);
+        assert_eq!(
+            widget(list, 5, 3),
+            Buffer::with_lines(["a    ", "b    ", "c    "])
+        );
+    }
+
+    #[test]
+    fn item_separator_scrolls_to_selected() {
+        let list = List::new(["a", "b", "c", "d"])
+            .item_separator(Span::from("-="))
+            .highlight_symbol(">")
+            .highlight_style(Modifier::REVERSED);
+        let mut state = ListState::default().with_selected(Some(3));
+        let buffer = stateful_widget(list, &mut state, 5, 3);
+        let mut expected = Buffer::with_lines([" c   ", "-=-=-", ">d   "]);
+        expected.set_style(Rect::new(0, 2, 5, 1), Modifier::REVERSED);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset, 2);
+    }
+
+    #[test]
+    fn item_separator_bottom_to_top() {
+        let list = List::new(["a", "b"])
+            .direction(ListDirection::BottomToTop)
+            .item_separator(Span::styled("~", Color::Red));
+        let mut expected = Buffer::with_lines(["   ", "b  ", "~~~", "a  "]);
+        expected.set_style(Rect::new(0, 2, 3, 1), Color::Red);
+        assert_eq!(widget(list, 3, 4), expected);
+    }
+
     /// Regression test for a bug where highlight symbol being greater than width caused a panic due
     /// to subtraction with underflow.
     ///
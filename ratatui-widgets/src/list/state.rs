@@ -1,3 +1,11 @@
+use std::{
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+
+use super::rendering::items_bounds;
+use crate::nav::{NavPolicy, PendingMove};
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -42,11 +50,32 @@
 /// ```
 ///
 /// [`List`]: super::List
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) navigation: NavPolicy,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) pending_move: Option<PendingMove>,
+}
+
+impl PartialEq for ListState {
+    /// A move which is not yet resolved is internal bookkeeping, so it is not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.selected == other.selected
+            && self.navigation == other.navigation
+    }
+}
+
+impl Hash for ListState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.selected.hash(state);
+        self.navigation.hash(state);
+    }
 }
 
 impl ListState {
@@ -81,9 +110,57 @@ impl ListState {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
         self.selected = selected;
+        self.pending_move = None;
         self
     }
 
+    /// Sets the policy used when moving the selection past the first or last item
+    ///
+    /// See [`NavPolicy`] for more information.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListState, NavPolicy};
+    ///
+    /// let state = ListState::default().with_navigation(NavPolicy::Wrap);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_navigation(mut self, navigation: NavPolicy) -> Self {
+        self.navigation = navigation;
+        self
+    }
+
+    /// Policy used when moving the selection past the first or last item
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListState, NavPolicy};
+    ///
+    /// let state = ListState::default();
+    /// assert_eq!(state.navigation(), NavPolicy::Clamp);
+    /// ```
+    pub const fn navigation(&self) -> NavPolicy {
+        self.navigation
+    }
+
+    /// Sets the policy used when moving the selection past the first or last item
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListState, NavPolicy};
+    ///
+    /// let mut state = ListState::default();
+    /// state.set_navigation(NavPolicy::Wrap);
+    /// ```
+    pub fn set_navigation(&mut self, navigation: NavPolicy) {
+        self.navigation = navigation;
+    }
+
     /// Index of the first item to be displayed
     ///
     /// # Examples
@@ -141,6 +218,7 @@ impl ListState {
     /// *state.selected_mut() = Some(1);
     /// ```
     pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        self.pending_move = None;
         &mut self.selected
     }
 
@@ -158,6 +236,7 @@ impl ListState {
     /// ```
     pub fn select(&mut self, index: Option<usize>) {
         self.selected = index;
+        self.pending_move = None;
         if index.is_none() {
             self.offset = 0;
         }
@@ -166,7 +245,9 @@ impl ListState {
     /// Selects the next item or the first one if no item is selected
     ///
     /// Note: until the list is rendered, the number of items is not known, so the index is set to
-    /// `0` and will be corrected when the list is rendered
+    /// `0` and will be corrected when the list is rendered. With [`NavPolicy::Wrap`], the move is
+    /// also applied when the list is rendered, so that moving past the last item selects the
+    /// first one.
     ///
     /// # Examples
     ///
@@ -178,13 +259,19 @@ impl ListState {
     /// ```
     pub fn select_next(&mut self) {
         let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        let pending_move = self
+            .navigation
+            .record_move(self.pending_move, self.selected, 1);
         self.select(Some(next));
+        self.pending_move = pending_move;
     }
 
     /// Selects the previous item or the last one if no item is selected
     ///
     /// Note: until the list is rendered, the number of items is not known, so the index is set to
-    /// `usize::MAX` and will be corrected when the list is rendered
+    /// `usize::MAX` and will be corrected when the list is rendered. With [`NavPolicy::Wrap`], the
+    /// move is also applied when the list is rendered, so that moving before the first item
+    /// selects the last one.
     ///
     /// # Examples
     ///
@@ -196,7 +283,11 @@ impl ListState {
     /// ```
     pub fn select_previous(&mut self) {
         let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        let pending_move = self
+            .navigation
+            .record_move(self.pending_move, self.selected, -1);
         self.select(Some(previous));
+        self.pending_move = pending_move;
     }
 
     /// Selects the first item
@@ -270,13 +361,141 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Moves the selection by `delta` items in a list of `count` items
+    ///
+    /// A positive `delta` moves the selection down and a negative one moves it up. Moving past
+    /// either end follows the [`NavPolicy`] of the state: with [`NavPolicy::Wrap`] the selection
+    /// continues from the other end, otherwise it stops at the first or last item. If no item is
+    /// selected, a positive `delta` counts from before the first item and a negative `delta`
+    /// counts from after the last one, so `1` selects the first item and `-1` selects the last. If
+    /// `count` is `0`, the selection is cleared.
+    ///
+    /// Unlike the other selection methods, the number of items is passed in, so the resulting index
+    /// is always valid. The offset is moved up if needed so that the selection is not above the
+    /// visible items; the rest is corrected when the list is rendered.
+    ///
+    /// This can be used to implement next / previous and page up / page down navigation with the
+    /// same code path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListState, NavPolicy};
+    ///
+    /// let mut state = ListState::default().with_navigation(NavPolicy::Wrap);
+    /// state.select_relative(1, 5);
+    /// assert_eq!(state.selected(), Some(0));
+    /// state.select_relative(-1, 5);
+    /// assert_eq!(state.selected(), Some(4));
+    ///
+    /// state.set_navigation(NavPolicy::Clamp);
+    /// state.select_relative(10, 5);
+    /// assert_eq!(state.selected(), Some(4));
+    /// ```
+    pub fn select_relative(&mut self, delta: isize, count: usize) {
+        self.clamp_selection(count);
+        let index = self.navigation.step(self.selected, delta, count);
+        if let Some(index) = index {
+            self.offset = self.offset.min(index);
+        }
+        self.select(index);
+    }
+
+    /// Clamps the selection to a list of `count` items
+    ///
+    /// Any move made by `select_next` or `select_previous` with [`NavPolicy::Wrap`] is applied
+    /// first, and an out of bounds selection is then set to the last item.
+    pub(crate) fn clamp_selection(&mut self, count: usize) {
+        if let Some(PendingMove { from, delta }) = self.pending_move.take() {
+            if let Some(index) = self.navigation.step(Some(from), delta, count) {
+                self.selected = Some(index);
+            }
+        }
+        if self.selected.is_some_and(|s| s >= count) {
+            self.select(Some(count.saturating_sub(1)));
+        }
+    }
+
+    /// Returns the range of the indices of the items that are visible in a list of `item_count`
+    /// items rendered in an area of `area_height` rows.
+    ///
+    /// This uses the same logic as the rendering of the [`List`] to clamp the offset and keep the
+    /// selected item visible, assuming that every item is a single line high and that the list has
+    /// no [scroll padding](super::List::scroll_padding) and no
+    /// [item separator](super::List::item_separator). This is useful for syncing an external
+    /// scrollbar or for lazily loading items as they scroll into view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_offset(2);
+    /// assert_eq!(state.visible_range(10, 5), 2..7);
+    ///
+    /// let state = ListState::default().with_offset(2).with_selected(Some(9));
+    /// assert_eq!(state.visible_range(10, 5), 5..10);
+    /// ```
+    ///
+    /// [`List`]: super::List
+    pub fn visible_range(&self, item_count: usize, area_height: u16) -> Range<usize> {
+        if item_count == 0 {
+            return 0..0;
+        }
+        let (first, last) = items_bounds(
+            item_count,
+            |_| 1,
+            self.selected.map(|selected| selected.min(item_count - 1)),
+            self.offset,
+            area_height as usize,
+            0,
+        );
+        first..last
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use ratatui_core::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
 
-    use crate::list::ListState;
+    use crate::list::{List, ListState, NavPolicy};
+
+    #[test]
+    fn visible_range() {
+        let state = ListState::default();
+        assert_eq!(state.visible_range(0, 5), 0..0);
+        assert_eq!(state.visible_range(3, 5), 0..3);
+        assert_eq!(state.visible_range(10, 5), 0..5);
+        assert_eq!(state.visible_range(10, 0), 0..0);
+
+        // the offset is clamped to the last item
+        let state = ListState::default().with_offset(20);
+        assert_eq!(state.visible_range(10, 5), 9..10);
+
+        // the selected item is kept visible
+        let state = ListState::default().with_offset(6).with_selected(Some(1));
+        assert_eq!(state.visible_range(10, 5), 1..6);
+        let state = ListState::default().with_selected(Some(7));
+        assert_eq!(state.visible_range(10, 5), 3..8);
+    }
+
+    #[test]
+    fn visible_range_matches_render() {
+        let items = (0..10).map(|i| format!("Item {i}")).collect::<List>();
+        for offset in 0..12 {
+            for selected in [None, Some(0), Some(4), Some(9), Some(15)] {
+                let mut state = ListState::default()
+                    .with_offset(offset)
+                    .with_selected(selected);
+                let range = state.visible_range(10, 4);
+                let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+                StatefulWidget::render(&items, buffer.area, &mut buffer, &mut state);
+                assert_eq!(range.start, state.offset, "{offset} {selected:?}");
+            }
+        }
+    }
 
     #[test]
     fn selected() {
@@ -356,4 +575,109 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[test]
+    fn state_wrap_navigation() {
+        let mut state = ListState::default().with_navigation(NavPolicy::Wrap);
+        assert_eq!(state.navigation(), NavPolicy::Wrap);
+
+        // moving before the first item is applied when the number of items is known
+        state.select_first();
+        state.select_previous();
+        state.select_previous();
+        assert_eq!(state.selected, Some(0));
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(3));
+
+        state.select(Some(4));
+        state.select_next();
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(0));
+
+        // selecting an index directly discards the pending move
+        state.select_previous();
+        state.select(Some(2));
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(2));
+
+        // an out of bounds selection is clamped rather than wrapped
+        state.select(Some(5));
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(4));
+
+        state.set_navigation(NavPolicy::Clamp);
+        state.select_first();
+        state.select_previous();
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn pending_move_is_not_compared() {
+        let state = ListState::default()
+            .with_navigation(NavPolicy::Wrap)
+            .with_selected(Some(0));
+        let mut moved = state.clone();
+        moved.select_previous();
+        assert_eq!(moved.selected, Some(0));
+        assert!(moved.pending_move.is_some());
+        assert_eq!(moved, state);
+    }
+
+    #[test]
+    fn select_relative() {
+        let mut state = ListState::default();
+        state.select_relative(1, 5);
+        assert_eq!(state.selected, Some(0));
+
+        let mut state = ListState::default();
+        state.select_relative(-1, 5);
+        assert_eq!(state.selected, Some(4));
+
+        state.select_relative(-2, 5);
+        assert_eq!(state.selected, Some(2));
+
+        state.select_relative(-10, 5);
+        assert_eq!(state.selected, Some(0));
+
+        state.select_relative(10, 5);
+        assert_eq!(state.selected, Some(4));
+
+        // an out of range selection is clamped before moving
+        state.select(Some(usize::MAX));
+        state.select_relative(-1, 5);
+        assert_eq!(state.selected, Some(3));
+
+        state.select_relative(0, 0);
+        assert_eq!(state.selected, None);
+    }
+
+    #[test]
+    fn select_relative_wrap() {
+        let mut state = ListState::default()
+            .with_navigation(NavPolicy::Wrap)
+            .with_selected(Some(4));
+        state.select_relative(1, 5);
+        assert_eq!(state.selected, Some(0));
+
+        state.select_relative(-1, 5);
+        assert_eq!(state.selected, Some(4));
+
+        state.select_relative(-7, 5);
+        assert_eq!(state.selected, Some(2));
+
+        state.select_relative(isize::MIN, 5);
+        assert!(state.selected.is_some_and(|i| i < 5));
+    }
+
+    #[test]
+    fn select_relative_offset() {
+        let mut state = ListState::default().with_offset(8).with_selected(Some(9));
+        state.select_relative(-3, 10);
+        assert_eq!(state.selected, Some(6));
+        assert_eq!(state.offset, 6);
+
+        state.select_relative(3, 10);
+        assert_eq!(state.offset, 6);
+    }
 }
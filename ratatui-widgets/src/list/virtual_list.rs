@@ -0,0 +1,358 @@
+use std::fmt;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{
+    block::{Block, BlockExt},
+    list::ListState,
+};
+
+/// A list that renders its items on demand through a callback
+///
+/// Unlike [`List`], which owns a [`ListItem`] for each of its items, a `VirtualList` only knows
+/// how many items there are. When rendered, it works out which items are visible from the
+/// [`ListState`] and calls `render_item` for each of them, so the cost of rendering depends on
+/// the size of the area rather than on the number of items. This makes it suitable for lists with
+/// millions of items, or for items that are expensive to build.
+///
+/// The callback is called with the index of the item, the area to render it in, the buffer and
+/// whether the item is selected. Every item has the same height, which is one row unless changed
+/// with [`VirtualList::item_height`].
+///
+/// The [`ListState`] is used in the same way as for a [`List`]: the selection is clamped to the
+/// last item and the offset is updated so that the selected item is visible.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     style::{Style, Stylize},
+///     widgets::{Block, ListState, StatefulWidget, VirtualList},
+/// };
+///
+/// let list = VirtualList::new(1_000_000, |index, area, buf: &mut Buffer, selected| {
+///     let marker = if selected { ">> " } else { "   " };
+///     buf.set_string(
+///         area.x,
+///         area.y,
+///         format!("{marker}Item {index}"),
+///         Style::new(),
+///     );
+/// })
+/// .block(Block::bordered().title("Virtual List"))
+/// .highlight_style(Style::new().reversed());
+///
+/// let mut state = ListState::default().with_selected(Some(500_000));
+/// let area = Rect::new(0, 0, 20, 10);
+/// let mut buf = Buffer::empty(area);
+/// list.render(area, &mut buf, &mut state);
+/// ```
+///
+/// [`List`]: super::List
+/// [`ListItem`]: super::ListItem
+pub struct VirtualList<'a, F> {
+    /// An optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The number of items in the list
+    item_count: usize,
+    /// The height of every item
+    item_height: u16,
+    /// Renders the item at the given index
+    render_item: F,
+    /// Style used as a base style for the widget
+    style: Style,
+    /// Style used to render the selected item
+    highlight_style: Style,
+}
+
+impl<'a, F> VirtualList<'a, F>
+where
+    F: Fn(usize, Rect, &mut Buffer, bool),
+{
+    /// Creates a new list of `item_count` items that are rendered by `render_item`
+    ///
+    /// `render_item` is called with the index of the item, the area to render it in, the buffer
+    /// and whether the item is selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, style::Style, widgets::VirtualList};
+    ///
+    /// let list = VirtualList::new(100, |index, area, buf: &mut Buffer, _selected| {
+    ///     buf.set_string(area.x, area.y, index.to_string(), Style::new());
+    /// });
+    /// ```
+    pub const fn new(item_count: usize, render_item: F) -> Self {
+        Self {
+            block: None,
+            item_count,
+            item_height: 1,
+            render_item,
+            style: Style::new(),
+            highlight_style: Style::new(),
+        }
+    }
+
+    /// Wraps the list with a custom [`Block`] widget.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the number of rows that each item takes up
+    ///
+    /// Defaults to `1`. A height of `0` is treated as `1`. Items that only partly fit at the bottom
+    /// of the area are not rendered, unless the area is smaller than a single item.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn item_height(mut self, height: u16) -> Self {
+        self.item_height = height.max(1);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is applied to the whole area before the items are rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected item
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is applied to the area of the selected item after it has been rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+}
+
+impl<F> fmt::Debug for VirtualList<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualList")
+            .field("block", &self.block)
+            .field("item_count", &self.item_count)
+            .field("item_height", &self.item_height)
+            .field("style", &self.style)
+            .field("highlight_style", &self.highlight_style)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> Widget for VirtualList<'_, F>
+where
+    F: Fn(usize, Rect, &mut Buffer, bool),
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<F> Widget for &VirtualList<'_, F>
+where
+    F: Fn(usize, Rect, &mut Buffer, bool),
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ListState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl<F> StatefulWidget for VirtualList<'_, F>
+where
+    F: Fn(usize, Rect, &mut Buffer, bool),
+{
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl<F> StatefulWidget for &VirtualList<'_, F>
+where
+    F: Fn(usize, Rect, &mut Buffer, bool),
+{
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let list_area = self.block.inner_if_some(area);
+
+        if list_area.is_empty() {
+            return;
+        }
+
+        if self.item_count == 0 {
+            state.select(None);
+            return;
+        }
+
+        // Apply any wrapping move and set an out of bounds selection to the last item
+        state.clamp_selection(self.item_count);
+
+        let visible_count = usize::from(list_area.height / self.item_height).max(1);
+        state.offset = visible_offset(state.selected, state.offset, self.item_count, visible_count);
+
+        let last = self
+            .item_count
+            .min(state.offset.saturating_add(visible_count));
+        for (row, index) in (state.offset..last).enumerate() {
+            // row is less than visible_count, which is at most list_area.height
+            #[allow(clippy::cast_possible_truncation)]
+            let y = list_area.y + row as u16 * self.item_height;
+            let item_area = Rect {
+                y,
+                height: self.item_height.min(list_area.bottom() - y),
+                ..list_area
+            };
+            let is_selected = state.selected == Some(index);
+            (self.render_item)(index, item_area, buf, is_selected);
+            if is_selected {
+                buf.set_style(item_area, self.highlight_style);
+            }
+        }
+    }
+}
+
+/// Returns the offset that keeps the selected item visible when `visible_count` items fit in the
+/// area, moving the given offset as little as possible.
+const fn visible_offset(
+    selected: Option<usize>,
+    offset: usize,
+    item_count: usize,
+    visible_count: usize,
+) -> usize {
+    let offset = if offset < item_count {
+        offset
+    } else {
+        item_count - 1
+    };
+    match selected {
+        Some(selected) if selected < offset => selected,
+        Some(selected) if selected >= offset + visible_count => selected + 1 - visible_count,
+        _ => offset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    fn render_index(index: usize, area: Rect, buf: &mut Buffer, selected: bool) {
+        let marker = if selected { ">" } else { " " };
+        buf.set_string(area.x, area.y, format!("{marker}{index}"), Style::new());
+    }
+
+    #[test]
+    fn render() {
+        let list = VirtualList::new(1_000_000, render_index);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Widget::render(&list, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" 0   ", " 1   ", " 2   "]));
+    }
+
+    #[test]
+    fn render_only_visible_items() {
+        let rendered = std::cell::RefCell::new(Vec::new());
+        let list = VirtualList::new(1_000_000, |index, _area, _buf: &mut Buffer, _selected| {
+            rendered.borrow_mut().push(index);
+        });
+        let mut state = ListState::default().with_offset(500);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(*rendered.borrow(), vec![500, 501, 502]);
+    }
+
+    #[test]
+    fn render_selected() {
+        let list = VirtualList::new(1_000_000, render_index).highlight_style(Color::Red);
+        let mut state = ListState::default().with_selected(Some(999_999));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 999_997);
+        let mut expected = Buffer::with_lines([" 999997 ", " 999998 ", ">999999 "]);
+        expected.set_style(Rect::new(0, 2, 8, 1), Color::Red);
+        assert_eq!(buf, expected);
+
+        // moving the selection above the offset scrolls up
+        state.select(Some(10));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 10);
+    }
+
+    #[test]
+    fn render_clamps_selection() {
+        let list = VirtualList::new(2, render_index);
+        let mut state = ListState::default().with_selected(Some(10));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), Some(1));
+        assert_eq!(buf, Buffer::with_lines([" 0 ", ">1 ", "   "]));
+    }
+
+    #[test]
+    fn render_empty() {
+        let list = VirtualList::new(0, render_index);
+        let mut state = ListState::default().with_selected(Some(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), None);
+        assert_eq!(buf, Buffer::with_lines(["   "]));
+    }
+
+    #[test]
+    fn render_item_height() {
+        let list = VirtualList::new(10, render_index)
+            .item_height(2)
+            .block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 7));
+        Widget::render(&list, buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌──┐", "│ 0│", "│  │", "│ 1│", "│  │", "│  │", "└──┘"])
+        );
+    }
+
+    #[test]
+    fn render_style() {
+        let list = VirtualList::new(1, render_index).style(Style::new().blue());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Widget::render(&list, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([" 0"]);
+        expected.set_style(buf.area, Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+}
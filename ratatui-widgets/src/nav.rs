@@ -0,0 +1,139 @@
+use strum::{Display, EnumString};
+
+/// Defines what happens when the selection of a [`Table`] or a [`List`] is moved past its first
+/// or last item
+///
+/// The policy is set on the [`TableState`] or [`ListState`] and is used by the `select_next` and
+/// `select_previous` methods of the state, and when the widget is rendered.
+///
+/// [`Table`]: crate::table::Table
+/// [`List`]: crate::list::List
+/// [`TableState`]: crate::table::TableState
+/// [`ListState`]: crate::list::ListState
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NavPolicy {
+    /// Keep the selection on the first or last item
+    ///
+    /// Selecting the next item when the last item is selected keeps the last item selected, and
+    /// selecting the previous item when the first item is selected keeps the first item selected.
+    #[default]
+    Clamp,
+
+    /// Wrap the selection around to the other end
+    ///
+    /// Selecting the next item when the last item is selected selects the first item, and
+    /// selecting the previous item when the first item is selected selects the last item.
+    Wrap,
+}
+
+impl NavPolicy {
+    /// Returns the index `delta` items away from `selected` in a list of `count` items
+    ///
+    /// If no item is selected, a positive `delta` counts from before the first item and a negative
+    /// `delta` counts from after the last one. An out of bounds selection is clamped to the last
+    /// item before moving. Returns `None` if there are no items.
+    pub(crate) fn step(self, selected: Option<usize>, delta: isize, count: usize) -> Option<usize> {
+        if count == 0 {
+            return None;
+        }
+        let count = isize::try_from(count).unwrap_or(isize::MAX);
+        let current = match selected {
+            Some(index) => isize::try_from(index).unwrap_or(isize::MAX).min(count - 1),
+            None if delta < 0 => count,
+            None if delta > 0 => -1,
+            None => 0,
+        };
+        let index = current.saturating_add(delta);
+        let index = match self {
+            Self::Clamp => index.clamp(0, count - 1),
+            Self::Wrap => index.rem_euclid(count),
+        };
+        #[allow(clippy::cast_sign_loss)] // index is non-negative
+        Some(index as usize)
+    }
+
+    /// Records a move of the selection by `delta` items made while the number of items is unknown
+    ///
+    /// With [`NavPolicy::Clamp`], moving the selection index directly is enough as an out of
+    /// bounds index is clamped to the last item when rendering. With [`NavPolicy::Wrap`], the move
+    /// is kept (and added to any move which is still `pending`) so that it can be wrapped once the
+    /// number of items is known.
+    pub(crate) const fn record_move(
+        self,
+        pending: Option<PendingMove>,
+        selected: Option<usize>,
+        delta: isize,
+    ) -> Option<PendingMove> {
+        match (self, pending, selected) {
+            (Self::Clamp, _, _) | (Self::Wrap, None, None) => None,
+            (Self::Wrap, Some(pending), _) => Some(PendingMove {
+                from: pending.from,
+                delta: pending.delta.saturating_add(delta),
+            }),
+            (Self::Wrap, None, Some(from)) => Some(PendingMove { from, delta }),
+        }
+    }
+}
+
+/// A move of the selection which is resolved when the number of items is known
+///
+/// This is created by the `select_next` and `select_previous` methods of the states when the
+/// [`NavPolicy`] is [`NavPolicy::Wrap`], and applied by [`NavPolicy::step`] when rendering.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub(crate) struct PendingMove {
+    /// The index which was selected before the move
+    pub from: usize,
+    /// The number of items to move by
+    pub delta: isize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string() {
+        assert_eq!(NavPolicy::Clamp.to_string(), "Clamp");
+        assert_eq!(NavPolicy::Wrap.to_string(), "Wrap");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("Clamp".parse::<NavPolicy>(), Ok(NavPolicy::Clamp));
+        assert_eq!("Wrap".parse::<NavPolicy>(), Ok(NavPolicy::Wrap));
+        assert!("Bounce".parse::<NavPolicy>().is_err());
+    }
+
+    #[test]
+    fn step() {
+        assert_eq!(NavPolicy::Clamp.step(Some(2), 1, 5), Some(3));
+        assert_eq!(NavPolicy::Clamp.step(Some(4), 1, 5), Some(4));
+        assert_eq!(NavPolicy::Clamp.step(Some(0), -1, 5), Some(0));
+        assert_eq!(NavPolicy::Clamp.step(Some(usize::MAX), -1, 5), Some(3));
+        assert_eq!(NavPolicy::Clamp.step(None, 1, 5), Some(0));
+        assert_eq!(NavPolicy::Clamp.step(None, -1, 5), Some(4));
+        assert_eq!(NavPolicy::Clamp.step(Some(2), 1, 0), None);
+
+        assert_eq!(NavPolicy::Wrap.step(Some(4), 1, 5), Some(0));
+        assert_eq!(NavPolicy::Wrap.step(Some(0), -1, 5), Some(4));
+        assert_eq!(NavPolicy::Wrap.step(Some(0), -2, 5), Some(3));
+        assert_eq!(NavPolicy::Wrap.step(Some(9), 1, 5), Some(0));
+        assert_eq!(NavPolicy::Wrap.step(None, -1, 5), Some(4));
+        assert!(NavPolicy::Wrap
+            .step(Some(2), isize::MIN, 5)
+            .is_some_and(|i| i < 5));
+    }
+
+    #[test]
+    fn record_move() {
+        assert_eq!(NavPolicy::Clamp.record_move(None, Some(2), 1), None);
+        assert_eq!(NavPolicy::Wrap.record_move(None, None, 1), None);
+        let pending = NavPolicy::Wrap.record_move(None, Some(2), -1);
+        assert_eq!(pending, Some(PendingMove { from: 2, delta: -1 }));
+        assert_eq!(
+            NavPolicy::Wrap.record_move(pending, Some(1), -1),
+            Some(PendingMove { from: 2, delta: -2 })
+        );
+    }
+}
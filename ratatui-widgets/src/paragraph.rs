@@ -1,12 +1,20 @@
 //! The [`Paragraph`] widget and related types allows displaying a block of text with optional
 //! wrapping, alignment, and block styling.
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    iter,
+    ops::Range,
+};
+
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Alignment, Position, Rect},
+    layout::{Alignment, Constraint, Position, Rect},
     style::{Style, Styled},
-    text::{Line, StyledGrapheme, Text},
-    widgets::Widget,
+    text::{Line, Span, StyledGrapheme, Text},
+    widgets::{SizeHint, StatefulWidget, Widget},
 };
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
@@ -28,6 +36,8 @@ use crate::{
 ///
 /// The text can be aligned to the left, right, or center. The alignment can be configured with the
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
+/// The alignment of a [`Line`] takes precedence over the alignment of the [`Text`], which takes
+/// precedence over the alignment of the paragraph.
 ///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
 /// the [`scroll`] method.
@@ -75,7 +85,7 @@ use crate::{
 ///     .block(Block::bordered().title("Paragraph"))
 ///     .style(Style::new().white().on_black())
 ///     .alignment(Alignment::Center)
-///     .wrap(Wrap { trim: true });
+///     .wrap(Wrap::new().trim(true));
 /// ```
 ///
 /// [`Span`]: ratatui_core::text::Span
@@ -93,6 +103,8 @@ pub struct Paragraph<'a> {
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// How to number the lines in a gutter on the left of the text
+    line_numbers: Option<LineNumbers>,
 }
 
 /// Describes how to wrap text across lines.
@@ -112,7 +124,7 @@ pub struct Paragraph<'a> {
 /// );
 ///
 /// // With leading spaces trimmed (window width of 30 chars):
-/// Paragraph::new(bullet_points.clone()).wrap(Wrap { trim: true });
+/// Paragraph::new(bullet_points.clone()).wrap(Wrap::new().trim(true));
 /// // Some indented points:
 /// // - First thing goes here and is
 /// // long so that it wraps
@@ -120,17 +132,130 @@ pub struct Paragraph<'a> {
 /// // is long enough to wrap
 ///
 /// // But without trimming, indentation is preserved:
-/// Paragraph::new(bullet_points).wrap(Wrap { trim: false });
+/// Paragraph::new(bullet_points).wrap(Wrap::new().trim(false));
 /// // Some indented points:
 /// //     - First thing goes here
 /// // and is long so that it wraps
 /// //     - Here is another point
 /// // that is long enough to wrap
+///
+/// // Preformatted text can keep the whitespace at the wrapping points:
+/// Paragraph::new("fn main() {    }").wrap(Wrap::new().trim(false).preserve_trailing(true));
 /// ```
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub struct Wrap {
     /// Should leading whitespace be trimmed
     pub trim: bool,
+    /// Should whitespace at the end of wrapped lines be kept
+    ///
+    /// By default, the whitespace at the point where a line is wrapped is dropped. When this is
+    /// set, the whitespace that fits is kept at the end of the wrapped line and the rest is
+    /// carried over to the next line. Combined with `trim: false`, this preserves all
+    /// whitespace exactly, which is useful for rendering code or other preformatted text.
+    pub preserve_trailing: bool,
+    /// Should the wrapped lines be laid out from right to left
+    ///
+    /// This is intended for right-to-left scripts such as Hebrew or Arabic. The lines are wrapped
+    /// as usual, then the graphemes of each wrapped line are placed from right to left, and the
+    /// alignment of the line is mirrored so that [`Alignment::Left`] (the default) lays the line
+    /// out from the right edge of the area. Widths are computed per grapheme, so wide graphemes
+    /// are handled correctly.
+    ///
+    /// This is not a bidirectional text implementation: a line mixing right-to-left and
+    /// left-to-right text (e.g. Hebrew with embedded numbers or English words) is reversed as a
+    /// whole, so the left-to-right runs end up reversed too. [`TextWrapExt::wrap`] ignores this
+    /// option and keeps the logical order of the graphemes.
+    pub rtl: bool,
+}
+
+impl Wrap {
+    /// Creates a new `Wrap` which neither trims the leading whitespace of the wrapped lines nor
+    /// keeps their trailing whitespace, and lays them out from left to right.
+    ///
+    /// More options may be added in the future, so `Wrap` can't be constructed with a struct
+    /// literal outside of this crate. Use this and the builder methods instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Hello, world!").wrap(Wrap::new().trim(true));
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            trim: false,
+            preserve_trailing: false,
+            rtl: false,
+        }
+    }
+
+    /// Sets whether the leading whitespace of the wrapped lines is trimmed, see [`Wrap::trim`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets whether the whitespace at the end of the wrapped lines is kept, see
+    /// [`Wrap::preserve_trailing`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn preserve_trailing(mut self, preserve_trailing: bool) -> Self {
+        self.preserve_trailing = preserve_trailing;
+        self
+    }
+
+    /// Sets whether the wrapped lines are laid out from right to left, see [`Wrap::rtl`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+}
+
+/// Describes how to number the lines of a [`Paragraph`].
+///
+/// The numbers are rendered right-aligned in a gutter on the left of the text, followed by a
+/// space. The gutter is as wide as the largest line number, and its width is taken from the area
+/// of the text. Each number is displayed on the first row of its line: the rows a line is wrapped
+/// onto have a blank gutter. The numbers follow the vertical [scroll](Paragraph::scroll) of the
+/// paragraph.
+///
+/// The default numbers the lines from `1` without any style.
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::{LineNumbers, Paragraph},
+/// };
+///
+/// let code = "fn main() {\n    println!(\"Hello, world!\");\n}";
+/// Paragraph::new(code).line_numbers(LineNumbers {
+///     start: 1,
+///     style: Style::new().dark_gray(),
+/// });
+/// // 1 fn main() {
+/// // 2     println!("Hello, world!");
+/// // 3 }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LineNumbers {
+    /// The number of the first line of the text
+    pub start: usize,
+    /// The style of the gutter
+    pub style: Style,
+}
+
+impl Default for LineNumbers {
+    fn default() -> Self {
+        Self {
+            start: 1,
+            style: Style::new(),
+        }
+    }
 }
 
 type Horizontal = u16;
@@ -168,6 +293,7 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             scroll: Position::ORIGIN,
             alignment: Alignment::Left,
+            line_numbers: None,
         }
     }
 
@@ -221,7 +347,7 @@ impl<'a> Paragraph<'a> {
     /// ```rust
     /// use ratatui::widgets::{Paragraph, Wrap};
     ///
-    /// let paragraph = Paragraph::new("Hello, world!").wrap(Wrap { trim: true });
+    /// let paragraph = Paragraph::new("Hello, world!").wrap(Wrap::new().trim(true));
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn wrap(mut self, wrap: Wrap) -> Self {
@@ -229,6 +355,23 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets how to number the lines of the text in a gutter on the left of the text.
+    ///
+    /// See [`LineNumbers`] for more information on how the lines are numbered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{LineNumbers, Paragraph};
+    ///
+    /// let paragraph = Paragraph::new("first\nsecond").line_numbers(LineNumbers::default());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_numbers(mut self, line_numbers: LineNumbers) -> Self {
+        self.line_numbers = Some(line_numbers);
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
@@ -254,12 +397,28 @@ impl<'a> Paragraph<'a> {
     /// The alignment is a variant of the [`Alignment`] enum which can be one of Left, Right, or
     /// Center. If no alignment is specified, the text in a paragraph will be left-aligned.
     ///
+    /// This is the default alignment of the lines: a line uses its own alignment if it has one
+    /// (see [`Line::alignment`]), otherwise the alignment of the text (see [`Text::alignment`]),
+    /// and otherwise this alignment.
+    ///
     /// # Example
     ///
     /// ```rust
-    /// use ratatui::{layout::Alignment, widgets::Paragraph};
+    /// use ratatui::{
+    ///     layout::Alignment,
+    ///     text::{Line, Text},
+    ///     widgets::Paragraph,
+    /// };
     ///
     /// let paragraph = Paragraph::new("Hello World").alignment(Alignment::Center);
+    ///
+    /// // the first line is right aligned, the second one is centered
+    /// let text = Text::from(vec![
+    ///     Line::from("right").right_aligned(),
+    ///     Line::from("center"),
+    /// ])
+    /// .centered();
+    /// let paragraph = Paragraph::new(text).alignment(Alignment::Left);
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn alignment(mut self, alignment: Alignment) -> Self {
@@ -315,13 +474,52 @@ impl<'a> Paragraph<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Returns a [`StatefulWidget`] that caches the wrapped lines of this paragraph between
+    /// renders.
+    ///
+    /// Wrapping the text is the most expensive part of rendering a wrapped paragraph, and it is
+    /// done again on each render. The returned [`CachedParagraph`] stores the wrapped lines in a
+    /// [`ParagraphState`] and reuses them as long as the text, the wrapping options, the alignment
+    /// and the width of the area do not change. This makes redrawing a large paragraph cheap when
+    /// only the [scroll offset](Paragraph::scroll) changes.
+    ///
+    /// The text is still compared with the cached one on each render to detect changes, so this
+    /// only helps paragraphs that are [wrapped](Paragraph::wrap); paragraphs that are not wrapped
+    /// are rendered as usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{Paragraph, ParagraphState, StatefulWidget, Wrap},
+    /// };
+    ///
+    /// let text = "A long text that is wrapped once and then scrolled through.";
+    /// let mut state = ParagraphState::default();
+    /// let area = Rect::new(0, 0, 10, 3);
+    /// let mut buf = Buffer::empty(area);
+    /// for offset in 0..3 {
+    ///     let paragraph = Paragraph::new(text)
+    ///         .wrap(Wrap::new().trim(true))
+    ///         .scroll((offset, 0));
+    ///     paragraph.cached().render(area, &mut buf, &mut state);
+    /// }
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cached(self) -> CachedParagraph<'a> {
+        CachedParagraph { paragraph: self }
+    }
+
     /// Calculates the number of lines needed to fully render.
     ///
     /// Given a max line width, this method calculates the number of lines that a paragraph will
     /// need in order to be fully rendered. For paragraphs that do not use wrapping, this count is
     /// simply the number of lines present in the paragraph.
     ///
-    /// This method will also account for the [`Block`] if one is set through [`Self::block`].
+    /// This method will also account for the [`Block`] if one is set through [`Self::block`], and
+    /// for the width of the gutter if the lines are numbered through [`Self::line_numbers`].
     ///
     /// Note: The design for text wrapping is not stable and might affect this API.
     ///
@@ -331,7 +529,7 @@ impl<'a> Paragraph<'a> {
     /// use ratatui::{widgets::{Paragraph, Wrap}};
     ///
     /// let paragraph = Paragraph::new("Hello World")
-    ///     .wrap(Wrap { trim: false });
+    ///     .wrap(Wrap::new().trim(false));
     /// assert_eq!(paragraph.line_count(20), 1);
     /// assert_eq!(paragraph.line_count(10), 2);
     /// ```
@@ -340,6 +538,7 @@ impl<'a> Paragraph<'a> {
         issue = "https://github.com/ratatui/ratatui/issues/293"
     )]
     pub fn line_count(&self, width: u16) -> usize {
+        let width = width.saturating_sub(self.gutter_width());
         if width < 1 {
             return 0;
         }
@@ -350,16 +549,7 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
+        let count = if let Some(mut line_composer) = self.wrapped_lines(width) {
             let mut count = 0;
             while line_composer.next_line().is_some() {
                 count += 1;
@@ -376,7 +566,8 @@ impl<'a> Paragraph<'a> {
 
     /// Calculates the shortest line width needed to avoid any word being wrapped or truncated.
     ///
-    /// Accounts for the [`Block`] if a block is set through [`Self::block`].
+    /// Accounts for the [`Block`] if a block is set through [`Self::block`], and for the gutter if
+    /// the lines are numbered through [`Self::line_numbers`].
     ///
     /// Note: The design for text wrapping is not stable and might affect this API.
     ///
@@ -397,6 +588,7 @@ impl<'a> Paragraph<'a> {
     )]
     pub fn line_width(&self) -> usize {
         let width = self.text.iter().map(Line::width).max().unwrap_or_default();
+        let width = width.saturating_add(usize::from(self.gutter_width()));
         let (left, right) = self
             .block
             .as_ref()
@@ -407,6 +599,166 @@ impl<'a> Paragraph<'a> {
             .saturating_add(left as usize)
             .saturating_add(right as usize)
     }
+
+    /// Returns the `(row, column)` at which the given character offset of the text is displayed
+    /// when the text is rendered `width` columns wide.
+    ///
+    /// `char_offset` counts the characters of the text, with each line break counting as one
+    /// character, so this maps a cursor position in the source text of a text input to its
+    /// position on the screen. The text is wrapped (see [`Paragraph::wrap`]) or truncated the same
+    /// way it is rendered and the alignment of the lines is applied. The position is relative to
+    /// the top left corner of the text area, so it doesn't account for the [`Block`], the line
+    /// numbers gutter or the [scroll offset](Paragraph::scroll), and the text is laid out from
+    /// left to right (ignoring [`Wrap::rtl`]).
+    ///
+    /// When the character is not displayed (e.g. whitespace trimmed at a line break, or a part of
+    /// a line which is truncated), the position right after the previous displayed character is
+    /// returned. An offset at the end of a line (or past the end of the text) is located right
+    /// after the last character of the line, so the column can be equal to `width`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Hello World\nHi").wrap(Wrap::new().trim(true));
+    /// assert_eq!(paragraph.locate_offset(0, 8), (0, 0));
+    /// assert_eq!(paragraph.locate_offset(7, 8), (1, 1));
+    /// assert_eq!(paragraph.locate_offset(13, 8), (2, 1));
+    /// ```
+    #[instability::unstable(
+        feature = "rendered-line-info",
+        issue = "https://github.com/ratatui/ratatui/issues/293"
+    )]
+    pub fn locate_offset(&self, char_offset: usize, width: u16) -> (u16, u16) {
+        let mut remaining = char_offset;
+        let mut row: u16 = 0;
+        let last_line = self.text.lines.len().saturating_sub(1);
+        for (index, line) in self.text.iter().enumerate() {
+            let line_chars = line
+                .spans
+                .iter()
+                .map(|span| span.content.chars().count())
+                .sum::<usize>();
+            let ((line_row, column), height) =
+                self.locate_line_offset(line, remaining.min(line_chars), width);
+            if remaining <= line_chars || index == last_line {
+                return (row.saturating_add(line_row), column);
+            }
+            // skip the characters of the line and the line break
+            remaining -= line_chars + 1;
+            row = row.saturating_add(height);
+        }
+        (0, 0)
+    }
+
+    /// Returns the `(row, column)` of the given character offset of a line, relative to the first
+    /// row of the line, and the number of rows that the line takes once wrapped or truncated.
+    fn locate_line_offset(&self, line: &Line, char_offset: usize, width: u16) -> ((u16, u16), u16) {
+        // All the graphemes of the line (including the ones which are never displayed) and the
+        // offset of their first character. The graphemes yielded by the line composers borrow the
+        // same strings, so they are matched to these by address.
+        let mut graphemes = vec![];
+        let mut char_start = 0;
+        for grapheme in line
+            .spans
+            .iter()
+            .flat_map(|span| span.content.graphemes(true))
+        {
+            graphemes.push((grapheme, char_start));
+            char_start += grapheme.chars().count();
+        }
+        let target = graphemes
+            .iter()
+            .position(|(grapheme, start)| char_offset < start + grapheme.chars().count())
+            .unwrap_or(graphemes.len());
+
+        let styled = iter::once((
+            line.styled_graphemes(self.text.style),
+            self.line_alignment(line),
+        ));
+        if let Some(wrap) = self.wrap {
+            // the position is located from left to right, see `locate_offset`
+            let composer = word_wrapper(styled, width, wrap.rtl(false));
+            locate_grapheme(composer, &graphemes, target, width)
+        } else {
+            locate_grapheme(LineTruncator::new(styled, width), &graphemes, target, width)
+        }
+    }
+
+    /// Returns the preferred size of the paragraph when it is at most `width` columns wide.
+    ///
+    /// This is like [`SizeHint::size_hint`], but the lines are wrapped (see [`Paragraph::wrap`]) or
+    /// truncated to fit in the given width, so the height is the number of wrapped lines and the
+    /// width is the width of the longest wrapped line. Both include the [`Block`] if one is set,
+    /// and the width includes the gutter if the lines are numbered.
+    ///
+    /// Note: The design for text wrapping is not stable and might affect this API.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Paragraph, Wrap},
+    /// };
+    ///
+    /// let paragraph = Paragraph::new("Hello World").wrap(Wrap::new().trim(true));
+    /// assert_eq!(
+    ///     paragraph.size_hint_for_width(8),
+    ///     (Constraint::Length(5), Constraint::Length(2))
+    /// );
+    /// ```
+    #[instability::unstable(
+        feature = "rendered-line-info",
+        issue = "https://github.com/ratatui/ratatui/issues/293"
+    )]
+    pub fn size_hint_for_width(&self, width: u16) -> (Constraint, Constraint) {
+        let (left, right) = self
+            .block
+            .as_ref()
+            .map(Block::horizontal_space)
+            .unwrap_or_default();
+        let (top, bottom) = self
+            .block
+            .as_ref()
+            .map(Block::vertical_space)
+            .unwrap_or_default();
+        let left = left.saturating_add(self.gutter_width());
+        let inner_width = width.saturating_sub(left).saturating_sub(right).max(1);
+
+        let (text_width, text_height) =
+            if let Some(mut line_composer) = self.wrapped_lines(inner_width) {
+                let (mut text_width, mut text_height) = (0, 0);
+                while let Some(line) = line_composer.next_line() {
+                    text_width = text_width.max(line.width);
+                    text_height += 1;
+                }
+                (text_width, text_height)
+            } else {
+                let text_width = u16::try_from(self.text.width()).unwrap_or(u16::MAX);
+                (text_width.min(inner_width), self.text.height())
+            };
+
+        (
+            Constraint::Length(text_width.saturating_add(left).saturating_add(right)),
+            Constraint::Length(
+                u16::try_from(text_height)
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(top)
+                    .saturating_add(bottom),
+            ),
+        )
+    }
+}
+
+impl SizeHint for Paragraph<'_> {
+    /// Returns the width of the longest line and the number of lines, including the [`Block`] if
+    /// one is set. Lines are not wrapped, see `Paragraph::size_hint_for_width` (behind the
+    /// `unstable-rendered-line-info` feature) for a hint that accounts for wrapping.
+    fn size_hint(&self) -> (Constraint, Constraint) {
+        self.size_hint_for_width(u16::MAX)
+    }
 }
 
 impl Widget for Paragraph<'_> {
@@ -420,42 +772,332 @@ impl Widget for &Paragraph<'_> {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        let (gutter, text_area) = self.gutter(inner, buf);
+        self.render_paragraph(text_area, gutter.as_ref(), buf);
     }
 }
 
 impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+    /// Returns the graphemes of each line of the text along with the alignment of the line.
+    fn styled_lines(
+        &self,
+    ) -> impl Iterator<Item = (impl Iterator<Item = StyledGrapheme<'_>>, Alignment)> {
+        self.text.iter().map(|line| {
+            let graphemes = line.styled_graphemes(self.text.style);
+            (graphemes, self.line_alignment(line))
+        })
+    }
+
+    /// Returns the lines of the text wrapped to `width` columns, or `None` if the paragraph isn't
+    /// wrapped.
+    ///
+    /// Each wrapped line records the index of the line of the text it starts, if any.
+    fn wrapped_lines(&self, width: u16) -> Option<impl LineComposer<'_>> {
+        let wrap = self.wrap?;
+        Some(word_wrapper(self.styled_lines(), width, wrap))
+    }
+
+    /// Returns the alignment of a line of the text, falling back to the alignment of the text and
+    /// then to the alignment of the paragraph.
+    fn line_alignment(&self, line: &Line) -> Alignment {
+        line.alignment
+            .or(self.text.alignment)
+            .unwrap_or(self.alignment)
+    }
+
+    /// Returns the width of the line numbers gutter, including the space that separates it from the
+    /// text, or `0` if the lines are not numbered.
+    fn gutter_width(&self) -> u16 {
+        let Some(LineNumbers { start, .. }) = self.line_numbers else {
+            return 0;
+        };
+        let last = start.saturating_add(self.text.lines.len().saturating_sub(1));
+        let digits = last.checked_ilog10().map_or(1, |log| log + 1);
+        u16::try_from(digits).unwrap_or(u16::MAX).saturating_add(1)
+    }
+
+    /// Splits the area into the line numbers gutter, if the lines are numbered, and the area that
+    /// is left for the text.
+    fn gutter(&self, area: Rect, buf: &mut Buffer) -> (Option<Gutter>, Rect) {
+        let Some(line_numbers) = self.line_numbers else {
+            return (None, area);
+        };
+        let gutter_width = self.gutter_width().min(area.width);
+        let text_area = Rect {
+            x: area.x + gutter_width,
+            width: area.width - gutter_width,
+            ..area
+        };
+        if text_area.is_empty() {
+            return (None, text_area);
+        }
+
+        let numbers_area = Rect {
+            width: gutter_width - 1,
+            ..area
+        };
+        buf.set_style(numbers_area, line_numbers.style);
+        let gutter = Gutter {
+            area: numbers_area,
+            start: line_numbers.start,
+        };
+        (Some(gutter), text_area)
+    }
+
+    fn render_paragraph(&self, text_area: Rect, gutter: Option<&Gutter>, buf: &mut Buffer) {
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
-            let alignment = line.alignment.unwrap_or(self.alignment);
-            (graphemes, alignment)
-        });
-
-        if let Some(Wrap { trim }) = self.wrap {
-            let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
-            // compute the lines iteratively until we reach the desired scroll offset.
-            for _ in 0..self.scroll.y {
-                if line_composer.next_line().is_none() {
-                    return;
+        if let Some(mut line_composer) = self.wrapped_lines(text_area.width) {
+            // compute the lines iteratively, only rendering them from the desired scroll offset.
+            for row in 0_usize.. {
+                let Some(wrapped) = line_composer.next_line() else {
+                    break;
+                };
+                let Some(y) = row.checked_sub(usize::from(self.scroll.y)) else {
+                    continue;
+                };
+                if y >= usize::from(text_area.height) {
+                    break;
+                }
+                let y = y as u16;
+                render_line(&wrapped, text_area, buf, y);
+                if let (Some(gutter), Some(index)) = (gutter, wrapped.first_row_of) {
+                    gutter.render_number(index, y, buf);
                 }
             }
-            render_lines(line_composer, text_area, buf);
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
-            let lines = styled.skip(self.scroll.y as usize);
+            let lines = self.styled_lines().skip(self.scroll.y as usize);
             let mut line_composer = LineTruncator::new(lines, text_area.width);
             line_composer.set_horizontal_offset(self.scroll.x);
             render_lines(line_composer, text_area, buf);
+            if let Some(gutter) = gutter {
+                let indexes = usize::from(self.scroll.y)..self.text.lines.len();
+                for (y, index) in (0..text_area.height).zip(indexes) {
+                    gutter.render_number(index, y, buf);
+                }
+            }
         }
     }
 }
 
+/// The line numbers gutter of a [`Paragraph`] being rendered
+struct Gutter {
+    /// The area of the numbers, without the space that separates them from the text
+    area: Rect,
+    /// The number of the first line
+    start: usize,
+}
+
+impl Gutter {
+    /// Renders the number of the line at `index` in the text, on the row `y` of the gutter.
+    fn render_number(&self, index: usize, y: u16, buf: &mut Buffer) {
+        let number = self.start.saturating_add(index);
+        let width = usize::from(self.area.width);
+        buf.set_stringn(
+            self.area.x,
+            self.area.y + y,
+            format!("{number:>width$}"),
+            width,
+            Style::new(),
+        );
+    }
+}
+
+/// A [`Paragraph`] that caches its wrapped lines in a [`ParagraphState`].
+///
+/// This is created with [`Paragraph::cached`], see its documentation for more details.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct CachedParagraph<'a> {
+    paragraph: Paragraph<'a>,
+}
+
+/// State of a [`CachedParagraph`] which holds the wrapped lines of the last render.
+///
+/// The state is only a cache: it can be reset to its default value at any time, in which case the
+/// lines are wrapped again on the next render. A single state should be used for a single
+/// paragraph, as rendering a different paragraph replaces the cached lines.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ParagraphState {
+    /// The wrapped lines of the last render
+    cache: Option<WrapCache>,
+}
+
+/// The wrapped lines of a paragraph along with a hash of what they were computed from
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct WrapCache {
+    /// The hash of the text and options the lines were wrapped from, see [`WrapCache::key`]
+    key: u64,
+    lines: Vec<CachedLine>,
+}
+
+impl WrapCache {
+    /// Returns a hash of the text and options that wrapping the paragraph to `width` columns
+    /// depends on.
+    ///
+    /// Hashing the text avoids keeping an owned copy of it in the cache, at the cost of a
+    /// negligible chance of a collision.
+    fn key(paragraph: &Paragraph<'_>, wrap: Wrap, width: u16) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (&paragraph.text, paragraph.alignment, wrap, width).hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// An owned copy of a [`WrappedLine`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct CachedLine {
+    /// The symbols of all the graphemes of the line
+    content: String,
+    /// The range of each grapheme in `content`, along with its style and the range of its link
+    /// in `links`
+    graphemes: Vec<(Range<usize>, Style, Option<Range<usize>>)>,
+    /// The links of the graphemes, each stored once for a run of graphemes sharing it
+    links: String,
+    width: u16,
+    alignment: Alignment,
+    /// The index of the line of the text that starts on this row, if any
+    first_row_of: Option<usize>,
+}
+
+impl CachedLine {
+    fn new(wrapped: &WrappedLine<'_, '_>) -> Self {
+        let mut content = String::new();
+        let mut links = String::new();
+        let mut last_link: Option<(&str, Range<usize>)> = None;
+        let graphemes = wrapped
+            .graphemes
+            .iter()
+            .map(|grapheme| {
+                let start = content.len();
+                content.push_str(grapheme.symbol);
+                let link = grapheme.link.map(|link| match &last_link {
+                    Some((last, range)) if *last == link => range.clone(),
+                    _ => {
+                        let range = links.len()..links.len() + link.len();
+                        links.push_str(link);
+                        last_link = Some((link, range.clone()));
+                        range
+                    }
+                });
+                (start..content.len(), grapheme.style, link)
+            })
+            .collect();
+        Self {
+            content,
+            graphemes,
+            links,
+            width: wrapped.width,
+            alignment: wrapped.alignment,
+            first_row_of: wrapped.first_row_of,
+        }
+    }
+
+    fn graphemes(&self) -> impl Iterator<Item = StyledGrapheme<'_>> {
+        // the ranges are built from the strings in `new`, so they are always on char boundaries
+        self.graphemes
+            .iter()
+            .map(|(range, style, link)| StyledGrapheme {
+                symbol: self.content.get(range.clone()).unwrap_or_default(),
+                style: *style,
+                link: link.clone().and_then(|range| self.links.get(range)),
+            })
+    }
+}
+
+impl ParagraphState {
+    /// Creates a new empty `ParagraphState`.
+    pub const fn new() -> Self {
+        Self { cache: None }
+    }
+}
+
+impl StatefulWidget for CachedParagraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &CachedParagraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let paragraph = &self.paragraph;
+        buf.set_style(area, paragraph.style);
+        paragraph.block.as_ref().render(area, buf);
+        let inner = paragraph.block.inner_if_some(area);
+        let (gutter, text_area) = paragraph.gutter(inner, buf);
+        paragraph.render_paragraph_cached(text_area, gutter.as_ref(), buf, state);
+    }
+}
+
+impl Paragraph<'_> {
+    fn render_paragraph_cached(
+        &self,
+        text_area: Rect,
+        gutter: Option<&Gutter>,
+        buf: &mut Buffer,
+        state: &mut ParagraphState,
+    ) {
+        let Some(wrap) = self.wrap else {
+            self.render_paragraph(text_area, gutter, buf);
+            return;
+        };
+        if text_area.is_empty() {
+            return;
+        }
+
+        buf.set_style(text_area, self.style);
+        let key = WrapCache::key(self, wrap, text_area.width);
+        let cache = match state.cache.take() {
+            Some(cache) if cache.key == key => cache,
+            _ => {
+                let mut line_composer = word_wrapper(self.styled_lines(), text_area.width, wrap);
+                let mut lines = vec![];
+                while let Some(wrapped) = line_composer.next_line() {
+                    lines.push(CachedLine::new(&wrapped));
+                }
+                WrapCache { key, lines }
+            }
+        };
+        let lines = cache
+            .lines
+            .iter()
+            .skip(usize::from(self.scroll.y))
+            .take(usize::from(text_area.height));
+        for (y, line) in (0..).zip(lines) {
+            render_graphemes(
+                line.graphemes(),
+                line.width,
+                line.alignment,
+                text_area,
+                buf,
+                y,
+            );
+            if let (Some(gutter), Some(index)) = (gutter, line.first_row_of) {
+                gutter.render_number(index, y, buf);
+            }
+        }
+        state.cache = Some(cache);
+    }
+}
+
+/// Creates the [`WordWrapper`] that wraps `lines` to `width` columns with the given options.
+fn word_wrapper<'a, O, I>(lines: O, width: u16, wrap: Wrap) -> WordWrapper<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    let mut line_composer = WordWrapper::new(lines, width, wrap.trim, wrap.preserve_trailing);
+    line_composer.set_rtl(wrap.rtl);
+    line_composer
+}
+
 fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
     let mut y = 0;
     while let Some(ref wrapped) = composer.next_line() {
@@ -468,8 +1110,25 @@ fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut
 }
 
 fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16) {
-    let mut x = get_line_offset(wrapped.width, area.width, wrapped.alignment);
-    for StyledGrapheme { symbol, style } in wrapped.graphemes {
+    let graphemes = wrapped.graphemes.iter().cloned();
+    render_graphemes(graphemes, wrapped.width, wrapped.alignment, area, buf, y);
+}
+
+fn render_graphemes<'a>(
+    graphemes: impl Iterator<Item = StyledGrapheme<'a>>,
+    line_width: u16,
+    alignment: Alignment,
+    area: Rect,
+    buf: &mut Buffer,
+    y: u16,
+) {
+    let mut x = get_line_offset(line_width, area.width, alignment);
+    for StyledGrapheme {
+        symbol,
+        style,
+        link,
+    } in graphemes
+    {
         let width = symbol.width();
         if width == 0 {
             continue;
@@ -477,11 +1136,62 @@ fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u
         // Make sure to overwrite any previous character with a space (rather than a zero-width)
         let symbol = if symbol.is_empty() { " " } else { symbol };
         let position = Position::new(area.left() + x, area.top() + y);
-        buf[position].set_symbol(symbol).set_style(*style);
+        let cell = &mut buf[position];
+        cell.set_symbol(symbol).set_style(style);
+        #[cfg(feature = "hyperlinks")]
+        cell.set_hyperlink(link);
+        #[cfg(not(feature = "hyperlinks"))]
+        let _ = link;
         x += u16::try_from(width).unwrap_or(u16::MAX);
     }
 }
 
+/// Returns the `(row, column)` at which the grapheme at index `target` of `graphemes` is displayed
+/// by the line composer, and the number of rows it composes.
+///
+/// If the grapheme isn't displayed, the position after the previous displayed grapheme is returned.
+fn locate_grapheme<'a>(
+    mut composer: impl LineComposer<'a>,
+    graphemes: &[(&str, usize)],
+    target: usize,
+    width: u16,
+) -> ((u16, u16), u16) {
+    let mut rows: u16 = 0;
+    let mut position = None;
+    let mut last_position = (0, 0);
+    let mut displayed = false;
+    let mut index = 0;
+    while let Some(WrappedLine {
+        graphemes: line,
+        width: line_width,
+        alignment,
+        ..
+    }) = composer.next_line()
+    {
+        let mut column = get_line_offset(line_width, width, alignment);
+        if !displayed {
+            last_position = (rows, column);
+        }
+        for grapheme in line {
+            while index < graphemes.len() && !std::ptr::eq(graphemes[index].0, grapheme.symbol) {
+                index += 1;
+            }
+            if position.is_none() && index >= target {
+                position = Some(if index == target {
+                    (rows, column)
+                } else {
+                    last_position
+                });
+            }
+            column = column.saturating_add(grapheme.symbol.width() as u16);
+            last_position = (rows, column);
+            displayed = true;
+        }
+        rows = rows.saturating_add(1);
+    }
+    (position.unwrap_or(last_position), rows)
+}
+
 const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
@@ -502,6 +1212,92 @@ impl Styled for Paragraph<'_> {
     }
 }
 
+/// An extension trait for [`Text`] to wrap it outside of a [`Paragraph`].
+///
+/// This is useful to measure or cache the wrapped text, or to feed it into other widgets.
+pub trait TextWrapExt {
+    /// Returns a new [`Text`] with the lines wrapped to the given width.
+    ///
+    /// The lines are wrapped in the same way as [`Paragraph`] wraps them when rendering with the
+    /// given [`Wrap`] options. The styles of the spans are preserved across the breaks, and each
+    /// wrapped line keeps the style and alignment of the line it comes from. The resulting text
+    /// owns its content. If the width is `0`, the resulting text has no lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Stylize,
+    ///     text::{Line, Text},
+    ///     widgets::{TextWrapExt, Wrap},
+    /// };
+    ///
+    /// let text = Text::from(Line::from(vec!["hello ".red(), "world".blue()]));
+    /// let wrapped = text.wrap(5, Wrap::new().trim(true));
+    /// assert_eq!(
+    ///     wrapped.lines,
+    ///     [Line::from("hello".red()), Line::from("world".blue())]
+    /// );
+    /// ```
+    fn wrap(&self, width: u16, options: Wrap) -> Text<'static>;
+}
+
+impl TextWrapExt for Text<'_> {
+    fn wrap(&self, width: u16, options: Wrap) -> Text<'static> {
+        let lines = self
+            .lines
+            .iter()
+            .flat_map(|line| {
+                let graphemes = line
+                    .spans
+                    .iter()
+                    .flat_map(|span| span.styled_graphemes(Style::default()));
+                // the alignment is kept on the wrapped lines, so it is not used by the wrapper, and
+                // the graphemes are kept in their logical order
+                let mut composer = word_wrapper(
+                    iter::once((graphemes, Alignment::Left)),
+                    width,
+                    options.rtl(false),
+                );
+                let mut wrapped_lines = vec![];
+                while let Some(wrapped) = composer.next_line() {
+                    wrapped_lines.push(Line {
+                        spans: spans_from_graphemes(wrapped.graphemes),
+                        style: line.style,
+                        alignment: line.alignment,
+                    });
+                }
+                wrapped_lines
+            })
+            .collect();
+        Text {
+            lines,
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+/// Merges consecutive graphemes with the same style and link into owned spans.
+fn spans_from_graphemes(graphemes: &[StyledGrapheme]) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = vec![];
+    for grapheme in graphemes {
+        match spans.last_mut() {
+            Some(span) if span.style == grapheme.style && span.link() == grapheme.link => {
+                span.content.to_mut().push_str(grapheme.symbol);
+            }
+            _ => {
+                let span = Span::styled(grapheme.symbol.to_string(), grapheme.style);
+                spans.push(match grapheme.link {
+                    Some(link) => span.hyperlink(link.to_string()),
+                    None => span,
+                });
+            }
+        }
+    }
+    spans
+}
+
 #[cfg(test)]
 mod tests {
     use ratatui_core::{
@@ -531,8 +1327,8 @@ mod tests {
         let line = "foo\u{200B}";
         for paragraph in [
             Paragraph::new(line),
-            Paragraph::new(line).wrap(Wrap { trim: false }),
-            Paragraph::new(line).wrap(Wrap { trim: true }),
+            Paragraph::new(line).wrap(Wrap::new().trim(false)),
+            Paragraph::new(line).wrap(Wrap::new().trim(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines(["foo"]));
             test_case(&paragraph, &Buffer::with_lines(["foo   "]));
@@ -545,8 +1341,8 @@ mod tests {
     fn test_render_empty_paragraph() {
         for paragraph in [
             Paragraph::new(""),
-            Paragraph::new("").wrap(Wrap { trim: false }),
-            Paragraph::new("").wrap(Wrap { trim: true }),
+            Paragraph::new("").wrap(Wrap::new().trim(false)),
+            Paragraph::new("").wrap(Wrap::new().trim(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines([" "]));
             test_case(&paragraph, &Buffer::with_lines(["          "]));
@@ -560,8 +1356,8 @@ mod tests {
         let text = "Hello, world!";
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new().trim(false)),
+            Paragraph::new(text).wrap(Wrap::new().trim(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines(["Hello, world!  "]));
             test_case(&paragraph, &Buffer::with_lines(["Hello, world!"]));
@@ -581,8 +1377,8 @@ mod tests {
         let text = "This is a\nmultiline\nparagraph.";
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new().trim(false)),
+            Paragraph::new(text).wrap(Wrap::new().trim(true)),
         ] {
             test_case(
                 &paragraph,
@@ -611,8 +1407,8 @@ mod tests {
         // can truncate this without triggering the typos linter.
         let text = "Hello, worlds!";
         let truncated_paragraph = Paragraph::new(text).block(Block::bordered().title("Title"));
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             #[rustfmt::skip]
@@ -722,8 +1518,8 @@ mod tests {
     #[test]
     fn test_render_paragraph_with_word_wrap() {
         let text = "This is a long line of text that should wrap      and contains a superultramegagigalong word.";
-        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
-        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap::new().trim(true));
 
         test_case(
             &wrapped_paragraph,
@@ -784,8 +1580,8 @@ mod tests {
             .into_iter()
             .map(Line::from)
             .collect();
-        let paragraph = Paragraph::new(text.clone()).wrap(Wrap { trim: false });
-        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let paragraph = Paragraph::new(text.clone()).wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap::new().trim(true));
 
         test_case(
             &paragraph,
@@ -824,8 +1620,8 @@ mod tests {
     fn test_render_paragraph_with_left_alignment() {
         let text = "Hello, world!";
         let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Left);
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines(["Hello, world!  "]));
@@ -847,8 +1643,8 @@ mod tests {
     fn test_render_paragraph_with_center_alignment() {
         let text = "Hello, world!";
         let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Center);
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines([" Hello, world! "]));
@@ -868,12 +1664,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_alignment_precedence() {
+        let lines = vec![
+            Line::from("line").left_aligned(),
+            Line::from("text"),
+            Line::from("line").right_aligned(),
+        ];
+        let paragraph = Paragraph::new(Text::from(lines.clone()).centered()).right_aligned();
+        let wrapped_paragraph = paragraph.clone().wrap(Wrap::new().trim(true));
+        for paragraph in [&paragraph, &wrapped_paragraph] {
+            test_case(
+                paragraph,
+                &Buffer::with_lines(["line    ", "  text  ", "    line"]),
+            );
+        }
+
+        // without a text alignment, the lines fall back to the paragraph alignment
+        let paragraph = Paragraph::new(lines).right_aligned();
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["line    ", "    text", "    line"]),
+        );
+    }
+
     #[test]
     fn test_render_paragraph_with_right_alignment() {
         let text = "Hello, world!";
         let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Right);
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines(["  Hello, world!"]));
@@ -895,8 +1715,8 @@ mod tests {
     fn test_render_paragraph_with_scroll_offset() {
         let text = "This is a\ncool\nmultiline\nparagraph.";
         let truncated_paragraph = Paragraph::new(text).scroll((2, 0));
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new().trim(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(
@@ -923,8 +1743,8 @@ mod tests {
 
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new().trim(false)),
+            Paragraph::new(text).wrap(Wrap::new().trim(true)),
         ] {
             test_case(&paragraph, &Buffer::empty(area));
             test_case(&paragraph.clone().scroll((2, 4)), &Buffer::empty(area));
@@ -938,8 +1758,8 @@ mod tests {
 
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new().trim(false)),
+            Paragraph::new(text).wrap(Wrap::new().trim(true)),
         ] {
             test_case(&paragraph, &Buffer::empty(area));
             test_case(&paragraph.clone().scroll((2, 4)), &Buffer::empty(area));
@@ -965,8 +1785,8 @@ mod tests {
 
         for paragraph in [
             Paragraph::new(text.clone()),
-            Paragraph::new(text.clone()).wrap(Wrap { trim: false }),
-            Paragraph::new(text.clone()).wrap(Wrap { trim: true }),
+            Paragraph::new(text.clone()).wrap(Wrap::new().trim(false)),
+            Paragraph::new(text.clone()).wrap(Wrap::new().trim(true)),
         ] {
             test_case(
                 &paragraph.style(Style::default().bg(Color::Green)),
@@ -980,8 +1800,8 @@ mod tests {
         let text = "Hello, <world>!";
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new().trim(false)),
+            Paragraph::new(text).wrap(Wrap::new().trim(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines(["Hello, <world>!"]));
             test_case(&paragraph, &Buffer::with_lines(["Hello, <world>!     "]));
@@ -1000,8 +1820,8 @@ mod tests {
     fn test_render_paragraph_with_unicode_characters() {
         let text = "こんにちは, 世界! 😃";
         let truncated_paragraph = Paragraph::new(text);
-        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
-        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap::new().trim(false));
+        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap::new().trim(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines(["こんにちは, 世界! 😃"]));
@@ -1025,6 +1845,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_wrapped_rtl() {
+        // "שלום עולם" (hello world), each letter is one column wide
+        let paragraph = Paragraph::new("שלום עולם").wrap(Wrap::new().trim(true).rtl(true));
+        test_case(&paragraph, &Buffer::with_lines([" םלוע םולש"]));
+        test_case(&paragraph, &Buffer::with_lines(["  םולש", "  םלוע"]));
+        test_case(
+            &paragraph.clone().centered(),
+            &Buffer::with_lines([" םולש ", " םלוע "]),
+        );
+        test_case(
+            &paragraph.right_aligned(),
+            &Buffer::with_lines(["םולש  ", "םלוע  "]),
+        );
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -1037,15 +1873,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn locate_offset_wrapped() {
+        let paragraph = Paragraph::new("Hello World\nHi").wrap(Wrap::new().trim(true));
+        assert_eq!(paragraph.locate_offset(0, 8), (0, 0));
+        assert_eq!(paragraph.locate_offset(4, 8), (0, 4));
+        // the whitespace trimmed at the line break is located after the previous word
+        assert_eq!(paragraph.locate_offset(5, 8), (0, 5));
+        assert_eq!(paragraph.locate_offset(6, 8), (1, 0));
+        // the end of the first line
+        assert_eq!(paragraph.locate_offset(11, 8), (1, 5));
+        assert_eq!(paragraph.locate_offset(12, 8), (2, 0));
+        assert_eq!(paragraph.locate_offset(14, 8), (2, 2));
+        assert_eq!(paragraph.locate_offset(100, 8), (2, 2));
+        assert_eq!(paragraph.locate_offset(4, 0), (0, 0));
+    }
+
+    #[test]
+    fn locate_offset_aligned() {
+        let paragraph = Paragraph::new("ab\ncdef").centered();
+        assert_eq!(paragraph.locate_offset(1, 6), (0, 3));
+        assert_eq!(paragraph.locate_offset(5, 6), (1, 3));
+    }
+
+    #[test]
+    fn locate_offset_truncated() {
+        let paragraph = Paragraph::new("abcdef");
+        assert_eq!(paragraph.locate_offset(2, 3), (0, 2));
+        assert_eq!(paragraph.locate_offset(5, 3), (0, 3));
+    }
+
+    #[test]
+    fn locate_offset_spans_and_empty_lines() {
+        let paragraph = Paragraph::new(Text::from(vec![
+            Line::from(vec![Span::raw("写"), Span::raw("ab")]),
+            Line::default(),
+            Line::from("c"),
+        ]));
+        assert_eq!(paragraph.locate_offset(1, 10), (0, 2));
+        assert_eq!(paragraph.locate_offset(2, 10), (0, 3));
+        assert_eq!(paragraph.locate_offset(4, 10), (1, 0));
+        assert_eq!(paragraph.locate_offset(5, 10), (2, 0));
+        assert_eq!(paragraph.locate_offset(6, 10), (2, 1));
+        assert_eq!(Paragraph::new("").locate_offset(3, 10), (0, 0));
+    }
+
     #[test]
     fn widgets_paragraph_count_rendered_lines() {
         let paragraph = Paragraph::new("Hello World");
         assert_eq!(paragraph.line_count(20), 1);
         assert_eq!(paragraph.line_count(10), 1);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: false });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new().trim(false));
         assert_eq!(paragraph.line_count(20), 1);
         assert_eq!(paragraph.line_count(10), 2);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new().trim(true));
         assert_eq!(paragraph.line_count(20), 1);
         assert_eq!(paragraph.line_count(10), 2);
 
@@ -1053,10 +1934,10 @@ mod tests {
         let paragraph = Paragraph::new(text.trim());
         assert_eq!(paragraph.line_count(11), 1);
         assert_eq!(paragraph.line_count(6), 1);
-        let paragraph = paragraph.wrap(Wrap { trim: false });
+        let paragraph = paragraph.wrap(Wrap::new().trim(false));
         assert_eq!(paragraph.line_count(11), 100);
         assert_eq!(paragraph.line_count(6), 200);
-        let paragraph = paragraph.wrap(Wrap { trim: true });
+        let paragraph = paragraph.wrap(Wrap::new().trim(true));
         assert_eq!(paragraph.line_count(11), 100);
         assert_eq!(paragraph.line_count(6), 200);
     }
@@ -1089,12 +1970,12 @@ mod tests {
         assert_eq!(paragraph.line_count(10), 3);
 
         let block = Block::bordered();
-        let paragraph = paragraph.block(block).wrap(Wrap { trim: true });
+        let paragraph = paragraph.block(block).wrap(Wrap::new().trim(true));
         assert_eq!(paragraph.line_count(20), 3);
         assert_eq!(paragraph.line_count(10), 4);
 
         let block = Block::bordered();
-        let paragraph = paragraph.block(block).wrap(Wrap { trim: false });
+        let paragraph = paragraph.block(block).wrap(Wrap::new().trim(false));
         assert_eq!(paragraph.line_count(20), 3);
         assert_eq!(paragraph.line_count(10), 4);
 
@@ -1128,20 +2009,241 @@ mod tests {
     fn widgets_paragraph_line_width() {
         let paragraph = Paragraph::new("Hello World");
         assert_eq!(paragraph.line_width(), 11);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: false });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new().trim(false));
         assert_eq!(paragraph.line_width(), 11);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new().trim(true));
         assert_eq!(paragraph.line_width(), 11);
 
         let text = "Hello World ".repeat(100);
         let paragraph = Paragraph::new(text);
         assert_eq!(paragraph.line_width(), 1200);
-        let paragraph = paragraph.wrap(Wrap { trim: false });
+        let paragraph = paragraph.wrap(Wrap::new().trim(false));
         assert_eq!(paragraph.line_width(), 1200);
-        let paragraph = paragraph.wrap(Wrap { trim: true });
+        let paragraph = paragraph.wrap(Wrap::new().trim(true));
         assert_eq!(paragraph.line_width(), 1200);
     }
 
+    #[test]
+    fn line_numbers() {
+        let paragraph = Paragraph::new("a\nb\nc").line_numbers(LineNumbers::default());
+        test_case(&paragraph, &Buffer::with_lines(["1 a  ", "2 b  ", "3 c  "]));
+    }
+
+    #[test]
+    fn line_numbers_start() {
+        let paragraph = Paragraph::new("a\nb").line_numbers(LineNumbers {
+            start: 9,
+            style: Style::new(),
+        });
+        test_case(&paragraph, &Buffer::with_lines([" 9 a", "10 b"]));
+    }
+
+    #[test]
+    fn line_numbers_style() {
+        let paragraph = Paragraph::new("a\nb").line_numbers(LineNumbers {
+            start: 1,
+            style: Style::new().dark_gray(),
+        });
+        let mut expected = Buffer::with_lines(["1 a ", "2 b ", "    "]);
+        expected.set_style(Rect::new(0, 0, 1, 3), Style::new().dark_gray());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn line_numbers_wrapped() {
+        let paragraph = Paragraph::new("Hello World\nHi")
+            .wrap(Wrap::new().trim(true))
+            .line_numbers(LineNumbers::default());
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["1 Hello ", "  World ", "2 Hi    "]),
+        );
+        test_case(
+            &paragraph.clone().scroll((1, 0)),
+            &Buffer::with_lines(["  World ", "2 Hi    ", "        "]),
+        );
+        test_case(
+            &paragraph.scroll((2, 0)),
+            &Buffer::with_lines(["2 Hi    ", "        ", "        "]),
+        );
+    }
+
+    #[test]
+    fn line_numbers_wrapped_with_empty_line() {
+        let paragraph = Paragraph::new("Hello World\n\nHi")
+            .wrap(Wrap::new().trim(true))
+            .line_numbers(LineNumbers::default());
+        let expected = Buffer::with_lines(["1 Hello ", "  World ", "2       ", "3 Hi    "]);
+        test_case(&paragraph, &expected);
+
+        let area = Rect::new(0, 0, 8, 4);
+        let mut buf = Buffer::empty(area);
+        paragraph
+            .cached()
+            .render(area, &mut buf, &mut ParagraphState::default());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn line_numbers_with_block() {
+        let paragraph = Paragraph::new("a\nb")
+            .block(Block::bordered())
+            .line_numbers(LineNumbers::default());
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["┌───┐", "│1 a│", "│2 b│", "└───┘"]),
+        );
+    }
+
+    #[test]
+    fn line_numbers_wider_than_area() {
+        let paragraph = Paragraph::new("a\nb").line_numbers(LineNumbers {
+            start: 100,
+            style: Style::new(),
+        });
+        test_case(&paragraph, &Buffer::with_lines(["   ", "   "]));
+    }
+
+    #[test]
+    fn line_numbers_size() {
+        let paragraph = Paragraph::new("Hello World")
+            .wrap(Wrap::new().trim(true))
+            .line_numbers(LineNumbers::default());
+        assert_eq!(paragraph.line_width(), 13);
+        assert_eq!(paragraph.line_count(20), 1);
+        assert_eq!(paragraph.line_count(8), 2);
+        assert_eq!(
+            paragraph.size_hint_for_width(8),
+            (Constraint::Length(7), Constraint::Length(2))
+        );
+    }
+
+    #[test]
+    fn render_cached_line_numbers() {
+        let text = "The quick brown fox jumps over the lazy dog.\nThe end.";
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap::new().trim(true))
+            .line_numbers(LineNumbers::default());
+        let area = Rect::new(0, 0, 12, 3);
+        let mut state = ParagraphState::default();
+        for scroll in 0..6 {
+            let paragraph = paragraph.clone().scroll((scroll, 0));
+            let mut expected = Buffer::empty(area);
+            paragraph.clone().render(area, &mut expected);
+            let mut buf = Buffer::empty(area);
+            paragraph.cached().render(area, &mut buf, &mut state);
+            assert_eq!(buf, expected, "scroll: {scroll}");
+        }
+    }
+
+    #[test]
+    fn render_cached() {
+        let text = "The quick brown fox jumps over the lazy dog.\nThe end.";
+        let paragraph = Paragraph::new(text)
+            .block(Block::bordered())
+            .wrap(Wrap::new().trim(true));
+        let area = Rect::new(0, 0, 12, 5);
+        let mut state = ParagraphState::default();
+        for scroll in 0..6 {
+            let paragraph = paragraph.clone().scroll((scroll, 0));
+            let mut expected = Buffer::empty(area);
+            paragraph.clone().render(area, &mut expected);
+            let mut buf = Buffer::empty(area);
+            paragraph.cached().render(area, &mut buf, &mut state);
+            assert_eq!(buf, expected, "scroll: {scroll}");
+        }
+    }
+
+    #[test]
+    fn render_cached_reuses_wrapped_lines() {
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new().trim(true));
+        let mut state = ParagraphState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        paragraph
+            .clone()
+            .cached()
+            .render(buf.area, &mut buf, &mut state);
+        let cache = state.cache.clone().expect("lines should be cached");
+        assert_eq!(cache.lines.len(), 2);
+
+        // scrolling reuses the cached lines
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        paragraph
+            .clone()
+            .scroll((1, 0))
+            .cached()
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.cache, Some(cache.clone()));
+        assert_eq!(buf, Buffer::with_lines(["World", "     "]));
+
+        // changing the width, the text or its style wraps the lines again
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+        paragraph.cached().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.cache.as_ref().map(|cache| cache.lines.len()), Some(1));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+        Paragraph::new("Hello World".red())
+            .wrap(Wrap::new().trim(true))
+            .cached()
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["Hello World", "           "]);
+        expected.set_style(Rect::new(0, 0, 11, 1), Style::new().red());
+        assert_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+        Paragraph::new("Hi")
+            .wrap(Wrap::new().trim(true))
+            .cached()
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["Hi         ", "           "]));
+    }
+
+    #[test]
+    fn render_cached_without_wrap() {
+        let mut state = ParagraphState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Paragraph::new("Hello World")
+            .cached()
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["Hello"]));
+        assert_eq!(state, ParagraphState::default());
+    }
+
+    #[test]
+    fn size_hint() {
+        let paragraph = Paragraph::new("Hello World\nhi");
+        assert_eq!(
+            paragraph.size_hint(),
+            (Constraint::Length(11), Constraint::Length(2))
+        );
+
+        let paragraph = paragraph.block(Block::bordered());
+        assert_eq!(
+            paragraph.size_hint(),
+            (Constraint::Length(13), Constraint::Length(4))
+        );
+    }
+
+    #[test]
+    fn size_hint_for_width() {
+        let paragraph = Paragraph::new("Hello World\nhi").block(Block::bordered());
+        // without wrapping the lines are truncated
+        assert_eq!(
+            paragraph.size_hint_for_width(8),
+            (Constraint::Length(8), Constraint::Length(4))
+        );
+
+        let paragraph = paragraph.wrap(Wrap::new().trim(true));
+        assert_eq!(
+            paragraph.size_hint_for_width(8),
+            (Constraint::Length(7), Constraint::Length(5))
+        );
+        assert_eq!(
+            paragraph.size_hint_for_width(20),
+            (Constraint::Length(13), Constraint::Length(4))
+        );
+    }
+
     #[test]
     fn widgets_paragraph_line_width_accounts_for_block() {
         let block = Block::bordered();
@@ -1155,13 +2257,13 @@ mod tests {
         let block = Block::new().borders(Borders::LEFT);
         let paragraph = Paragraph::new("Hello World")
             .block(block)
-            .wrap(Wrap { trim: true });
+            .wrap(Wrap::new().trim(true));
         assert_eq!(paragraph.line_width(), 12);
 
         let block = Block::new().borders(Borders::LEFT);
         let paragraph = Paragraph::new("Hello World")
             .block(block)
-            .wrap(Wrap { trim: false });
+            .wrap(Wrap::new().trim(false));
         assert_eq!(paragraph.line_width(), 12);
     }
 
@@ -1183,6 +2285,43 @@ mod tests {
         assert_eq!(p.alignment, Alignment::Right);
     }
 
+    #[test]
+    fn render_without_bg_keeps_existing_bg() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        buf.set_style(buf.area, Style::new().on_blue());
+        Paragraph::new("Hello")
+            .style(Style::new().red())
+            .block(Block::bordered())
+            .render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["┌────────┐", "│Hello   │", "└────────┘"]);
+        expected.set_style(expected.area, Style::new().red().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn text_wrap() {
+        let text = Text::from(vec![
+            Line::from(vec!["hello ".red(), "big ".into(), "world".blue()]).centered(),
+            Line::from("short").bold(),
+        ]);
+        let wrapped = text.wrap(9, Wrap::new().trim(true));
+        assert_eq!(
+            wrapped,
+            Text::from(vec![
+                Line::from(vec!["hello ".red(), "big".into()]).centered(),
+                Line::from("world".blue()).centered(),
+                Line::from("short").bold(),
+            ])
+        );
+    }
+
+    #[test]
+    fn text_wrap_zero_width() {
+        let wrapped = Text::from("hello").wrap(0, Wrap::default());
+        assert_eq!(wrapped.lines, []);
+    }
+
     /// Regression test for <https://github.com/ratatui/ratatui/issues/990>
     ///
     /// This test ensures that paragraphs with a block and styled text are rendered correctly.
@@ -1203,4 +2342,37 @@ mod tests {
         expected.set_style(Rect::new(1, 1, 11, 1), Style::default().fg(Color::Green));
         assert_eq!(buf, expected);
     }
+
+    #[cfg(feature = "hyperlinks")]
+    #[test]
+    fn render_hyperlinks() {
+        let text = Line::from(vec![
+            Span::raw("see "),
+            Span::raw("ratatui docs").hyperlink("https://ratatui.rs"),
+        ]);
+        let paragraph = Paragraph::new(text).wrap(Wrap::default());
+        let mut state = ParagraphState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        let mut cached_buf = buf.clone();
+        paragraph.clone().render(buf.area, &mut buf);
+        paragraph
+            .cached()
+            .render(cached_buf.area, &mut cached_buf, &mut state);
+
+        let link = Some("https://ratatui.rs");
+        let hyperlinks: Vec<_> = buf
+            .content()
+            .iter()
+            .map(|cell| cell.hyperlink.as_deref())
+            .collect();
+        #[rustfmt::skip]
+        assert_eq!(
+            hyperlinks,
+            [
+                None, None, None, None, link, link, link, link, link, link, link, None,
+                link, link, link, link, None, None, None, None, None, None, None, None,
+            ]
+        );
+        assert_eq!(cached_buf, buf);
+    }
 }
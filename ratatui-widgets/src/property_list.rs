@@ -0,0 +1,441 @@
+//! The [`PropertyList`] widget is used to display a list of key/value pairs with aligned labels.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Styled},
+    text::Line,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::block::{Block, BlockExt};
+
+/// A widget to display a list of labelled values.
+///
+/// Each property is a pair of [`Line`]s: a label and a value. The labels are right-aligned to the
+/// width of the widest label and are followed by a separator (`": "` by default) and the value.
+///
+/// ```text
+///     Name: ratatui
+///  Version: 0.30.0
+/// Homepage: https://ratatui.rs
+/// ```
+///
+/// Each property takes exactly one row. Properties that do not fit in the render area can be
+/// scrolled into view by rendering the widget as a [`StatefulWidget`] with a
+/// [`PropertyListState`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::Rect,
+///     style::{Style, Stylize},
+///     widgets::{Block, PropertyList},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let properties = PropertyList::new([
+///     ("Name", "ratatui"),
+///     ("Version", "0.30.0"),
+///     ("Homepage", "https://ratatui.rs"),
+/// ])
+/// .block(Block::bordered().title("About"))
+/// .label_style(Style::new().bold())
+/// .separator(" = ");
+///
+/// frame.render_widget(properties, area);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct PropertyList<'a> {
+    /// The properties to display as label/value pairs
+    items: Vec<(Line<'a>, Line<'a>)>,
+    /// Optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Base style of the widget
+    style: Style,
+    /// Style applied to the labels
+    label_style: Style,
+    /// Style applied to the values
+    value_style: Style,
+    /// Text displayed between each label and value
+    separator: Line<'a>,
+}
+
+/// State of the [`PropertyList`] widget
+///
+/// This state holds the index of the first property to be displayed and can be used to scroll
+/// through properties that do not fit in the render area.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{PropertyList, PropertyListState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let properties = PropertyList::new([("Name", "ratatui"), ("Version", "0.30.0")]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = PropertyListState::default().with_offset(1);
+///
+/// frame.render_stateful_widget(properties, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropertyListState {
+    offset: usize,
+}
+
+impl<'a> PropertyList<'a> {
+    /// Creates a new `PropertyList` from an iterator of label/value pairs.
+    ///
+    /// Both the label and the value can be anything that converts into a [`Line`], such as a
+    /// `&str`, a `String` or a [`Span`](ratatui_core::text::Span).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{text::Line, widgets::PropertyList};
+    ///
+    /// let properties = PropertyList::new([("Name", "ratatui")]);
+    /// let properties = PropertyList::new(vec![(Line::from("Name"), Line::from("ratatui"))]);
+    /// ```
+    pub fn new<T, L, V>(items: T) -> Self
+    where
+        T: IntoIterator<Item = (L, V)>,
+        L: Into<Line<'a>>,
+        V: Into<Line<'a>>,
+    {
+        Self {
+            items: items
+                .into_iter()
+                .map(|(label, value)| (label.into(), value.into()))
+                .collect(),
+            separator: Line::raw(": "),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the properties to display.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<T, L, V>(mut self, items: T) -> Self
+    where
+        T: IntoIterator<Item = (L, V)>,
+        L: Into<Line<'a>>,
+        V: Into<Line<'a>>,
+    {
+        self.items = items
+            .into_iter()
+            .map(|(label, value)| (label.into(), value.into()))
+            .collect();
+        self
+    }
+
+    /// Wraps the property list with the given block.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the labels.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Sets the style of the values.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.value_style = style.into();
+        self
+    }
+
+    /// Sets the text displayed between each label and its value.
+    ///
+    /// Defaults to `": "` when created with [`PropertyList::new`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator<T: Into<Line<'a>>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Returns the number of properties in the list.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the list contains no properties.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the width of the widest label.
+    fn label_width(&self) -> u16 {
+        self.items
+            .iter()
+            .map(|(label, _)| label.width())
+            .max()
+            .unwrap_or_default()
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+}
+
+impl PropertyListState {
+    /// Sets the index of the first property to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Index of the first property to be displayed
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first property to be displayed
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Scrolls down by the given number of properties
+    pub fn scroll_down_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_add(amount as usize);
+    }
+
+    /// Scrolls up by the given number of properties
+    pub fn scroll_up_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_sub(amount as usize);
+    }
+}
+
+impl Styled for PropertyList<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for PropertyList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &PropertyList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = PropertyListState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for PropertyList<'_> {
+    type State = PropertyListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &PropertyList<'_> {
+    type State = PropertyListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        // keep the last page of properties visible when scrolled past the end
+        let height = inner.height as usize;
+        state.offset = state.offset.min(self.items.len().saturating_sub(height));
+
+        let separator_width = self.separator.width().try_into().unwrap_or(u16::MAX);
+        let [label_column, separator_column, value_column] = Layout::horizontal([
+            Constraint::Length(self.label_width()),
+            Constraint::Length(separator_width),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+
+        for ((label, value), Rect { y, .. }) in
+            self.items.iter().skip(state.offset).zip(inner.rows())
+        {
+            let label_area = Rect {
+                y,
+                height: 1,
+                ..label_column
+            };
+            let label_width = u16::try_from(label.width()).unwrap_or(u16::MAX);
+            let label_area = Rect {
+                x: label_area
+                    .right()
+                    .saturating_sub(label_width)
+                    .max(label_area.x),
+                width: label_width.min(label_area.width),
+                ..label_area
+            };
+            buf.set_style(label_area, self.label_style);
+            label.render(label_area, buf);
+            Widget::render(
+                &self.separator,
+                Rect {
+                    y,
+                    height: 1,
+                    ..separator_column
+                },
+                buf,
+            );
+            let value_area = Rect {
+                y,
+                height: 1,
+                ..value_column
+            };
+            buf.set_style(value_area, self.value_style);
+            value.render(value_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    fn properties() -> PropertyList<'static> {
+        PropertyList::new([("Name", "ratatui"), ("Version", "0.30"), ("Id", "42")])
+    }
+
+    #[test]
+    fn new() {
+        let list = PropertyList::new([("a", "b")]);
+        assert_eq!(list.items, vec![(Line::from("a"), Line::from("b"))]);
+        assert_eq!(list.separator, Line::from(": "));
+        assert_eq!(list.len(), 1);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn render_aligns_labels() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        Widget::render(properties(), buf.area, &mut buf);
+        let expected =
+            Buffer::with_lines(["   Name: ratatui", "Version: 0.30   ", "     Id: 42     "]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_with_separator_and_block() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 18, 5));
+        let list = properties().separator(" = ").block(Block::bordered());
+        Widget::render(list, buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "┌────────────────┐",
+            "│   Name = ratatu│",
+            "│Version = 0.30  │",
+            "│     Id = 42    │",
+            "└────────────────┘",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_scrolled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let mut state = PropertyListState::default().with_offset(1);
+        StatefulWidget::render(properties(), buf.area, &mut buf, &mut state);
+        let expected = Buffer::with_lines([
+            "Version: 0.30   ", //
+            "     Id: 42     ",
+        ]);
+        assert_eq!(buf, expected);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_clamps_offset() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let mut state = PropertyListState::default().with_offset(10);
+        StatefulWidget::render(properties(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_styles() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let list = PropertyList::new([("ab", "cd")])
+            .label_style(Color::Red)
+            .value_style(Color::Blue);
+        Widget::render(list, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["ab: cd  "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 0, 4, 1), Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn scroll() {
+        let mut state = PropertyListState::default();
+        state.scroll_down_by(3);
+        assert_eq!(state.offset(), 3);
+        state.scroll_up_by(5);
+        assert_eq!(state.offset(), 0);
+        *state.offset_mut() = 2;
+        assert_eq!(state.offset(), 2);
+    }
+}
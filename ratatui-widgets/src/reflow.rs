@@ -20,6 +20,8 @@ pub struct WrappedLine<'lend, 'text> {
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
+    /// The index of the input line when this is the first line wrapped from it
+    pub first_row_of: Option<usize>,
 }
 
 /// A state machine that wraps lines on word boundaries.
@@ -40,6 +42,14 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Keeps the whitespace at the end of wrapped lines
+    preserve_trailing: bool,
+    /// Lays out the wrapped lines from right to left
+    rtl: bool,
+    /// The number of lines taken from `input_lines`
+    taken_lines: usize,
+    /// The index of the last input line, until its first wrapped line is emitted
+    first_row_of: Option<usize>,
 
     // These are cached allocations that hold no state across next_line invocations
     pending_word: Vec<StyledGrapheme<'a>>,
@@ -53,7 +63,7 @@ where
     I: Iterator<Item = StyledGrapheme<'a>>,
 {
     /// Create a new `WordWrapper` with the given lines and maximum line width.
-    pub const fn new(lines: O, max_line_width: u16, trim: bool) -> Self {
+    pub const fn new(lines: O, max_line_width: u16, trim: bool, preserve_trailing: bool) -> Self {
         Self {
             input_lines: lines,
             max_line_width,
@@ -61,6 +71,10 @@ where
             current_alignment: Alignment::Left,
             current_line: vec![],
             trim,
+            preserve_trailing,
+            rtl: false,
+            taken_lines: 0,
+            first_row_of: None,
 
             pending_word: Vec::new(),
             pending_line_pool: Vec::new(),
@@ -68,6 +82,14 @@ where
         }
     }
 
+    /// Set whether the wrapped lines are laid out from right to left.
+    ///
+    /// The lines are wrapped as usual, then the graphemes of each wrapped line are reversed and
+    /// its alignment is mirrored, so that the start of the line is on the right.
+    pub fn set_rtl(&mut self, rtl: bool) {
+        self.rtl = rtl;
+    }
+
     /// Split an input line (`line_symbols`) into wrapped lines
     /// and cache them to be emitted later
     fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
@@ -129,10 +151,11 @@ where
             if line_full || pending_word_overflow {
                 let mut remaining_width = u16::saturating_sub(self.max_line_width, line_width);
 
-                self.wrapped_lines.push_back(mem::take(&mut pending_line));
+                let mut wrapped_line = mem::take(&mut pending_line);
                 line_width = 0;
 
-                // remove whitespace up to the end of line
+                // remove whitespace up to the end of line, keeping it on the wrapped line if
+                // trailing whitespace is preserved
                 while let Some(grapheme) = self.pending_whitespace.front() {
                     let width = grapheme.symbol.width() as u16;
 
@@ -142,11 +165,15 @@ where
 
                     whitespace_width -= width;
                     remaining_width -= width;
-                    self.pending_whitespace.pop_front();
+                    let grapheme = self.pending_whitespace.pop_front();
+                    if self.preserve_trailing {
+                        wrapped_line.extend(grapheme);
+                    }
                 }
+                self.wrapped_lines.push_back(wrapped_line);
 
                 // don't count first whitespace toward next word
-                if is_whitespace && self.pending_whitespace.is_empty() {
+                if is_whitespace && self.pending_whitespace.is_empty() && !self.preserve_trailing {
                     continue;
                 }
             }
@@ -208,22 +235,35 @@ where
 
         loop {
             // emit next cached line if present
-            if let Some(line) = self.wrapped_lines.pop_front() {
+            if let Some(mut line) = self.wrapped_lines.pop_front() {
                 let line_width = line
                     .iter()
                     .map(|grapheme| grapheme.symbol.width() as u16)
                     .sum();
+                let alignment = if self.rtl {
+                    line.reverse();
+                    match self.current_alignment {
+                        Alignment::Left => Alignment::Right,
+                        Alignment::Center => Alignment::Center,
+                        Alignment::Right => Alignment::Left,
+                    }
+                } else {
+                    self.current_alignment
+                };
 
                 self.replace_current_line(line);
                 return Some(WrappedLine {
                     graphemes: &self.current_line,
                     width: line_width,
-                    alignment: self.current_alignment,
+                    alignment,
+                    first_row_of: self.first_row_of.take(),
                 });
             }
 
             // otherwise, process pending wrapped lines from input
             let (line_symbols, line_alignment) = self.input_lines.next()?;
+            self.first_row_of = Some(self.taken_lines);
+            self.taken_lines += 1;
             self.current_alignment = line_alignment;
             self.process_input(line_symbols);
         }
@@ -246,6 +286,8 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Record the offset to skip render
     horizontal_offset: u16,
+    /// The number of lines taken from `input_lines`
+    taken_lines: usize,
 }
 
 impl<'a, O, I> LineTruncator<'a, O, I>
@@ -260,6 +302,7 @@ where
             max_line_width,
             horizontal_offset: 0,
             current_line: vec![],
+            taken_lines: 0,
         }
     }
 
@@ -287,9 +330,15 @@ where
         let mut current_alignment = Alignment::Left;
         if let Some((current_line, alignment)) = &mut self.input_lines.next() {
             lines_exhausted = false;
+            self.taken_lines += 1;
             current_alignment = *alignment;
 
-            for StyledGrapheme { symbol, style } in current_line {
+            for StyledGrapheme {
+                symbol,
+                style,
+                link,
+            } in current_line
+            {
                 // Ignore characters wider that the total max width.
                 if symbol.width() as u16 > self.max_line_width {
                     continue;
@@ -314,7 +363,11 @@ where
                     }
                 };
                 current_line_width += symbol.width() as u16;
-                self.current_line.push(StyledGrapheme { symbol, style });
+                self.current_line.push(StyledGrapheme {
+                    symbol,
+                    style,
+                    link,
+                });
             }
         }
 
@@ -325,6 +378,7 @@ where
                 graphemes: &self.current_line,
                 width: current_line_width,
                 alignment: current_alignment,
+                first_row_of: Some(self.taken_lines - 1),
             })
         }
     }
@@ -359,6 +413,8 @@ mod tests {
     #[derive(Clone, Copy)]
     enum Composer {
         WordWrapper { trim: bool },
+        WordWrapperPreserveTrailing { trim: bool },
+        WordWrapperRtl { trim: bool },
         LineTruncator,
     }
 
@@ -378,7 +434,15 @@ mod tests {
 
         let mut composer: Box<dyn LineComposer> = match which {
             Composer::WordWrapper { trim } => {
-                Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
+                Box::new(WordWrapper::new(styled_lines, text_area_width, trim, false))
+            }
+            Composer::WordWrapperPreserveTrailing { trim } => {
+                Box::new(WordWrapper::new(styled_lines, text_area_width, trim, true))
+            }
+            Composer::WordWrapperRtl { trim } => {
+                let mut composer = WordWrapper::new(styled_lines, text_area_width, trim, false);
+                composer.set_rtl(true);
+                Box::new(composer)
             }
             Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
         };
@@ -389,6 +453,7 @@ mod tests {
             graphemes,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = graphemes
@@ -678,6 +743,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn line_composer_word_wrapper_preserve_trailing() {
+        let width = 5;
+        let text = "AAAA  BBBB";
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: false }, text, width);
+        let (preserved, widths, _) = run_composer(
+            Composer::WordWrapperPreserveTrailing { trim: false },
+            text,
+            width,
+        );
+        let (preserved_trimmed, _, _) = run_composer(
+            Composer::WordWrapperPreserveTrailing { trim: true },
+            text,
+            width,
+        );
+        assert_eq!(word_wrapper, ["AAAA", "BBBB"]);
+        assert_eq!(preserved, ["AAAA ", " BBBB"]);
+        assert_eq!(widths, [5, 5]);
+        assert_eq!(preserved_trimmed, ["AAAA ", "BBBB"]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_preserve_trailing_at_end() {
+        let width = 5;
+        let text = "AAAA   ";
+        let (preserved, _, _) = run_composer(
+            Composer::WordWrapperPreserveTrailing { trim: false },
+            text,
+            width,
+        );
+        assert_eq!(preserved, ["AAAA ", "  "]);
+        assert_eq!(preserved.concat(), text);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_preserve_trailing_indentation() {
+        let width = 20;
+        let text = "AAAAAAAAAAAAAAAAAAAA    AAA";
+        let (preserved, _, _) = run_composer(
+            Composer::WordWrapperPreserveTrailing { trim: false },
+            text,
+            width,
+        );
+        assert_eq!(preserved, ["AAAAAAAAAAAAAAAAAAAA", "    AAA"]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_rtl() {
+        let width = 6;
+        // each letter is one column wide
+        let text = "שלום עולם יפה";
+        let (rtl, widths, alignments) =
+            run_composer(Composer::WordWrapperRtl { trim: true }, text, width);
+        assert_eq!(rtl, ["םולש", "םלוע", "הפי"]);
+        assert_eq!(widths, [4, 4, 3]);
+        assert_eq!(alignments, [Alignment::Right; 3]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_rtl_mirrors_alignment() {
+        let text = Text::from(vec![
+            Line::from("אב").right_aligned(),
+            Line::from("גד").centered(),
+        ]);
+        let (rtl, _, alignments) = run_composer(Composer::WordWrapperRtl { trim: true }, text, 4);
+        assert_eq!(rtl, ["בא", "דג"]);
+        assert_eq!(alignments, [Alignment::Left, Alignment::Center]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_rtl_wide_graphemes() {
+        let (rtl, widths, _) = run_composer(Composer::WordWrapperRtl { trim: true }, "コンピュ", 4);
+        assert_eq!(rtl, ["ンコ", "ュピ"]);
+        assert_eq!(widths, [4, 4]);
+    }
+
     #[test]
     fn line_composer_zero_width_at_end() {
         let width = 3;
@@ -724,4 +865,31 @@ mod tests {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+
+    #[test]
+    fn line_composer_first_row_of() {
+        let lines = [
+            Line::from("first line"),
+            Line::from(""),
+            Line::from("third"),
+        ];
+        let styled = || {
+            lines
+                .iter()
+                .map(|line| (line.styled_graphemes(Style::default()), Alignment::Left))
+        };
+        let mut first_rows = vec![];
+        let mut word_wrapper = WordWrapper::new(styled(), 6, true, false);
+        while let Some(line) = word_wrapper.next_line() {
+            first_rows.push(line.first_row_of);
+        }
+        assert_eq!(first_rows, [Some(0), None, Some(1), Some(2)]);
+
+        first_rows.clear();
+        let mut line_truncator = LineTruncator::new(styled(), 6);
+        while let Some(line) = line_truncator.next_line() {
+            first_rows.push(line.first_row_of);
+        }
+        assert_eq!(first_rows, [Some(0), Some(1), Some(2)]);
+    }
 }
@@ -96,6 +96,7 @@ pub struct Scrollbar<'a> {
     begin_style: Style,
     end_symbol: Option<&'a str>,
     end_style: Style,
+    min_thumb_size: u16,
 }
 
 /// This is the position of the scrollbar around a given area.
@@ -206,6 +207,7 @@ impl<'a> Scrollbar<'a> {
             begin_style: Style::new(),
             end_symbol: Some(symbols.end),
             end_style: Style::new(),
+            min_thumb_size: 1,
         }
     }
 
@@ -354,6 +356,31 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets the minimum length of the thumb, in cells.
+    ///
+    /// The length of the thumb is proportional to the part of the content that is visible in the
+    /// viewport, which makes it hard to see and grab when the content is much longer than the
+    /// viewport. When the proportional length is shorter than `min_thumb_size`, the thumb is drawn
+    /// with this length instead, and its position is scaled to the remaining part of the track so
+    /// that it still reaches both ends of the track at the first and last positions.
+    ///
+    /// The thumb is never longer than the track. Defaults to `1`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight).min_thumb_size(3);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_thumb_size(mut self, min_thumb_size: u16) -> Self {
+        self.min_thumb_size = min_thumb_size;
+        self
+    }
+
     /// Sets the symbols used for the various parts of the scrollbar from a [`Set`].
     ///
     /// ```text
@@ -473,6 +500,27 @@ impl ScrollbarState {
             .min(self.content_length.saturating_sub(1));
     }
 
+    /// Decrements the scroll position by a page of `page` items, ensuring it doesn't go below
+    /// zero.
+    ///
+    /// `page` is usually the number of items visible in the viewport, i.e. the
+    /// [`viewport_content_length`](ScrollbarState::viewport_content_length).
+    pub fn scroll_page_up(&mut self, page: usize) {
+        self.position = self.position.saturating_sub(page);
+    }
+
+    /// Increments the scroll position by a page of `page` items, ensuring it doesn't exceed the
+    /// length of the content.
+    ///
+    /// `page` is usually the number of items visible in the viewport, i.e. the
+    /// [`viewport_content_length`](ScrollbarState::viewport_content_length).
+    pub fn scroll_page_down(&mut self, page: usize) {
+        self.position = self
+            .position
+            .saturating_add(page)
+            .min(self.content_length.saturating_sub(1));
+    }
+
     /// Sets the scroll position to the start of the scrollable content.
     pub fn first(&mut self) {
         self.position = 0;
@@ -578,10 +626,25 @@ impl Scrollbar<'_> {
         // Make sure that the thumb is at least 1 cell long by ensuring that the start of the thumb
         // is less than the track_len. We use the positions instead of the sizes and use nearest
         // integer instead of floor / ceil to avoid problems caused by rounding errors.
-        let thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
+        let mut thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
         let thumb_end = thumb_end.round().clamp(0.0, track_length) as usize;
 
-        let thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
+        let mut thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
+
+        // A thumb that is enlarged to the minimum size doesn't fit in the proportional layout
+        // anymore, so its start is instead scaled to the part of the track that is not covered by
+        // the thumb. This keeps the thumb at the ends of the track only at the first and last
+        // positions, even when the content is barely longer than the viewport.
+        let min_thumb_length = usize::from(self.min_thumb_size).min(track_length as usize);
+        if thumb_length < min_thumb_length {
+            thumb_length = min_thumb_length;
+            let free_track_length = track_length as usize - thumb_length;
+            thumb_start = if max_position > 0.0 {
+                (start_position / max_position * free_track_length as f64).round() as usize
+            } else {
+                0
+            };
+        }
         let track_end_length = (track_length as usize).saturating_sub(thumb_start + thumb_length);
 
         (thumb_start, thumb_length, track_end_length)
@@ -1071,6 +1134,106 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
 
+    #[rstest]
+    #[case::position_0("###-------", 0)]
+    #[case::position_1("###-------", 1)]
+    #[case::position_25("--###-----", 25)]
+    #[case::position_50("----###---", 50)]
+    #[case::position_75("-----###--", 75)]
+    #[case::position_98("-------###", 98)]
+    #[case::position_99("-------###", 99)]
+    #[case::position_one_out_of_bounds("-------###", 100)]
+    fn min_thumb_size(
+        #[case] expected: &str,
+        #[case] position: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(100)
+            .position(position)
+            .viewport_content_length(2);
+        scrollbar_no_arrows
+            .min_thumb_size(3)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::min_shorter_than_proportional("#####-----", 2)]
+    #[case::min_longer_than_track("##########", 20)]
+    fn min_thumb_size_limits(
+        #[case] expected: &str,
+        #[case] min_thumb_size: u16,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(11).viewport_content_length(10);
+        scrollbar_no_arrows.min_thumb_size(min_thumb_size).render(
+            buffer.area,
+            &mut buffer,
+            &mut state,
+        );
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    /// When the content is barely longer than the viewport, the enlarged thumb must only touch the
+    /// end of the track once the last position is reached.
+    #[rstest]
+    #[case::position_0("####--", 0)]
+    #[case::position_1("-####-", 1)]
+    #[case::position_2("--####", 2)]
+    fn min_thumb_size_barely_overflowing(
+        #[case] expected: &str,
+        #[case] position: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = ScrollbarState::new(3)
+            .position(position)
+            .viewport_content_length(1);
+        scrollbar_no_arrows
+            .min_thumb_size(4)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn scroll_page() {
+        let mut state = ScrollbarState::new(25).position(3);
+        state.scroll_page_down(10);
+        assert_eq!(state.get_position(), 13);
+        state.scroll_page_down(10);
+        assert_eq!(state.get_position(), 23);
+        state.scroll_page_down(10);
+        assert_eq!(state.get_position(), 24);
+        state.scroll_page_up(10);
+        assert_eq!(state.get_position(), 14);
+        state.scroll_page_up(10);
+        assert_eq!(state.get_position(), 4);
+        state.scroll_page_up(10);
+        assert_eq!(state.get_position(), 0);
+    }
+
+    #[rstest]
+    #[case::position_0("##--------", 0)]
+    #[case::page_1("--##------", 1)]
+    #[case::page_2("----##----", 2)]
+    #[case::page_3("------##--", 3)]
+    #[case::page_4("--------##", 4)]
+    fn scroll_page_moves_thumb(
+        #[case] expected: &str,
+        #[case] pages: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(41).viewport_content_length(10);
+        for _ in 0..pages {
+            state.scroll_page_down(10);
+        }
+        scrollbar_no_arrows.render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
     #[rstest]
     #[case::scrollbar_height_0(10, 0)]
     #[case::scrollbar_width_0(0, 10)]
@@ -43,6 +43,7 @@ use crate::block::{Block, BlockExt};
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
 /// - [`Sparkline::max`] sets the maximum value of bars
+/// - [`Sparkline::scale`] sets how values are scaled to bar heights
 /// - [`Sparkline::direction`] sets the render direction
 ///
 /// # Examples
@@ -82,6 +83,8 @@ pub struct Sparkline<'a> {
     bar_set: symbols::bar::Set,
     /// The direction to render the sparkline, either from left to right, or from right to left
     direction: RenderDirection,
+    /// How the values are scaled to the height of the bars
+    scale: SparklineScale,
 }
 
 /// Defines the direction in which sparkline will be rendered.
@@ -96,6 +99,20 @@ pub enum RenderDirection {
     RightToLeft,
 }
 
+/// Defines how the values of a sparkline are scaled to the height of the bars.
+///
+/// See [`Sparkline::scale`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SparklineScale {
+    /// The height of a bar is proportional to its value
+    #[default]
+    Linear,
+    /// The height of a bar is proportional to the logarithm of its value
+    ///
+    /// Values of `0` are rendered as empty bars, and values of `1` as the lowest visible bar.
+    Log,
+}
+
 impl<'a> Sparkline<'a> {
     /// Wraps the sparkline with the given `block`.
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -215,11 +232,41 @@ impl<'a> Sparkline<'a> {
 
     /// Sets the maximum value of bars.
     ///
-    /// Every bar will be scaled accordingly. If no max is given, this will be the max in the
-    /// dataset.
+    /// Every bar will be scaled accordingly. If no max is given (or `None` is given), this will be
+    /// the max in the dataset. Setting the same max on multiple sparklines renders them on the same
+    /// scale. Values above the max are rendered as full height bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Sparkline;
+    ///
+    /// let sparkline = Sparkline::default().data([1, 2, 10]).max(5);
+    /// let sparkline = sparkline.max(None);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max<T: Into<Option<u64>>>(mut self, max: T) -> Self {
+        self.max = max.into();
+        self
+    }
+
+    /// Sets how the values are scaled to the height of the bars.
+    ///
+    /// [`SparklineScale::Linear`] by default. [`SparklineScale::Log`] is useful to display data
+    /// spanning several orders of magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Sparkline, SparklineScale};
+    ///
+    /// let sparkline = Sparkline::default()
+    ///     .data([1, 10, 100, 1000])
+    ///     .scale(SparklineScale::Log);
+    /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn max(mut self, max: u64) -> Self {
-        self.max = Some(max);
+    pub const fn scale(mut self, scale: SparklineScale) -> Self {
+        self.scale = scale;
         self
     }
 
@@ -384,11 +431,7 @@ impl Sparkline<'_> {
                     value: Some(value),
                     style,
                 } => {
-                    let height = if max_height == 0 {
-                        0
-                    } else {
-                        *value * u64::from(spark_area.height) * 8 / max_height
-                    };
+                    let height = self.bar_height(*value, max_height, spark_area.height);
                     (height, None, *style)
                 }
                 _ => (
@@ -419,6 +462,25 @@ impl Sparkline<'_> {
         }
     }
 
+    /// Returns the height of the bar for the given value in eighths of a cell.
+    ///
+    /// Values above the max are clamped to the full height of the area.
+    fn bar_height(&self, value: u64, max: u64, area_height: u16) -> u64 {
+        let full_height = u64::from(area_height) * 8;
+        let height = match self.scale {
+            SparklineScale::Linear if max == 0 => 0,
+            SparklineScale::Linear => value.saturating_mul(full_height) / max,
+            SparklineScale::Log if value == 0 => 0,
+            SparklineScale::Log if max <= 1 => full_height,
+            // log(1) is 0, so the heights are offset by one eighth to tell a value of 1 from 0
+            SparklineScale::Log => {
+                let ratio = (value as f64).log2() / (max as f64).log2();
+                1 + (ratio * (full_height - 1) as f64) as u64
+            }
+        };
+        height.min(full_height)
+    }
+
     const fn symbol_for_height(&self, height: u64) -> &str {
         match height {
             0 => self.bar_set.empty,
@@ -563,6 +625,58 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["   xxx"]));
     }
 
+    #[test]
+    fn scale_to_string() {
+        assert_eq!(SparklineScale::Linear.to_string(), "Linear");
+        assert_eq!(SparklineScale::Log.to_string(), "Log");
+    }
+
+    #[test]
+    fn scale_from_str() {
+        assert_eq!(
+            "Linear".parse::<SparklineScale>(),
+            Ok(SparklineScale::Linear)
+        );
+        assert_eq!("Log".parse::<SparklineScale>(), Ok(SparklineScale::Log));
+        assert_eq!(
+            "".parse::<SparklineScale>(),
+            Err(ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn it_clamps_values_above_max() {
+        let widget = Sparkline::default().data([0, 1, 5, 10]).max(5);
+        let buffer = render(widget, 6);
+        assert_eq!(buffer, Buffer::with_lines([" ▁██xx"]));
+    }
+
+    #[test]
+    fn it_uses_the_dataset_max_if_max_is_none() {
+        let widget = Sparkline::default().data([0, 4, 8]).max(16).max(None);
+        let buffer = render(widget, 4);
+        assert_eq!(buffer, Buffer::with_lines([" ▄█x"]));
+    }
+
+    #[test]
+    fn it_draws_log_scale() {
+        let widget = Sparkline::default()
+            .data([0, 1, 2, 4, 16, 256])
+            .scale(SparklineScale::Log);
+        let buffer = render(widget, 8);
+        assert_eq!(buffer, Buffer::with_lines([" ▁▁▂▄█xx"]));
+    }
+
+    #[test]
+    fn it_draws_log_scale_with_max() {
+        let widget = Sparkline::default()
+            .data([2, 16, 1024])
+            .max(256)
+            .scale(SparklineScale::Log);
+        let buffer = render(widget, 4);
+        assert_eq!(buffer, Buffer::with_lines(["▁▄█x"]));
+    }
+
     #[test]
     fn it_draws() {
         let widget = Sparkline::default().data([0, 1, 2, 3, 4, 5, 6, 7, 8]);
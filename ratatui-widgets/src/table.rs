@@ -6,17 +6,22 @@ use ratatui_core::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Rect},
     style::{Style, Styled},
-    text::Text,
+    text::{Span, Text},
     widgets::{StatefulWidget, Widget},
 };
 
-pub use self::{cell::Cell, highlight_spacing::HighlightSpacing, row::Row, state::TableState};
+pub use self::{
+    cell::Cell, highlight_spacing::HighlightSpacing, row::Row, state::TableState,
+    stripe_index::StripeIndex,
+};
 use crate::block::{Block, BlockExt};
+pub use crate::nav::NavPolicy;
 
 mod cell;
 mod highlight_spacing;
 mod row;
 mod state;
+mod stripe_index;
 
 /// A widget to display data in formatted columns.
 ///
@@ -58,6 +63,8 @@ mod state;
 /// - [`Table::rows`] sets the rows of the [`Table`].
 /// - [`Table::header`] sets the header row of the [`Table`].
 /// - [`Table::footer`] sets the footer row of the [`Table`].
+/// - [`Table::header_separator`] sets the line drawn between the header and the rows.
+/// - [`Table::footer_separator`] sets the line drawn between the rows and the footer.
 /// - [`Table::widths`] sets the width constraints of each column.
 /// - [`Table::column_spacing`] sets the spacing between each column.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
@@ -248,6 +255,12 @@ pub struct Table<'a> {
     /// Optional footer
     footer: Option<Row<'a>>,
 
+    /// Optional line drawn between the header and the rows
+    header_separator: Option<Span<'a>>,
+
+    /// Optional line drawn between the rows and the footer
+    footer_separator: Option<Span<'a>>,
+
     /// Width constraints for each column
     widths: Vec<Constraint>,
 
@@ -277,6 +290,12 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Styles of the even and odd rows
+    row_stripe_styles: Option<[Style; 2]>,
+
+    /// Which index of a row is used to pick its stripe style
+    stripe_index: StripeIndex,
 }
 
 impl Default for Table<'_> {
@@ -285,6 +304,8 @@ impl Default for Table<'_> {
             rows: Vec::new(),
             header: None,
             footer: None,
+            header_separator: None,
+            footer_separator: None,
             widths: Vec::new(),
             column_spacing: 1,
             block: None,
@@ -295,6 +316,8 @@ impl Default for Table<'_> {
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            row_stripe_styles: None,
+            stripe_index: StripeIndex::Visible,
         }
     }
 }
@@ -421,6 +444,59 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Sets the line drawn between the header and the rows
+    ///
+    /// The content of the separator is repeated to fill the width of the table and is styled with
+    /// the style of the [`Span`]. The separator takes one line and is only drawn when the table has
+    /// a [header](Table::header). Pass `None` to remove the separator.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::Stylize,
+    ///     text::Span,
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let table = Table::default()
+    ///     .header(Row::new(["Name", "Age"]))
+    ///     .header_separator(Span::from("─").dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_separator<T: Into<Option<Span<'a>>>>(mut self, separator: T) -> Self {
+        self.header_separator = separator.into();
+        self
+    }
+
+    /// Sets the line drawn between the rows and the footer
+    ///
+    /// The content of the separator is repeated to fill the width of the table and is styled with
+    /// the style of the [`Span`]. The separator takes one line and is only drawn when the table has
+    /// a [footer](Table::footer). Pass `None` to remove the separator.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     text::Span,
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let table = Table::default()
+    ///     .footer(Row::new(["Total", "42"]))
+    ///     .footer_separator(Span::from("═"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn footer_separator<T: Into<Option<Span<'a>>>>(mut self, separator: T) -> Self {
+        self.footer_separator = separator.into();
+        self
+    }
+
     /// Set the widths of the columns.
     ///
     /// The `widths` parameter accepts any type that implements `IntoIterator<Item =
@@ -430,6 +506,9 @@ impl<'a> Table<'a> {
     ///
     /// If the widths are empty, the table will be rendered with equal widths.
     ///
+    /// The widths are computed from the constraints on each render. To keep the widths measured by
+    /// one render for the following renders, see [`TableState::freeze_column_widths`].
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
@@ -748,6 +827,74 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Set the styles of alternating rows (zebra striping)
+    ///
+    /// `even` and `odd` accept any type that is convertible to [`Style`] (e.g. [`Style`],
+    /// [`Color`], or your own type that implements [`Into<Style>`]).
+    ///
+    /// The styles are applied when rendering, so the stripes stay correct when the rows are
+    /// filtered or sorted. By default the stripe of a row depends on its position in the visible
+    /// area of the table, which can be changed with [`Table::stripe_index`].
+    ///
+    /// The stripe style is applied under the style of the row and its cells, and the highlight
+    /// styles of the selection are applied over it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     style::{Style, Stylize},
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let rows = [
+    ///     Row::new(vec!["Cell1", "Cell2"]),
+    ///     Row::new(vec!["Cell3", "Cell4"]),
+    /// ];
+    /// let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).row_stripe_styles(Style::new(), Style::new().on_black());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_stripe_styles<E: Into<Style>, O: Into<Style>>(mut self, even: E, odd: O) -> Self {
+        self.row_stripe_styles = Some([even.into(), odd.into()]);
+        self
+    }
+
+    /// Set which index of a row is used to pick its stripe style
+    ///
+    /// Defaults to [`StripeIndex::Visible`]. See [`Table::row_stripe_styles`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     style::{Style, Stylize},
+    ///     widgets::{Row, StripeIndex, Table},
+    /// };
+    ///
+    /// let rows = [
+    ///     Row::new(vec!["Cell1", "Cell2"]),
+    ///     Row::new(vec!["Cell3", "Cell4"]),
+    /// ];
+    /// let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .row_stripe_styles(Style::new(), Style::new().on_black())
+    ///     .stripe_index(StripeIndex::Absolute);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stripe_index(mut self, stripe_index: StripeIndex) -> Self {
+        self.stripe_index = stripe_index;
+        self
+    }
 }
 
 impl Widget for Table<'_> {
@@ -782,9 +929,8 @@ impl StatefulWidget for &Table<'_> {
             return;
         }
 
-        if state.selected.is_some_and(|s| s >= self.rows.len()) {
-            state.select(Some(self.rows.len().saturating_sub(1)));
-        }
+        // Apply any wrapping move and set an out of bounds selection to the last item
+        state.clamp_selection(self.rows.len());
 
         if self.rows.is_empty() {
             state.select(None);
@@ -799,39 +945,77 @@ impl StatefulWidget for &Table<'_> {
         }
 
         let selection_width = self.selection_width(state);
-        let column_widths = self.get_column_widths(table_area.width, selection_width, column_count);
-        let (header_area, rows_area, footer_area) = self.layout(table_area);
+        let column_widths =
+            self.state_column_widths(state, table_area.width, selection_width, column_count);
+        let [header_area, header_separator_area, rows_area, footer_separator_area, footer_area] =
+            self.layout(table_area);
 
         self.render_header(header_area, buf, &column_widths);
+        render_separator(self.header_separator.as_ref(), header_separator_area, buf);
 
         self.render_rows(rows_area, buf, state, selection_width, &column_widths);
 
+        render_separator(self.footer_separator.as_ref(), footer_separator_area, buf);
         self.render_footer(footer_area, buf, &column_widths);
     }
 }
 
 // private methods for rendering
 impl Table<'_> {
-    /// Splits the table area into a header, rows area and a footer
-    fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
+    /// Splits the table area into a header, header separator, rows area, footer separator and a
+    /// footer
+    ///
+    /// When the area is too short to show the header, at least one line of rows and the footer,
+    /// the footer (and its separator) is hidden so that the header and the rows stay visible.
+    fn layout(&self, area: Rect) -> [Rect; 5] {
         let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
         let header_height = self.header.as_ref().map_or(0, |h| h.height);
         let header_bottom_margin = self.header.as_ref().map_or(0, |h| h.bottom_margin);
+        let header_separator_height =
+            u16::from(self.header.is_some() && self.header_separator.is_some());
         let footer_top_margin = self.footer.as_ref().map_or(0, |h| h.top_margin);
         let footer_height = self.footer.as_ref().map_or(0, |f| f.height);
         let footer_bottom_margin = self.footer.as_ref().map_or(0, |h| h.bottom_margin);
+        let footer_separator_height =
+            u16::from(self.footer.is_some() && self.footer_separator.is_some());
+
+        let header_total = header_top_margin
+            .saturating_add(header_height)
+            .saturating_add(header_bottom_margin)
+            .saturating_add(header_separator_height);
+        let footer_total = footer_separator_height
+            .saturating_add(footer_top_margin)
+            .saturating_add(footer_height)
+            .saturating_add(footer_bottom_margin);
+        let min_rows_height = u16::from(!self.rows.is_empty());
+        let footer_fits = header_total
+            .saturating_add(min_rows_height)
+            .saturating_add(footer_total)
+            <= area.height;
+        let footer_constraints = if footer_fits {
+            [
+                footer_separator_height,
+                footer_top_margin,
+                footer_height,
+                footer_bottom_margin,
+            ]
+        } else {
+            [0; 4]
+        };
+
         let layout = Layout::vertical([
             Constraint::Length(header_top_margin),
             Constraint::Length(header_height),
             Constraint::Length(header_bottom_margin),
+            Constraint::Length(header_separator_height),
             Constraint::Min(0),
-            Constraint::Length(footer_top_margin),
-            Constraint::Length(footer_height),
-            Constraint::Length(footer_bottom_margin),
+            Constraint::Length(footer_constraints[0]),
+            Constraint::Length(footer_constraints[1]),
+            Constraint::Length(footer_constraints[2]),
+            Constraint::Length(footer_constraints[3]),
         ])
         .split(area);
-        let (header_area, rows_area, footer_area) = (layout[1], layout[3], layout[5]);
-        (header_area, rows_area, footer_area)
+        [layout[1], layout[3], layout[4], layout[5], layout[7]]
     }
 
     fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
@@ -880,6 +1064,10 @@ impl Table<'_> {
             let y = area.y + y_offset + row.top_margin;
             let height = (y + row.height).min(area.bottom()).saturating_sub(y);
             let row_area = Rect { y, height, ..area };
+            if let Some(styles) = self.row_stripe_styles {
+                let odd = self.stripe_index.is_odd(i, start_index);
+                buf.set_style(row_area, styles[usize::from(odd)]);
+            }
             buf.set_style(row_area, row.style);
 
             let is_selected = state.selected.is_some_and(|index| index == i);
@@ -1000,6 +1188,43 @@ impl Table<'_> {
         } else {
             self.widths.clone()
         };
+        self.layout_columns(widths, max_width, selection_width)
+    }
+
+    /// Get the offsets and widths of the columns, taking the widths held by the state into account.
+    ///
+    /// Returns (x, width). Widths held by the state are reused as is, unless the state asks for
+    /// the widths computed from the constraints to be stored in it.
+    fn state_column_widths(
+        &self,
+        state: &mut TableState,
+        max_width: u16,
+        selection_width: u16,
+        col_count: usize,
+    ) -> Vec<(u16, u16)> {
+        match state.column_widths.as_deref() {
+            Some(widths) if !state.measure_column_widths => {
+                let widths = widths.iter().map(|&w| Constraint::Length(w)).collect();
+                self.layout_columns(widths, max_width, selection_width)
+            }
+            _ => {
+                let column_widths = self.get_column_widths(max_width, selection_width, col_count);
+                if state.measure_column_widths {
+                    state.column_widths = Some(column_widths.iter().map(|&(_, w)| w).collect());
+                    state.measure_column_widths = false;
+                }
+                column_widths
+            }
+        }
+    }
+
+    /// Lays out the columns with the given constraints, returning (x, width) for each column.
+    fn layout_columns(
+        &self,
+        widths: Vec<Constraint>,
+        max_width: u16,
+        selection_width: u16,
+    ) -> Vec<(u16, u16)> {
         // this will always allocate a selection area
         let [_selection_area, columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
@@ -1033,6 +1258,29 @@ impl Table<'_> {
     }
 }
 
+/// Renders the separator by repeating its content to fill the area
+fn render_separator(separator: Option<&Span>, area: Rect, buf: &mut Buffer) {
+    let Some(separator) = separator else {
+        return;
+    };
+    if area.is_empty() {
+        return;
+    }
+    buf.set_style(area, separator.style);
+    let width = separator.width();
+    if width == 0 {
+        return;
+    }
+    let repeat = usize::from(area.width) / width + 1;
+    buf.set_stringn(
+        area.x,
+        area.y,
+        separator.content.repeat(repeat),
+        usize::from(area.width),
+        separator.style,
+    );
+}
+
 fn ensure_percentages_less_than_100(widths: &[Constraint]) {
     for w in widths {
         if let Constraint::Percentage(p) = w {
@@ -1194,6 +1442,19 @@ mod tests {
         assert_eq!(table.footer, Some(footer));
     }
 
+    #[test]
+    fn separators() {
+        let table = Table::default()
+            .header_separator(Span::from("─"))
+            .footer_separator(Span::from("═"));
+        assert_eq!(table.header_separator, Some(Span::from("─")));
+        assert_eq!(table.footer_separator, Some(Span::from("═")));
+
+        let table = table.header_separator(None).footer_separator(None);
+        assert_eq!(table.header_separator, None);
+        assert_eq!(table.footer_separator, None);
+    }
+
     #[test]
     #[allow(deprecated)]
     fn highlight_style() {
@@ -1480,6 +1741,69 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_separators() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 6));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .header_separator(Span::from("─").red())
+                .footer(Row::new(vec!["Foot1", "Foot2"]))
+                .footer_separator(Span::from("=-"));
+            Widget::render(table, Rect::new(0, 0, 15, 6), &mut buf);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "Head1 Head2    ",
+                "───────────────",
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+                "=-=-=-=-=-=-=-=",
+                "Foot1 Foot2    ",
+            ]);
+            expected.set_style(Rect::new(0, 1, 15, 1), Style::new().red());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_separators_without_header_and_footer() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header_separator(Span::from("─"))
+                .footer_separator(Span::from("─"));
+            Widget::render(table, Rect::new(0, 0, 15, 2), &mut buf);
+            let expected = Buffer::with_lines(["Cell1 Cell2    ", "Cell3 Cell4    "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_hides_footer_when_too_short() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .header_separator(Span::from("─"))
+                .footer(Row::new(vec!["Foot1", "Foot2"]))
+                .footer_separator(Span::from("─"));
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Head1 Head2    ",
+                "───────────────",
+                "Cell1 Cell2    ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_row_margin() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1498,6 +1822,27 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_selected_row_margin() {
+            let rows = (0..3).map(|i| Row::new([format!("Row{i}")]).top_margin(1).bottom_margin(1));
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .row_highlight_style(Style::new().on_red());
+            let mut state = TableState::new().with_selected(Some(2));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 6));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            // the margins count when scrolling, but are not highlighted
+            assert_eq!(state.offset(), 1);
+            let expected = Buffer::with_lines([
+                "     ".into(),
+                "Row1 ".into(),
+                "     ".into(),
+                "     ".into(),
+                "Row2 ".on_red(),
+                "     ".into(),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_tall_row() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 23, 3));
@@ -1574,6 +1919,113 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_frozen_column_widths() {
+            let mut state = TableState::new();
+            state.freeze_column_widths();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            let table = Table::new(vec![Row::new(vec!["a", "b"])], [2, 3]);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["a  b      "]));
+            assert_eq!(state.column_widths(), Some([2, 3].as_slice()));
+
+            // the constraints are ignored while the widths are frozen
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            let table = Table::new(vec![Row::new(vec!["c", "d"])], [5, 1]);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["c  d      "]));
+            assert_eq!(state.column_widths(), Some([2, 3].as_slice()));
+
+            // clearing the widths uses the constraints again
+            *state.column_widths_mut() = None;
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            let table = Table::new(vec![Row::new(vec!["c", "d"])], [5, 1]);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["c     d   "]));
+            assert_eq!(state.column_widths(), None);
+
+            // freezing the widths again measures them with the current constraints
+            *state.column_widths_mut() = Some(vec![2, 3]);
+            state.freeze_column_widths();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            let table = Table::new(vec![Row::new(vec!["c", "d"])], [5, 1]);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["c     d   "]));
+            assert_eq!(state.column_widths(), Some([5, 1].as_slice()));
+        }
+
+        #[test]
+        fn render_with_row_stripe_styles() {
+            let rows = (0..4).map(|i| Row::new([format!("R{i}")]));
+            let rows = rows.map(|row| row.style(Style::new().italic()));
+            let table = Table::new(rows, [Constraint::Length(2)])
+                .row_stripe_styles(Style::new().on_blue(), Style::new().on_green())
+                .row_highlight_style(Style::new().on_red());
+            let mut state = TableState::new().with_selected(Some(3));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 2, 3));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 1);
+            // the first visible row is even, and the selection wins over the stripes
+            let expected = Buffer::with_lines([
+                "R1".italic().on_blue(),
+                "R2".italic().on_green(),
+                "R3".italic().on_red(),
+            ]);
+            assert_eq!(buf, expected);
+
+            let table = table.stripe_index(StripeIndex::Absolute);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 2, 3));
+            state.select(Some(1));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines([
+                "R1".italic().on_red(),
+                "R2".italic().on_blue(),
+                "R3".italic().on_green(),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_wrap_navigation() {
+            let rows = (0..5).map(|i| Row::new([format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(4)]).highlight_symbol(">>");
+            let mut state = TableState::new()
+                .with_navigation(NavPolicy::Wrap)
+                .with_selected(Some(4));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["  Row3", ">>Row4"]));
+            assert_eq!(state.offset(), 3);
+
+            state.select_next();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines([">>Row0", "  Row1"]));
+            assert_eq!(state.selected(), Some(0));
+            assert_eq!(state.offset(), 0);
+
+            state.select_previous();
+            state.select_previous();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["  Row2", ">>Row3"]));
+            assert_eq!(state.selected(), Some(3));
+            assert_eq!(state.offset(), 2);
+        }
+
+        #[test]
+        fn render_with_wrap_navigation_after_rows_are_removed() {
+            let rows = (0..5).map(|i| Row::new([format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(4)]).highlight_symbol(">>");
+            let mut state = TableState::new()
+                .with_navigation(NavPolicy::Wrap)
+                .with_selected(Some(5));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["  Row3", ">>Row4"]));
+            assert_eq!(state.selected(), Some(4));
+        }
+
         #[test]
         fn render_with_selected_column() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1,6 +1,6 @@
 use ratatui_core::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::{Style, Styled},
     text::Text,
     widgets::Widget,
@@ -13,7 +13,8 @@ use ratatui_core::{
 /// [`Style`] of the [`Cell`] by adding the [`Style`] of the [`Text`] content to the [`Style`] of
 /// the [`Cell`]. Styles set on the text content will only affect the content.
 ///
-/// You can use [`Text::alignment`] when creating a cell to align its content.
+/// You can use [`Cell::alignment`] or [`Text::alignment`] when creating a cell to align its
+/// content within the column, e.g. to right-align numbers.
 ///
 /// # Examples
 ///
@@ -158,6 +159,54 @@ impl<'a> Cell<'a> {
         self.style = style.into();
         self
     }
+
+    /// Returns a mutable reference to the [`Style`] of this cell
+    ///
+    /// This allows the style of a cell held in a [`Row`] to be changed in place, see
+    /// [`Row::cells_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Cell,
+    /// };
+    ///
+    /// let mut cell = Cell::new("Cell 1");
+    /// *cell.style_mut() = Style::new().yellow();
+    /// ```
+    ///
+    /// [`Row`]: super::Row
+    /// [`Row::cells_mut`]: super::Row::cells_mut
+    pub fn style_mut(&mut self) -> &mut Style {
+        &mut self.style
+    }
+
+    /// Set the [`Alignment`] of the content of this cell within its column
+    ///
+    /// This sets the alignment of the [`Text`] content of the cell, so it replaces any alignment
+    /// previously set on the text. Lines of the content with their own alignment keep it. When the
+    /// content is wider than the column, it is truncated on the side opposite to the alignment
+    /// (e.g. a right-aligned cell shows the end of the content).
+    ///
+    /// Note that the alignment is part of the content, so setting the content of the cell with
+    /// [`Cell::content`] afterwards resets it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{layout::Alignment, widgets::Cell};
+    ///
+    /// Cell::new("42.00").alignment(Alignment::Right);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.content = self.content.alignment(alignment);
+        self
+    }
 }
 
 impl Cell<'_> {
@@ -194,6 +243,7 @@ impl Styled for Cell<'_> {
 #[cfg(test)]
 mod tests {
     use ratatui_core::style::{Color, Modifier, Stylize};
+    use rstest::rstest;
 
     use super::*;
 
@@ -216,6 +266,38 @@ mod tests {
         assert_eq!(cell.style, style);
     }
 
+    #[test]
+    fn style_mut() {
+        let mut cell = Cell::new("");
+        *cell.style_mut() = Style::new().red().italic();
+        assert_eq!(cell.style, Style::new().red().italic());
+    }
+
+    #[test]
+    fn alignment() {
+        let cell = Cell::new("").alignment(Alignment::Right);
+        assert_eq!(cell.content, Text::from("").right_aligned());
+    }
+
+    #[rstest]
+    #[case::left(Alignment::Left, "12   ", "12345")]
+    #[case::center(Alignment::Center, " 12  ", "23456")]
+    #[case::right(Alignment::Right, "   12", "34567")]
+    fn render_alignment(
+        #[case] alignment: Alignment,
+        #[case] expected_short: &str,
+        #[case] expected_long: &str,
+    ) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        Cell::new("12")
+            .alignment(alignment)
+            .render(Rect::new(0, 0, 5, 1), &mut buf);
+        Cell::new("1234567")
+            .alignment(alignment)
+            .render(Rect::new(0, 1, 5, 1), &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected_short, expected_long]));
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(
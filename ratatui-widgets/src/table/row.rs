@@ -109,6 +109,63 @@ impl<'a> Row<'a> {
         }
     }
 
+    /// Creates a new [`Row`] with the given cells and style
+    ///
+    /// This is a shorthand for `Row::new(cells).style(style)`. The `cells` parameter accepts the
+    /// same values as [`Row::new`] and `style` accepts any type that is convertible to [`Style`].
+    ///
+    /// # Examples
+    ///
+    /// Rows generated from a slice of structs:
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::{Cell, Row},
+    /// };
+    ///
+    /// struct User {
+    ///     name: &'static str,
+    ///     email: &'static str,
+    ///     admin: bool,
+    /// }
+    ///
+    /// let users = [
+    ///     User {
+    ///         name: "Alice",
+    ///         email: "alice@example.com",
+    ///         admin: true,
+    ///     },
+    ///     User {
+    ///         name: "Bob",
+    ///         email: "bob@example.com",
+    ///         admin: false,
+    ///     },
+    /// ];
+    /// let rows: Vec<Row> = users
+    ///     .iter()
+    ///     .map(|user| {
+    ///         let style = if user.admin {
+    ///             Style::new().bold()
+    ///         } else {
+    ///             Style::new()
+    ///         };
+    ///         Row::styled(
+    ///             [Cell::from(user.name), Cell::from(user.email).italic()],
+    ///             style,
+    ///         )
+    ///     })
+    ///     .collect();
+    /// ```
+    pub fn styled<T, S>(cells: T, style: S) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Cell<'a>>,
+        S: Into<Style>,
+    {
+        Self::new(cells).style(style)
+    }
+
     /// Set the cells of the [`Row`]
     ///
     /// The `cells` parameter accepts any value that can be converted into an iterator of anything
@@ -239,6 +296,31 @@ impl<'a> Row<'a> {
         self.style = style.into();
         self
     }
+
+    /// Returns a mutable reference to the [`Cell`]s of the row
+    ///
+    /// This allows a row that is kept between frames (e.g. in the state of the application) to be
+    /// updated in place, such as restyling a single cell to highlight a changed value, instead of
+    /// being rebuilt from all its cells. Nothing is derived from the cells when they are set, so
+    /// the changes are used by the next render. Note that the height of the row is fixed (see
+    /// [`Row::height`]), so content with more lines than this height is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Row,
+    /// };
+    ///
+    /// let mut row = Row::new(vec!["AAPL", "189.84"]);
+    /// if let Some(cell) = row.cells_mut().get_mut(1) {
+    ///     *cell.style_mut() = Style::new().green().bold();
+    /// }
+    /// ```
+    pub fn cells_mut(&mut self) -> &mut Vec<Cell<'a>> {
+        &mut self.cells
+    }
 }
 
 // private methods for rendering
@@ -287,6 +369,14 @@ mod tests {
         assert_eq!(row.cells, cells);
     }
 
+    #[test]
+    fn styled() {
+        let cells = vec![Cell::from("a"), Cell::from("b").red()];
+        let row = Row::styled(cells.clone(), Style::new().bold());
+        assert_eq!(row, Row::new(cells).style(Style::new().bold()));
+        assert_eq!(row.height, 1);
+    }
+
     #[test]
     fn collect() {
         let cells = vec![Cell::from("")];
@@ -301,6 +391,19 @@ mod tests {
         assert_eq!(row.cells, cells);
     }
 
+    #[test]
+    fn cells_mut() {
+        let mut row = Row::new(vec!["a", "b", "c"]);
+        *row.cells_mut()[1].style_mut() = Style::new().red();
+        assert_eq!(
+            row.cells,
+            vec![Cell::from("a"), Cell::from("b").red(), Cell::from("c")]
+        );
+
+        row.cells_mut().push(Cell::from("d"));
+        assert_eq!(row.cells.len(), 4);
+    }
+
     #[test]
     fn height() {
         let row = Row::default().height(2);
@@ -1,3 +1,7 @@
+use std::hash::{Hash, Hasher};
+
+use crate::nav::{NavPolicy, PendingMove};
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -52,12 +56,43 @@
 ///
 /// [`Table`]: super::Table
 /// [`Table::widths`]: crate::table::Table::widths
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
     pub(crate) selected_column: Option<usize>,
+    pub(crate) column_widths: Option<Vec<u16>>,
+    /// Whether the next render stores the widths computed from the constraints of the table
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) measure_column_widths: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) navigation: NavPolicy,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) pending_move: Option<PendingMove>,
+}
+
+impl PartialEq for TableState {
+    /// A move which is not yet resolved is internal bookkeeping, so it is not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.selected == other.selected
+            && self.selected_column == other.selected_column
+            && self.column_widths == other.column_widths
+            && self.measure_column_widths == other.measure_column_widths
+            && self.navigation == other.navigation
+    }
+}
+
+impl Hash for TableState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.selected.hash(state);
+        self.selected_column.hash(state);
+        self.column_widths.hash(state);
+        self.measure_column_widths.hash(state);
+        self.navigation.hash(state);
+    }
 }
 
 impl TableState {
@@ -75,6 +110,10 @@ impl TableState {
             offset: 0,
             selected: None,
             selected_column: None,
+            column_widths: None,
+            measure_column_widths: false,
+            navigation: NavPolicy::Clamp,
+            pending_move: None,
         }
     }
 
@@ -112,6 +151,7 @@ impl TableState {
         T: Into<Option<usize>>,
     {
         self.selected = selected.into();
+        self.pending_move = None;
         self
     }
 
@@ -149,6 +189,7 @@ impl TableState {
     where
         T: Into<Option<(usize, usize)>>,
     {
+        self.pending_move = None;
         if let Some((r, c)) = selected.into() {
             self.selected = Some(r);
             self.selected_column = Some(c);
@@ -160,6 +201,76 @@ impl TableState {
         self
     }
 
+    /// Sets the widths of the columns, which are used instead of the widths of the table
+    ///
+    /// See [`TableState::column_widths`] for more information.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_column_widths(vec![5, 10]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_column_widths<T>(mut self, widths: T) -> Self
+    where
+        T: Into<Option<Vec<u16>>>,
+    {
+        self.column_widths = widths.into();
+        self.measure_column_widths = false;
+        self
+    }
+
+    /// Sets the policy used when moving the selection past the first or last row
+    ///
+    /// See [`NavPolicy`] for more information.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{NavPolicy, TableState};
+    ///
+    /// let state = TableState::default().with_navigation(NavPolicy::Wrap);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_navigation(mut self, navigation: NavPolicy) -> Self {
+        self.navigation = navigation;
+        self
+    }
+
+    /// Policy used when moving the selection past the first or last row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{NavPolicy, TableState};
+    ///
+    /// let state = TableState::default();
+    /// assert_eq!(state.navigation(), NavPolicy::Clamp);
+    /// ```
+    pub const fn navigation(&self) -> NavPolicy {
+        self.navigation
+    }
+
+    /// Sets the policy used when moving the selection past the first or last row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{NavPolicy, TableState};
+    ///
+    /// let mut state = TableState::default();
+    /// state.set_navigation(NavPolicy::Wrap);
+    /// ```
+    pub fn set_navigation(&mut self, navigation: NavPolicy) {
+        self.navigation = navigation;
+    }
+
     /// Index of the first row to be displayed
     ///
     /// # Examples
@@ -188,6 +299,66 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Widths of the columns that are reused across renders
+    ///
+    /// When this is `None` (the default), the widths of the columns are computed from the
+    /// [`Table::widths`] constraints on each render. When this holds widths, they are used as
+    /// [`Constraint::Length`] constraints instead, which keeps the columns from jittering when the
+    /// content of the table changes between frames. The widths can be measured by a render, see
+    /// [`TableState::freeze_column_widths`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_widths(), None);
+    /// ```
+    ///
+    /// [`Table::widths`]: crate::table::Table::widths
+    /// [`Constraint::Length`]: ratatui_core::layout::Constraint::Length
+    pub fn column_widths(&self) -> Option<&[u16]> {
+        self.column_widths.as_deref()
+    }
+
+    /// Mutable reference to the widths of the columns that are reused across renders
+    ///
+    /// Set this to `None` to compute the widths from the constraints of the table again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// *state.column_widths_mut() = Some(vec![5, 10]);
+    /// ```
+    pub fn column_widths_mut(&mut self) -> &mut Option<Vec<u16>> {
+        &mut self.column_widths
+    }
+
+    /// Keeps the widths of the columns measured by the next render for the following renders
+    ///
+    /// The next render computes the widths of the columns from the [`Table::widths`] constraints,
+    /// even if the state already holds widths, and stores them in the state. Later renders reuse
+    /// these widths until they are cleared with [`TableState::column_widths_mut`], e.g. when the
+    /// terminal is resized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.freeze_column_widths();
+    /// ```
+    ///
+    /// [`Table::widths`]: crate::table::Table::widths
+    pub fn freeze_column_widths(&mut self) {
+        self.measure_column_widths = true;
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -250,6 +421,7 @@ impl TableState {
     /// *state.selected_mut() = Some(1);
     /// ```
     pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        self.pending_move = None;
         &mut self.selected
     }
 
@@ -282,6 +454,7 @@ impl TableState {
     /// ```
     pub fn select(&mut self, index: Option<usize>) {
         self.selected = index;
+        self.pending_move = None;
         if index.is_none() {
             self.offset = 0;
         }
@@ -312,6 +485,7 @@ impl TableState {
     /// state.select_cell(Some((1, 5)));
     /// ```
     pub fn select_cell(&mut self, indexes: Option<(usize, usize)>) {
+        self.pending_move = None;
         if let Some((r, c)) = indexes {
             self.selected = Some(r);
             self.selected_column = Some(c);
@@ -325,7 +499,9 @@ impl TableState {
     /// Selects the next row or the first one if no row is selected
     ///
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
-    /// `0` and will be corrected when the table is rendered
+    /// `0` and will be corrected when the table is rendered. With [`NavPolicy::Wrap`], the move is
+    /// also applied when the table is rendered, so that moving past the last row selects the
+    /// first one.
     ///
     /// # Examples
     ///
@@ -337,7 +513,11 @@ impl TableState {
     /// ```
     pub fn select_next(&mut self) {
         let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        let pending_move = self
+            .navigation
+            .record_move(self.pending_move, self.selected, 1);
         self.select(Some(next));
+        self.pending_move = pending_move;
     }
 
     /// Selects the next column or the first one if no column is selected
@@ -360,7 +540,9 @@ impl TableState {
     /// Selects the previous row or the last one if no item is selected
     ///
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
-    /// `usize::MAX` and will be corrected when the table is rendered
+    /// `usize::MAX` and will be corrected when the table is rendered. With [`NavPolicy::Wrap`], the
+    /// move is also applied when the table is rendered, so that moving before the first row
+    /// selects the last one.
     ///
     /// # Examples
     ///
@@ -372,7 +554,11 @@ impl TableState {
     /// ```
     pub fn select_previous(&mut self) {
         let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        let pending_move = self
+            .navigation
+            .record_move(self.pending_move, self.selected, -1);
         self.select(Some(previous));
+        self.pending_move = pending_move;
     }
 
     /// Selects the previous column or the last one if no column is selected
@@ -498,6 +684,61 @@ impl TableState {
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
 
+    /// Moves the selection by `delta` rows in a table of `count` rows
+    ///
+    /// A positive `delta` moves the selection down and a negative one moves it up. Moving past
+    /// either end follows the [`NavPolicy`] of the state: with [`NavPolicy::Wrap`] the selection
+    /// continues from the other end, otherwise it stops at the first or last row. If no row is
+    /// selected, a positive `delta` counts from before the first row and a negative `delta`
+    /// counts from after the last one, so `1` selects the first row and `-1` selects the last. If
+    /// `count` is `0`, the selection is cleared.
+    ///
+    /// Unlike the other selection methods, the number of rows is passed in, so the resulting index
+    /// is always valid. The offset is moved up if needed so that the selection is not above the
+    /// visible rows; the rest is corrected when the table is rendered.
+    ///
+    /// This can be used to implement next / previous and page up / page down navigation with the
+    /// same code path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{NavPolicy, TableState};
+    ///
+    /// let mut state = TableState::default().with_navigation(NavPolicy::Wrap);
+    /// state.select_relative(1, 5);
+    /// assert_eq!(state.selected(), Some(0));
+    /// state.select_relative(-1, 5);
+    /// assert_eq!(state.selected(), Some(4));
+    ///
+    /// state.set_navigation(NavPolicy::Clamp);
+    /// state.select_relative(10, 5);
+    /// assert_eq!(state.selected(), Some(4));
+    /// ```
+    pub fn select_relative(&mut self, delta: isize, count: usize) {
+        self.clamp_selection(count);
+        let index = self.navigation.step(self.selected, delta, count);
+        if let Some(index) = index {
+            self.offset = self.offset.min(index);
+        }
+        self.select(index);
+    }
+
+    /// Clamps the selection to a table of `count` rows
+    ///
+    /// Any move made by `select_next` or `select_previous` with [`NavPolicy::Wrap`] is applied
+    /// first, and an out of bounds selection is then set to the last row.
+    pub(crate) fn clamp_selection(&mut self, count: usize) {
+        if let Some(PendingMove { from, delta }) = self.pending_move.take() {
+            if let Some(index) = self.navigation.step(Some(from), delta, count) {
+                self.selected = Some(index);
+            }
+        }
+        if self.selected.is_some_and(|s| s >= count) {
+            self.select(Some(count.saturating_sub(1)));
+        }
+    }
+
     /// Scrolls right by a specified `amount` in the table.
     ///
     /// This method updates the selected index by moving it right by the given `amount`.
@@ -545,6 +786,27 @@ mod tests {
         assert_eq!(state.offset, 0);
         assert_eq!(state.selected, None);
         assert_eq!(state.selected_column, None);
+        assert_eq!(state.column_widths, None);
+        assert_eq!(state.navigation, NavPolicy::Clamp);
+    }
+
+    #[test]
+    fn with_column_widths() {
+        let state = TableState::new().with_column_widths(vec![1, 2]);
+        assert_eq!(state.column_widths(), Some([1, 2].as_slice()));
+        let state = state.with_column_widths(None);
+        assert_eq!(state.column_widths(), None);
+    }
+
+    #[test]
+    fn freeze_column_widths() {
+        let mut state = TableState::new();
+        state.freeze_column_widths();
+        assert!(state.measure_column_widths);
+        assert_eq!(state.column_widths(), None);
+
+        let state = state.with_column_widths(vec![1, 2]);
+        assert!(!state.measure_column_widths);
     }
 
     #[test]
@@ -744,4 +1006,97 @@ mod tests {
         state.scroll_left_by(20);
         assert_eq!(state.selected_column, Some(80));
     }
+
+    #[test]
+    fn test_table_state_wrap_navigation() {
+        let mut state = TableState::default().with_navigation(NavPolicy::Wrap);
+        assert_eq!(state.navigation(), NavPolicy::Wrap);
+
+        // moving before the first item is applied when the number of items is known
+        state.select_first();
+        state.select_previous();
+        state.select_previous();
+        assert_eq!(state.selected, Some(0));
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(3));
+
+        state.select(Some(4));
+        state.select_next();
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(0));
+
+        // selecting an index directly discards the pending move
+        state.select_previous();
+        state.select(Some(2));
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(2));
+
+        // an out of bounds selection is clamped rather than wrapped
+        state.select(Some(5));
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(4));
+
+        state.set_navigation(NavPolicy::Clamp);
+        state.select_first();
+        state.select_previous();
+        state.clamp_selection(5);
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn select_relative() {
+        let mut state = TableState::default();
+        state.select_relative(1, 5);
+        assert_eq!(state.selected, Some(0));
+
+        let mut state = TableState::default();
+        state.select_relative(-1, 5);
+        assert_eq!(state.selected, Some(4));
+
+        state.select_relative(-2, 5);
+        assert_eq!(state.selected, Some(2));
+
+        state.select_relative(-10, 5);
+        assert_eq!(state.selected, Some(0));
+
+        state.select_relative(10, 5);
+        assert_eq!(state.selected, Some(4));
+
+        // an out of range selection is clamped before moving
+        state.select(Some(usize::MAX));
+        state.select_relative(-1, 5);
+        assert_eq!(state.selected, Some(3));
+
+        state.select_relative(0, 0);
+        assert_eq!(state.selected, None);
+    }
+
+    #[test]
+    fn select_relative_wrap() {
+        let mut state = TableState::default()
+            .with_navigation(NavPolicy::Wrap)
+            .with_selected(Some(4));
+        state.select_relative(1, 5);
+        assert_eq!(state.selected, Some(0));
+
+        state.select_relative(-1, 5);
+        assert_eq!(state.selected, Some(4));
+
+        state.select_relative(-7, 5);
+        assert_eq!(state.selected, Some(2));
+
+        state.select_relative(isize::MIN, 5);
+        assert!(state.selected.is_some_and(|i| i < 5));
+    }
+
+    #[test]
+    fn select_relative_offset() {
+        let mut state = TableState::default().with_offset(8).with_selected(Some(9));
+        state.select_relative(-3, 10);
+        assert_eq!(state.selected, Some(6));
+        assert_eq!(state.offset, 6);
+
+        state.select_relative(3, 10);
+        assert_eq!(state.offset, 6);
+    }
 }
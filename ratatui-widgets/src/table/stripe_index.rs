@@ -0,0 +1,59 @@
+use strum::{Display, EnumString};
+
+/// Which index of a row is used to pick its stripe style
+///
+/// See [`Table::row_stripe_styles`] for more information.
+///
+/// [`Table::row_stripe_styles`]: crate::table::Table::row_stripe_styles
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum StripeIndex {
+    /// Use the position of the row in the visible area of the table
+    ///
+    /// The first visible row always has the even style, so the stripes don't move when the table
+    /// is scrolled.
+    #[default]
+    Visible,
+
+    /// Use the index of the row in the table
+    ///
+    /// Each row keeps the same style when the table is scrolled.
+    Absolute,
+}
+
+impl StripeIndex {
+    /// Returns whether the row at `index` in the table should use the odd style when the first
+    /// visible row is at `offset`
+    pub(crate) const fn is_odd(self, index: usize, offset: usize) -> bool {
+        let index = match self {
+            Self::Visible => index - offset,
+            Self::Absolute => index,
+        };
+        index % 2 == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string() {
+        assert_eq!(StripeIndex::Visible.to_string(), "Visible");
+        assert_eq!(StripeIndex::Absolute.to_string(), "Absolute");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("Visible".parse::<StripeIndex>(), Ok(StripeIndex::Visible));
+        assert_eq!("Absolute".parse::<StripeIndex>(), Ok(StripeIndex::Absolute));
+        assert!("Relative".parse::<StripeIndex>().is_err());
+    }
+
+    #[test]
+    fn is_odd() {
+        assert!(!StripeIndex::Visible.is_odd(3, 3));
+        assert!(StripeIndex::Visible.is_odd(4, 3));
+        assert!(StripeIndex::Absolute.is_odd(3, 3));
+        assert!(!StripeIndex::Absolute.is_odd(4, 3));
+    }
+}
@@ -1,4 +1,6 @@
 //! The [`Tabs`] widget displays a horizontal set of tabs with a single tab selected.
+use std::ops::Range;
+
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
@@ -13,12 +15,17 @@ use crate::block::{Block, BlockExt};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
 
+/// Indicator drawn on the left when tabs are scrolled out of view
+const LEFT_OVERFLOW_SYMBOL: &str = "‹";
+/// Indicator drawn on the right when tabs are scrolled out of view
+const RIGHT_OVERFLOW_SYMBOL: &str = "›";
+
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
 ///
 /// Each tab title is stored as a [`Line`] which can be individually styled. The selected tab is set
 /// using [`Tabs::select`] and styled using [`Tabs::highlight_style`]. The divider can be customized
-/// with [`Tabs::divider`]. Padding can be set with [`Tabs::padding`] or [`Tabs::padding_left`] and
-/// [`Tabs::padding_right`].
+/// with [`Tabs::divider`] and styled with [`Tabs::divider_style`]. Padding can be set with
+/// [`Tabs::padding`] or [`Tabs::padding_left`] and [`Tabs::padding_right`].
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
@@ -62,10 +69,18 @@ pub struct Tabs<'a> {
     highlight_style: Style,
     /// Tab divider
     divider: Span<'a>,
+    /// Style applied to the dividers
+    divider_style: Style,
     /// Tab Left Padding
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// Whether to scroll the tabs so that the selected tab is always visible
+    scroll_to_selected: bool,
+    /// Whether to underline the selected tab instead of highlighting it
+    underline_selected: bool,
+    /// Symbol used to underline the selected tab
+    underline_symbol: &'a str,
 }
 
 impl Default for Tabs<'_> {
@@ -77,6 +92,7 @@ impl Default for Tabs<'_> {
     /// - The highlight style is set to reversed.
     /// - The divider is set to a pipe (`|`).
     /// - The padding on the left and right is set to a space.
+    /// - The selected tab is highlighted rather than underlined.
     ///
     /// This is rarely useful on its own without calling [`Tabs::titles`].
     ///
@@ -139,8 +155,12 @@ impl<'a> Tabs<'a> {
             style: Style::default(),
             highlight_style: DEFAULT_HIGHLIGHT_STYLE,
             divider: Span::raw(symbols::line::VERTICAL),
+            divider_style: Style::default(),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            scroll_to_selected: false,
+            underline_selected: false,
+            underline_symbol: symbols::line::HORIZONTAL,
         }
     }
 
@@ -278,6 +298,33 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Sets the style of the dividers between tabs.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is applied on top of [`Tabs::style`], and any style set on the divider given to
+    /// [`Tabs::divider`] is applied on top of it. This makes it possible to dim the dividers
+    /// relative to the titles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Tabs,
+    /// };
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).divider_style(Style::new().dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn divider_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.divider_style = style.into();
+        self
+    }
+
     /// Sets the padding between tabs.
     ///
     /// Both default to space.
@@ -348,6 +395,78 @@ impl<'a> Tabs<'a> {
         self.padding_left = padding.into();
         self
     }
+
+    /// Sets whether the tabs scroll horizontally to keep the selected tab visible.
+    ///
+    /// When enabled and the tabs do not fit in the available width, the tabs before the selected
+    /// tab are skipped as needed so that the selected tab is rendered. A `‹` indicator is drawn on
+    /// the left when tabs are hidden before the first visible tab, and a `›` indicator is drawn on
+    /// the right when tabs are hidden after the last visible tab.
+    ///
+    /// Defaults to `false`, which always renders the tabs starting from the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2", "Tab 3"])
+    ///     .select(2)
+    ///     .scroll_to_selected(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scroll_to_selected(mut self, scroll_to_selected: bool) -> Self {
+        self.scroll_to_selected = scroll_to_selected;
+        self
+    }
+
+    /// Sets whether the selected tab is underlined instead of highlighted.
+    ///
+    /// When enabled, the row below the tabs is used to draw a line (`─` by default, see
+    /// [`Tabs::underline_symbol`]) under the title of the selected tab, and the
+    /// [`highlight_style`](Tabs::highlight_style) is not applied. This requires an area that is at
+    /// least two rows high (not counting the [`Block`]); when there is only one row, the selected
+    /// tab is highlighted as usual.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2", "Tab 3"])
+    ///     .select(1)
+    ///     .underline_selected(true);
+    /// // Renders
+    /// //  Tab 1 │ Tab 2 │ Tab 3
+    /// //          ─────
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn underline_selected(mut self, underline_selected: bool) -> Self {
+        self.underline_selected = underline_selected;
+        self
+    }
+
+    /// Sets the symbol used to underline the selected tab.
+    ///
+    /// The symbol is repeated for each column of the selected tab's title. It is only used when
+    /// [`Tabs::underline_selected`] is enabled. Defaults to `─`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{symbols, widgets::Tabs};
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2", "Tab 3"])
+    ///     .underline_selected(true)
+    ///     .underline_symbol(symbols::line::THICK_HORIZONTAL);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn underline_symbol(mut self, underline_symbol: &'a str) -> Self {
+        self.underline_symbol = underline_symbol;
+        self
+    }
 }
 
 impl Styled for Tabs<'_> {
@@ -383,9 +502,63 @@ impl Tabs<'_> {
             return;
         }
 
+        let selected = self
+            .selected
+            .filter(|&selected| self.scroll_to_selected && selected < self.titles.len());
+        let available_width = usize::from(tabs_area.width);
+        let Some(selected) =
+            selected.filter(|_| self.titles_width(0..self.titles.len()) > available_width)
+        else {
+            self.render_titles(tabs_area, buf, 0);
+            return;
+        };
+
+        // the rightmost column is reserved for the right overflow indicator
+        let available_width = available_width - 1;
+        let first = (0..=selected)
+            .find(|&first| {
+                self.titles_width(first..selected + 1) + usize::from(first > 0) <= available_width
+            })
+            .unwrap_or(selected);
+
+        let mut titles_area = Rect {
+            width: tabs_area.width - 1,
+            ..tabs_area
+        };
+        if first > 0 {
+            buf.set_string(
+                tabs_area.x,
+                tabs_area.y,
+                LEFT_OVERFLOW_SYMBOL,
+                Style::default(),
+            );
+            titles_area.x += 1;
+            titles_area.width = titles_area.width.saturating_sub(1);
+        }
+        if self.titles_width(first..self.titles.len()) > usize::from(titles_area.width) {
+            let x = tabs_area.right() - 1;
+            buf.set_string(x, tabs_area.y, RIGHT_OVERFLOW_SYMBOL, Style::default());
+        }
+        self.render_titles(titles_area, buf, first);
+    }
+
+    /// Returns the width of the tabs in the given range, including padding and dividers.
+    fn titles_width(&self, range: Range<usize>) -> usize {
+        let padding_width = self.padding_left.width() + self.padding_right.width();
+        let dividers_width = self.divider.width() * range.len().saturating_sub(1);
+        self.titles[range]
+            .iter()
+            .map(|title| title.width() + padding_width)
+            .sum::<usize>()
+            + dividers_width
+    }
+
+    /// Renders the titles starting from the tab at index `first`.
+    fn render_titles(&self, tabs_area: Rect, buf: &mut Buffer, first: usize) {
+        let underline = self.underline_selected && tabs_area.height >= 2;
         let mut x = tabs_area.left();
         let titles_length = self.titles.len();
-        for (i, title) in self.titles.iter().enumerate() {
+        for (i, title) in self.titles.iter().enumerate().skip(first) {
             let last_title = titles_length - 1 == i;
             let remaining_width = tabs_area.right().saturating_sub(x);
 
@@ -404,15 +577,27 @@ impl Tabs<'_> {
             // Title
             let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
             if Some(i) == self.selected {
-                buf.set_style(
-                    Rect {
+                let width = pos.0.saturating_sub(x);
+                if underline {
+                    let underline = self.underline_symbol.repeat(usize::from(width));
+                    buf.set_stringn(
                         x,
-                        y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
-                        height: 1,
-                    },
-                    self.highlight_style,
-                );
+                        tabs_area.top() + 1,
+                        underline,
+                        usize::from(width),
+                        Style::default(),
+                    );
+                } else {
+                    buf.set_style(
+                        Rect {
+                            x,
+                            y: tabs_area.top(),
+                            width,
+                            height: 1,
+                        },
+                        self.highlight_style,
+                    );
+                }
             }
             x = pos.0;
             let remaining_width = tabs_area.right().saturating_sub(x);
@@ -428,7 +613,13 @@ impl Tabs<'_> {
                 break;
             }
 
-            let pos = buf.set_span(x, tabs_area.top(), &self.divider, remaining_width);
+            let pos = buf.set_stringn(
+                x,
+                tabs_area.top(),
+                &self.divider.content,
+                usize::from(remaining_width),
+                self.divider_style.patch(self.divider.style),
+            );
             x = pos.0;
         }
     }
@@ -467,8 +658,12 @@ mod tests {
                 style: Style::default(),
                 highlight_style: DEFAULT_HIGHLIGHT_STYLE,
                 divider: Span::raw(symbols::line::VERTICAL),
+                divider_style: Style::default(),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                scroll_to_selected: false,
+                underline_selected: false,
+                underline_symbol: symbols::line::HORIZONTAL,
             }
         );
     }
@@ -484,8 +679,12 @@ mod tests {
                 style: Style::default(),
                 highlight_style: DEFAULT_HIGHLIGHT_STYLE,
                 divider: Span::raw(symbols::line::VERTICAL),
+                divider_style: Style::default(),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                scroll_to_selected: false,
+                underline_selected: false,
+                underline_symbol: symbols::line::HORIZONTAL,
             }
         );
     }
@@ -526,9 +725,7 @@ mod tests {
 
     #[track_caller]
     fn test_case(tabs: Tabs, area: Rect, expected: &Buffer) {
-        let mut buffer = Buffer::empty(area);
-        tabs.render(area, &mut buffer);
-        assert_eq!(&buffer, expected);
+        assert_eq!(&tabs.render_buffer(area), expected);
     }
 
     #[test]
@@ -641,6 +838,91 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_divider_style() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"])
+            .style(Style::new().red())
+            .divider_style(Style::new().dark_gray())
+            .select(None);
+        let expected = Buffer::with_lines([Line::from(vec![
+            " Tab1 ".red(),
+            "│".dark_gray(),
+            " Tab2 ".red(),
+            "│".dark_gray(),
+            " Tab3 ".red(),
+        ])]);
+        test_case(tabs, Rect::new(0, 0, 20, 1), &expected);
+    }
+
+    #[test]
+    fn render_divider_style_is_patched_by_divider() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"])
+            .divider("-".bold())
+            .divider_style(Style::new().dark_gray())
+            .select(None);
+        let expected = Buffer::with_lines([Line::from(vec![
+            " Tab1 ".into(),
+            "-".dark_gray().bold(),
+            " Tab2 ".into(),
+        ])]);
+        test_case(tabs, Rect::new(0, 0, 13, 1), &expected);
+    }
+
+    #[test]
+    fn render_scroll_to_selected() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).scroll_to_selected(true);
+
+        // first tab selected, the tabs after the visible ones are hidden
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 │›"]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.clone().select(0), Rect::new(0, 0, 15, 1), &expected);
+
+        // last tab selected, the tabs before the visible ones are skipped
+        let mut expected = Buffer::with_lines(["‹ Tab3 │ Tab4  "]);
+        expected.set_style(Rect::new(9, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.clone().select(3), Rect::new(0, 0, 15, 1), &expected);
+
+        // all tabs fit, nothing is skipped
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 │ Tab3 │ Tab4    "]);
+        expected.set_style(Rect::new(22, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.select(3), Rect::new(0, 0, 30, 1), &expected);
+    }
+
+    #[test]
+    fn render_underline_selected() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"])
+            .underline_selected(true)
+            .select(1);
+        let expected = Buffer::with_lines([" Tab1 │ Tab2 │ Tab3    ", "        ────           "]);
+        test_case(tabs.clone(), Rect::new(0, 0, 23, 2), &expected);
+
+        let expected = Buffer::with_lines([
+            "┌───────────────────────┐",
+            "│ Tab1 │ Tab2 │ Tab3    │",
+            "│        ━━━━           │",
+            "└───────────────────────┘",
+        ]);
+        test_case(
+            tabs.clone()
+                .underline_symbol(symbols::line::THICK_HORIZONTAL)
+                .block(Block::bordered()),
+            Rect::new(0, 0, 25, 4),
+            &expected,
+        );
+
+        // falls back to the highlight style when there is a single row
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 │ Tab3    "]);
+        expected.set_style(Rect::new(8, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 23, 1), &expected);
+    }
+
+    #[test]
+    fn render_without_scroll_to_selected() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).select(3);
+        let expected = Buffer::with_lines([" Tab1 │ Tab2 │ "]);
+        test_case(tabs, Rect::new(0, 0, 15, 1), &expected);
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -0,0 +1,287 @@
+//! The [`Throbber`] widget displays an animated symbol (also known as a spinner) to indicate that
+//! work is in progress.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    symbols,
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+/// A widget to display an activity indicator, optionally followed by a label.
+///
+/// A throbber cycles through a set of frames, one symbol per frame. Which frame is rendered is
+/// chosen by the caller with [`Throbber::frame`], which wraps around the number of frames. This
+/// makes it easy to animate the throbber by passing an ever-increasing counter such as the
+/// `Frame::count` of the frame being drawn.
+///
+/// The frames default to [`symbols::throbber::BRAILLE`]. Other sets are available in the
+/// [`symbols::throbber`] module, and any slice of strings can be used with [`Throbber::frames`].
+///
+/// ```text
+/// ⠋ Loading...
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     symbols,
+///     widgets::Throbber,
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let throbber = Throbber::new()
+///     .frames(symbols::throbber::LINE)
+///     .frame(frame.count())
+///     .label("Loading...")
+///     .symbol_style(Style::new().cyan());
+///
+/// frame.render_widget(throbber, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Throbber<'a> {
+    /// The symbols to cycle through
+    frames: &'a [&'a str],
+    /// The index of the frame to render, wrapped around the number of frames
+    frame: usize,
+    /// Optional label rendered after the symbol
+    label: Option<Line<'a>>,
+    /// Base style of the widget
+    style: Style,
+    /// Style applied to the symbol
+    symbol_style: Style,
+}
+
+impl Default for Throbber<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Throbber<'a> {
+    /// Creates a new `Throbber` using the [`symbols::throbber::BRAILLE`] frames.
+    pub const fn new() -> Self {
+        Self {
+            frames: symbols::throbber::BRAILLE,
+            frame: 0,
+            label: None,
+            style: Style::new(),
+            symbol_style: Style::new(),
+        }
+    }
+
+    /// Sets the symbols to cycle through.
+    ///
+    /// Presets are available in the [`symbols::throbber`] module. If `frames` is empty, only the
+    /// label is rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{symbols, widgets::Throbber};
+    ///
+    /// let throbber = Throbber::new().frames(symbols::throbber::DOTS);
+    /// let custom = Throbber::new().frames(&["◐", "◓", "◑", "◒"]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frames(mut self, frames: &'a [&'a str]) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Sets the frame to render.
+    ///
+    /// The index wraps around the number of frames, so an increasing counter can be passed
+    /// directly.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frame(mut self, frame: usize) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Sets the label rendered after the symbol.
+    ///
+    /// `label` can be any type that converts into a [`Line`] (e.g. `&str`, `String`, [`Span`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the base style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the symbol.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbol_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.symbol_style = style.into();
+        self
+    }
+
+    /// Returns the symbol of the current frame, or `None` if there are no frames.
+    pub const fn symbol(&self) -> Option<&'a str> {
+        if self.frames.is_empty() {
+            None
+        } else {
+            Some(self.frames[self.frame % self.frames.len()])
+        }
+    }
+}
+
+impl Styled for Throbber<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for Throbber<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Throbber<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let mut x = area.left();
+        if let Some(symbol) = self.symbol() {
+            let span = Span::styled(symbol, self.symbol_style);
+            (x, _) = buf.set_span(x, area.top(), &span, area.width);
+            if self.label.is_some() {
+                x = x.saturating_add(1);
+            }
+        }
+        if let Some(label) = &self.label {
+            if x < area.right() {
+                buf.set_line(x, area.top(), label, area.right() - x);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn default_uses_braille_frames() {
+        let throbber = Throbber::new();
+        assert_eq!(throbber.frames, symbols::throbber::BRAILLE);
+        assert_eq!(throbber.symbol(), Some("⠋"));
+    }
+
+    #[rstest]
+    #[case::first(0, "-")]
+    #[case::last(3, "/")]
+    #[case::wraps(5, "\\")]
+    #[case::large(usize::MAX, "/")]
+    fn symbol(#[case] frame: usize, #[case] expected: &str) {
+        let throbber = Throbber::new().frames(symbols::throbber::LINE).frame(frame);
+        assert_eq!(throbber.symbol(), Some(expected));
+    }
+
+    #[test]
+    fn symbol_without_frames() {
+        let throbber = Throbber::new().frames(&[]);
+        assert_eq!(throbber.symbol(), None);
+    }
+
+    #[test]
+    fn render_frame() {
+        let throbber = Throbber::new().frame(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        throbber.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["⠹    "]));
+    }
+
+    #[test]
+    fn render_frame_with_label() {
+        let throbber = Throbber::new()
+            .frames(symbols::throbber::DOTS)
+            .frame(1)
+            .label("Loading");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        throbber.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["..  Loading "]));
+    }
+
+    #[test]
+    fn render_truncates_label() {
+        let throbber = Throbber::new().label("Loading");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        throbber.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["⠋ Loa"]));
+    }
+
+    #[test]
+    fn render_label_without_frames() {
+        let throbber = Throbber::new().frames(&[]).label("Done");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        throbber.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["Done "]));
+    }
+
+    #[test]
+    fn render_styles() {
+        let throbber = Throbber::new()
+            .label("ok")
+            .style(Color::Blue)
+            .symbol_style(Style::new().red());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        throbber.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["⠋ ok "]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_in_empty_area() {
+        let throbber = Throbber::new().label("Loading");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        throbber.render(Rect::new(0, 0, 0, 0), &mut buf);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 5, 1)));
+    }
+}
@@ -2,10 +2,11 @@ use criterion::{black_box, BenchmarkId, Criterion};
 use ratatui::{
     buffer::{Buffer, Cell},
     layout::Rect,
+    style::Style,
     text::Line,
 };
 
-criterion::criterion_group!(benches, empty, filled, with_lines);
+criterion::criterion_group!(benches, empty, filled, with_lines, diff);
 
 const fn rect(size: u16) -> Rect {
     Rect::new(0, 0, size, size)
@@ -58,3 +59,32 @@ fn with_lines(c: &mut Criterion) {
     }
     group.finish();
 }
+
+/// Diffs a buffer filled with text against a sparse buffer, where most of the rows end with blank
+/// cells, as happens when the content of a UI is cleared.
+fn diff(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/diff");
+    for size in [16, 64, 255] {
+        let area = rect(size);
+        let previous = Buffer::filled(area, Cell::new("a"));
+        let mut next = Buffer::empty(area);
+        for y in (0..size).step_by(4) {
+            next.set_string(0, y, "sparse", Style::new());
+        }
+        group.bench_with_input(
+            BenchmarkId::new("diff", size),
+            &(&previous, &next),
+            |b, (previous, next)| {
+                b.iter(|| black_box(previous.diff(next)));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("diff_erasing_trailing", size),
+            &(&previous, &next),
+            |b, (previous, next)| {
+                b.iter(|| black_box(previous.diff_erasing_trailing(next)));
+            },
+        );
+    }
+    group.finish();
+}
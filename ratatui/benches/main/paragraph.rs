@@ -2,7 +2,7 @@ use criterion::{black_box, criterion_group, BatchSize, Bencher, BenchmarkId, Cri
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    widgets::{Paragraph, Widget, Wrap},
+    widgets::{Paragraph, ParagraphState, StatefulWidget, Widget, Wrap},
 };
 
 /// because the scroll offset is a u16, the maximum number of lines that can be scrolled is 65535.
@@ -51,7 +51,7 @@ fn paragraph(c: &mut Criterion) {
         // render the paragraph wrapped to 100 characters
         group.bench_with_input(
             BenchmarkId::new("render_wrap", line_count),
-            &Paragraph::new(lines).wrap(Wrap { trim: false }),
+            &Paragraph::new(lines).wrap(Wrap::new().trim(false)),
             |bencher, paragraph| render(bencher, paragraph, WRAP_WIDTH),
         );
 
@@ -59,10 +59,37 @@ fn paragraph(c: &mut Criterion) {
         group.bench_with_input(
             BenchmarkId::new("render_wrap_scroll_full", line_count),
             &Paragraph::new(lines)
-                .wrap(Wrap { trim: false })
+                .wrap(Wrap::new().trim(false))
                 .scroll((y_scroll, 0)),
             |bencher, paragraph| render(bencher, paragraph, WRAP_WIDTH),
         );
+
+        // scroll the paragraph by the full number of lines and render wrapped to 100 characters,
+        // reusing the lines wrapped by a previous render
+        group.bench_with_input(
+            BenchmarkId::new("render_wrap_scroll_full_cached", line_count),
+            &Paragraph::new(lines)
+                .wrap(Wrap::new().trim(false))
+                .scroll((y_scroll, 0)),
+            |bencher, paragraph| render_cached(bencher, paragraph, WRAP_WIDTH),
+        );
+
+        // scroll through the paragraph wrapped to 100 characters one line per render, wrapping
+        // the text again on each render
+        let wrapped = Paragraph::new(lines).wrap(Wrap::new().trim(false));
+        group.bench_with_input(
+            BenchmarkId::new("render_wrap_scrolling", line_count),
+            &wrapped,
+            |bencher, paragraph| render_scrolling(bencher, paragraph, line_count, false),
+        );
+
+        // scroll through the paragraph wrapped to 100 characters one line per render, reusing the
+        // lines wrapped by the previous render
+        group.bench_with_input(
+            BenchmarkId::new("render_wrap_scrolling_cached", line_count),
+            &wrapped,
+            |bencher, paragraph| render_scrolling(bencher, paragraph, line_count, true),
+        );
     }
     group.finish();
 }
@@ -81,6 +108,49 @@ fn render(bencher: &mut Bencher, paragraph: &Paragraph, width: u16) {
     );
 }
 
+/// render the paragraph into a buffer with the given width, reusing the wrapped lines of a first
+/// render so that only the rendering from the cache is measured
+fn render_cached(bencher: &mut Bencher, paragraph: &Paragraph, width: u16) {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, PARAGRAPH_DEFAULT_HEIGHT));
+    let mut state = ParagraphState::default();
+    paragraph
+        .to_owned()
+        .cached()
+        .render(buffer.area, &mut buffer, &mut state);
+    bencher.iter_batched(
+        || paragraph.to_owned().cached(),
+        |bench_paragraph| {
+            bench_paragraph.render(buffer.area, &mut buffer, &mut state);
+        },
+        BatchSize::LargeInput,
+    );
+}
+
+/// render the paragraph wrapped to 100 characters, scrolling down by one line on each render (up
+/// to `line_count` lines) as when scrolling through a long text, with or without caching the
+/// wrapped lines
+fn render_scrolling(bencher: &mut Bencher, paragraph: &Paragraph, line_count: u16, cached: bool) {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, WRAP_WIDTH, PARAGRAPH_DEFAULT_HEIGHT));
+    let mut state = ParagraphState::default();
+    let mut offset = 0;
+    bencher.iter_batched(
+        || {
+            offset = (offset + 1) % line_count;
+            paragraph.to_owned().scroll((offset, 0))
+        },
+        |bench_paragraph| {
+            if cached {
+                bench_paragraph
+                    .cached()
+                    .render(buffer.area, &mut buffer, &mut state);
+            } else {
+                bench_paragraph.render(buffer.area, &mut buffer);
+            }
+        },
+        BatchSize::LargeInput,
+    );
+}
+
 /// Create a string with the given number of lines filled with nonsense words
 ///
 /// English language has about 5.1 average characters per word so including the space between words
@@ -20,37 +20,42 @@
 //! - [`Gauge`]: displays progress percentage using block characters.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`VirtualList`]: displays a list of items that are rendered on demand.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PropertyList`]: displays a list of key/value pairs with aligned labels.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: display a single data set as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`Throbber`]: displays an animated activity indicator with an optional label.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
 
-pub use ratatui_core::widgets::{StatefulWidget, Widget};
+pub use ratatui_core::widgets::{Clipped, SizeHint, StatefulWidget, Widget};
 // TODO remove this module once title etc. are gone
 pub use ratatui_widgets::block;
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
 pub use ratatui_widgets::{
-    barchart::{Bar, BarChart, BarGroup},
+    barchart::{Bar, BarChart, BarChartState, BarGroup, LabelOverflow},
     block::{Block, Padding},
     borders::{BorderType, Borders},
     canvas,
     chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
     clear::Clear,
     gauge::{Gauge, LineGauge},
-    list::{List, ListDirection, ListItem, ListState},
+    list::{HighlightSymbolPosition, List, ListDirection, ListItem, ListState, VirtualList},
     logo::{RatatuiLogo, Size as RatatuiLogoSize},
     mascot::{MascotEyeColor, RatatuiMascot},
-    paragraph::{Paragraph, Wrap},
+    paragraph::{CachedParagraph, LineNumbers, Paragraph, ParagraphState, TextWrapExt, Wrap},
+    property_list::{PropertyList, PropertyListState},
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
-    sparkline::{RenderDirection, Sparkline, SparklineBar},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
+    sparkline::{RenderDirection, Sparkline, SparklineBar, SparklineScale},
+    table::{Cell, HighlightSpacing, NavPolicy, Row, StripeIndex, Table, TableState},
     tabs::Tabs,
+    throbber::Throbber,
 };
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
@@ -67,7 +72,8 @@ pub trait FrameExt {
     /// Render a [`WidgetRef`] to the current buffer using [`WidgetRef::render_ref`].
     ///
     /// Usually the area argument is the size of the current frame or a sub-area of the current
-    /// frame (which can be obtained using [`Layout`] to split the total area).
+    /// frame (which can be obtained using [`Layout`] to split the total area). As with
+    /// [`Frame::render_widget`], the area is clipped to the [area of the frame].
     ///
     /// # Example
     ///
@@ -89,6 +95,8 @@ pub trait FrameExt {
     /// ```
     ///
     /// [`Layout`]: crate::layout::Layout
+    /// [`Frame::render_widget`]: crate::Frame::render_widget
+    /// [area of the frame]: crate::Frame::area
     #[allow(clippy::needless_pass_by_value)]
     fn render_widget_ref<W: WidgetRef>(&mut self, widget: W, area: Rect);
 
@@ -96,11 +104,16 @@ pub trait FrameExt {
     /// [`StatefulWidgetRef::render_ref`].
     ///
     /// Usually the area argument is the size of the current frame or a sub-area of the current
-    /// frame (which can be obtained using [`Layout`] to split the total area).
+    /// frame (which can be obtained using [`Layout`] to split the total area). As with
+    /// [`Frame::render_stateful_widget`], the area is clipped to the [area of the frame].
     ///
     /// The last argument should be an instance of the [`StatefulWidgetRef::State`] associated to
     /// the given [`StatefulWidgetRef`].
     ///
+    /// Passing a reference to a widget that implements [`StatefulWidget`] for `&W` (like the
+    /// built-in widgets) renders it without moving it, so a long-lived widget holding large owned
+    /// data can be kept in the application state and rendered on each frame.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -121,6 +134,8 @@ pub trait FrameExt {
     /// # }
     /// ```
     /// [`Layout`]: crate::layout::Layout
+    /// [`Frame::render_stateful_widget`]: crate::Frame::render_stateful_widget
+    /// [area of the frame]: crate::Frame::area
     #[allow(clippy::needless_pass_by_value)]
     fn render_stateful_widget_ref<W>(&mut self, widget: W, area: Rect, state: &mut W::State)
     where
@@ -131,6 +146,7 @@ pub trait FrameExt {
 impl FrameExt for ratatui_core::terminal::Frame<'_> {
     #[allow(clippy::needless_pass_by_value)]
     fn render_widget_ref<W: WidgetRef>(&mut self, widget: W, area: Rect) {
+        let area = area.intersection(self.area());
         widget.render_ref(area, self.buffer_mut());
     }
 
@@ -139,6 +155,7 @@ impl FrameExt for ratatui_core::terminal::Frame<'_> {
     where
         W: StatefulWidgetRef,
     {
+        let area = area.intersection(self.area());
         widget.render_ref(area, self.buffer_mut(), state);
     }
 }
@@ -90,6 +90,16 @@ where
     }
 }
 
+/// This allows you to render a stack of boxed or referenced widgets of different types, e.g. with
+/// [`Frame::render_all`].
+///
+/// [`Frame::render_all`]: crate::Frame::render_all
+impl Widget for &dyn WidgetRef {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
 /// Provides the ability to render a string slice by reference.
 ///
 /// This trait implementation ensures that a string slice, which is an immutable view over a
@@ -209,6 +219,15 @@ mod tests {
         assert_eq!(buf, Buffer::with_lines(["Hello        Goodbye"]));
     }
 
+    #[rstest]
+    fn render_dyn_widget_ref(mut buf: Buffer) {
+        let widgets: [&dyn WidgetRef; 2] = [&&Greeting, &&Farewell];
+        for widget in widgets {
+            widget.render(buf.area, &mut buf);
+        }
+        assert_eq!(buf, Buffer::with_lines(["Hello        Goodbye"]));
+    }
+
     #[rstest]
     fn render_ref_some(mut buf: Buffer) {
         let widget = Some(&Greeting);
@@ -103,12 +103,15 @@ const DEFAULT_STATE_BUFFER: [&str; 5] = [
 const DEFAULT_STATE_REPR: &str = r#"{
   "list": {
     "offset": 0,
-    "selected": null
+    "selected": null,
+    "navigation": "Clamp"
   },
   "table": {
     "offset": 0,
     "selected": null,
-    "selected_column": null
+    "selected_column": null,
+    "column_widths": null,
+    "navigation": "Clamp"
   },
   "scrollbar": {
     "content_length": 10,
@@ -141,12 +144,15 @@ const SELECTED_STATE_BUFFER: [&str; 5] = [
 const SELECTED_STATE_REPR: &str = r#"{
   "list": {
     "offset": 0,
-    "selected": 1
+    "selected": 1,
+    "navigation": "Clamp"
   },
   "table": {
     "offset": 0,
     "selected": 1,
-    "selected_column": 0
+    "selected_column": 0,
+    "column_widths": null,
+    "navigation": "Clamp"
   },
   "scrollbar": {
     "content_length": 10,
@@ -181,12 +187,15 @@ const SCROLLED_STATE_BUFFER: [&str; 5] = [
 const SCROLLED_STATE_REPR: &str = r#"{
   "list": {
     "offset": 4,
-    "selected": 8
+    "selected": 8,
+    "navigation": "Clamp"
   },
   "table": {
     "offset": 4,
     "selected": 8,
-    "selected_column": 0
+    "selected_column": 0,
+    "column_widths": null,
+    "navigation": "Clamp"
   },
   "scrollbar": {
     "content_length": 10,
@@ -219,7 +228,9 @@ const OLD_TABLE_DESERIALIZE: &str = r#"{
 const NEW_TABLE_DESERIALIZE: &str = r#"{
     "offset": 0,
     "selected": 1,
-    "selected_column": null
+    "selected_column": null,
+    "column_widths": null,
+    "navigation": "Clamp"
 }"#;
 
 // This test is to check for backwards compatibility with the old states.
@@ -1,8 +1,10 @@
 use std::error::Error;
 
 use ratatui::{
-    backend::TestBackend,
-    layout::Rect,
+    backend::{Backend, TestBackend},
+    buffer::{Buffer, Cell},
+    layout::{Rect, Size},
+    style::Style,
     widgets::{Block, Paragraph, Widget},
     Terminal, TerminalOptions, Viewport,
 };
@@ -19,6 +21,26 @@ fn swap_buffer_clears_prev_buffer() {
     assert_eq!(terminal.current_buffer_mut().content()[0].symbol(), " ");
 }
 
+#[test]
+fn swap_buffers_custom_render_loop() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(5, 1);
+    let mut terminal = Terminal::new(backend)?;
+    let area = terminal.current_buffer_mut().area;
+
+    for text in ["one", "two"] {
+        let mut buffer = Buffer::empty(area);
+        Paragraph::new(text).render(area, &mut buffer);
+        *terminal.current_buffer_mut() = buffer;
+        terminal.flush()?;
+        terminal.swap_buffers();
+        terminal
+            .backend()
+            .assert_buffer_lines([format!("{text}  ")]);
+        assert_eq!(terminal.current_buffer_mut(), &Buffer::empty(area));
+    }
+    Ok(())
+}
+
 #[test]
 fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(10, 10);
@@ -39,28 +61,334 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_draw_render_widget_centered() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        f.render_widget_centered(Block::bordered(), Size::new(4, 3));
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "          ",
+        "   ┌──┐   ",
+        "   │  │   ",
+        "   └──┘   ",
+        "          ",
+    ]);
+    terminal.draw(|f| {
+        f.render_widget_centered(Paragraph::new("Too wide"), Size::new(20, 1));
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "          ",
+        "          ",
+        "Too wide  ",
+        "          ",
+        "          ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_render_widgets() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        f.render_widgets([
+            (Paragraph::new("Header"), Rect::new(0, 0, 6, 1)),
+            (Paragraph::new("Footer"), Rect::new(0, 1, 6, 1)),
+        ]);
+    })?;
+    terminal.backend().assert_buffer_lines(["Header", "Footer"]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_render_all() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(8, 3);
+    let mut terminal = Terminal::new(backend)?;
+    let backdrop = Block::bordered();
+    let title = Block::new().title("Title");
+    terminal.draw(|f| {
+        // later widgets are rendered on top of earlier ones
+        f.render_all(Rect::new(1, 0, 7, 3), [&backdrop, &title]);
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines([" Title─┐", " │     │", " └─────┘"]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_render_widget_if_changed() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(5, 1);
+    let mut terminal = Terminal::new(backend)?;
+    let area = Rect::new(0, 0, 5, 1);
+
+    // the first frame is always rendered
+    terminal.draw(|f| f.render_widget_if_changed(Paragraph::new("one"), area, false))?;
+    terminal.backend().assert_buffer_lines(["one  "]);
+
+    // the previous frame is kept when the widget did not change
+    terminal.draw(|f| f.render_widget_if_changed(Paragraph::new("two"), area, false))?;
+    terminal.backend().assert_buffer_lines(["one  "]);
+
+    terminal.draw(|f| f.render_widget_if_changed(Paragraph::new("two"), area, true))?;
+    terminal.backend().assert_buffer_lines(["two  "]);
+
+    // the widget is rendered again after the terminal is cleared
+    terminal.clear()?;
+    terminal.draw(|f| f.render_widget_if_changed(Paragraph::new("three"), area, false))?;
+    terminal.backend().assert_buffer_lines(["three"]);
+    Ok(())
+}
+
+#[test]
+fn terminal_set_viewport() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 4);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("xxxxxx\nxxxxxx\nxxxxxx"), f.area()))?;
+
+    terminal.set_viewport(Viewport::Fixed(Rect::new(1, 1, 3, 2)))?;
+    terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(1, 1, 3, 2));
+        f.render_widget(Paragraph::new("ab"), f.area());
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["      ", " ab   ", "      ", "      "]);
+
+    terminal.set_viewport(Viewport::Fullscreen)?;
+    terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(0, 0, 6, 4));
+        f.render_widget(Paragraph::new("full"), f.area());
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["full  ", "      ", "      ", "      "]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_clips_widgets_to_the_viewport() -> Result<(), Box<dyn Error>> {
+    /// A widget that writes to every cell of its area without checking the buffer bounds
+    struct Fill;
+
+    impl Widget for Fill {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            for position in area.positions() {
+                buf[position].set_symbol("x");
+            }
+        }
+    }
+
+    let backend = TestBackend::new(6, 4);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Fixed(Rect::new(1, 1, 3, 2)),
+        },
+    )?;
+    terminal.draw(|f| f.render_widget(Fill, Rect::new(0, 0, 6, 4)))?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["      ", " xxx  ", " xxx  ", "      "]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_counts_writes_outside_of_the_area() -> Result<(), Box<dyn Error>> {
+    /// A widget that writes its text without truncating it to its area
+    struct Overflow;
+
+    impl Widget for Overflow {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            buf.set_string(area.x, area.y, "overflow", Style::new());
+        }
+    }
+
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend)?;
+    let mut dropped_writes = (0, 0);
+    terminal.draw(|f| {
+        dropped_writes = (
+            f.render_widget_debug_clip(Overflow, Rect::new(0, 0, 4, 1)),
+            f.render_widget_debug_clip(Paragraph::new("overflow"), Rect::new(0, 1, 4, 1)),
+        );
+    })?;
+    // the writes past the end of the frame are not recorded
+    assert_eq!(dropped_writes, (2, 0));
+    terminal.backend().assert_buffer_lines(["over  ", "over  "]);
+    Ok(())
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+#[test]
+fn terminal_draw_renders_stateful_widget_ref() -> Result<(), Box<dyn Error>> {
+    use ratatui::widgets::{FrameExt, List, ListState};
+
+    struct App {
+        list: List<'static>,
+        state: ListState,
+    }
+
+    let mut app = App {
+        list: List::new(["Item 0", "Item 1", "Item 2"]).highlight_symbol(">"),
+        state: ListState::default().with_selected(Some(0)),
+    };
+    let backend = TestBackend::new(7, 2);
+    let mut terminal = Terminal::new(backend)?;
+    for selected in [1, 2] {
+        app.state.select(Some(selected));
+        terminal.draw(|f| {
+            // the area is clipped to the frame
+            f.render_stateful_widget_ref(&app.list, Rect::new(0, 0, 20, 20), &mut app.state);
+        })?;
+    }
+    terminal
+        .backend()
+        .assert_buffer_lines([" Item 1", ">Item 2"]);
+    assert_eq!(app.state.offset(), 1);
+    Ok(())
+}
+
+#[test]
+fn terminal_present_writes_incremental_changes() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        Paragraph::new("frame").render(f.area(), f.buffer_mut());
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["frame     ", "          "]);
+
+    // the current buffer is cleared by draw, so the whole content is written again
+    let buffer = terminal.current_buffer_mut();
+    buffer.set_string(0, 0, "frame", Style::new());
+    buffer.set_string(0, 1, "step 1", Style::new());
+    terminal.present()?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["frame     ", "step 1    "]);
+
+    // the current buffer is kept by present
+    terminal
+        .current_buffer_mut()
+        .set_string(5, 1, "2", Style::new());
+    terminal.present()?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["frame     ", "step 2    "]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_after_present_starts_from_an_empty_buffer() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 1);
+    let mut terminal = Terminal::new(backend)?;
+    terminal
+        .current_buffer_mut()
+        .set_string(0, 0, "loading...", Style::new());
+    terminal.present()?;
+    terminal.backend().assert_buffer_lines(["loading..."]);
+
+    terminal.draw(|f| {
+        Paragraph::new("ab").render(f.area(), f.buffer_mut());
+    })?;
+    terminal.backend().assert_buffer_lines(["ab        "]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_erases_trailing_cells() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.set_erase_trailing_cells(true);
+    terminal.draw(|f| {
+        Paragraph::new("abcdefghij\nabcdefghij\nabcdefghij").render(f.area(), f.buffer_mut());
+    })?;
+    terminal.draw(|f| {
+        Paragraph::new("abc\n\nabcdefghij").render(f.area(), f.buffer_mut());
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["abc       ", "          ", "abcdefghij"]);
+    assert!(terminal.erase_trailing_cells());
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_keeps_cells_right_of_a_fixed_viewport() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Fixed(Rect::new(0, 0, 4, 2)),
+        },
+    )?;
+    terminal.set_erase_trailing_cells(true);
+    let cell = Cell::new("x");
+    terminal
+        .backend_mut()
+        .draw([(4, 0, &cell), (5, 0, &cell)].into_iter())?;
+    terminal.draw(|f| Paragraph::new("abcd").render(f.area(), f.buffer_mut()))?;
+    terminal.draw(|f| Paragraph::new("a").render(f.area(), f.buffer_mut()))?;
+    terminal.backend().assert_buffer_lines(["a   xx", "      "]);
+    Ok(())
+}
+
 #[test]
 fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(10, 10);
     let mut terminal = Terminal::new(backend)?;
+    assert_eq!(terminal.frame_count(), 0);
     let frame = terminal.draw(|f| {
         assert_eq!(f.count(), 0);
         let paragraph = Paragraph::new("Test");
         f.render_widget(paragraph, f.area());
     })?;
     assert_eq!(frame.count, 0);
+    assert_eq!(terminal.frame_count(), 1);
     let frame = terminal.draw(|f| {
         assert_eq!(f.count(), 1);
         let paragraph = Paragraph::new("test");
         f.render_widget(paragraph, f.area());
     })?;
     assert_eq!(frame.count, 1);
+    assert_eq!(terminal.frame_count(), 2);
     let frame = terminal.draw(|f| {
         assert_eq!(f.count(), 2);
         let paragraph = Paragraph::new("test");
         f.render_widget(paragraph, f.area());
     })?;
     assert_eq!(frame.count, 2);
+    assert_eq!(terminal.frame_count(), 3);
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_before_has_no_effect_without_inline_viewport() -> Result<(), Box<dyn Error>> {
+    // insert_before only makes room above an inline viewport, so it must leave a fullscreen
+    // viewport and the scrollback untouched.
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::new(backend)?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("[---- Viewport ----]");
+        f.render_widget(paragraph, f.area());
+    })?;
+
+    terminal.insert_before(2, |buf| {
+        Paragraph::new("------ Line 1 ------").render(buf.area, buf);
+    })?;
+
+    terminal.backend().assert_buffer_lines([
+        "[---- Viewport ----]",
+        "                    ",
+        "                    ",
+    ]);
+    terminal.backend().assert_scrollback_empty();
+
     Ok(())
 }
 
@@ -482,3 +810,61 @@ fn terminal_insert_before_large_viewport_does_not_clobber() -> Result<(), Box<dy
 
     Ok(())
 }
+
+#[test]
+fn terminal_finish_inline_moves_cursor_below_viewport() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+        },
+    )?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new(vec!["Line 1".into(), "Line 2".into()]);
+        f.render_widget(paragraph, f.area());
+    })?;
+    terminal.finish_inline()?;
+
+    terminal.backend().assert_buffer_lines([
+        "Line 1              ",
+        "Line 2              ",
+        "                    ",
+        "                    ",
+        "                    ",
+    ]);
+    terminal.backend().assert_scrollback_empty();
+    terminal.backend_mut().assert_cursor_position((0, 2));
+
+    Ok(())
+}
+
+#[test]
+fn terminal_finish_inline_scrolls_when_viewport_is_at_bottom() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(3),
+        },
+    )?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new(vec!["Line 1".into(), "Line 2".into(), "Line 3".into()]);
+        f.render_widget(paragraph, f.area());
+    })?;
+    terminal.finish_inline()?;
+
+    terminal.backend().assert_buffer_lines([
+        "Line 2              ",
+        "Line 3              ",
+        "                    ",
+    ]);
+    terminal
+        .backend()
+        .assert_scrollback_lines(["Line 1              "]);
+    terminal.backend_mut().assert_cursor_position((0, 2));
+
+    Ok(())
+}
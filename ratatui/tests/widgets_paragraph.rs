@@ -26,7 +26,7 @@ fn widgets_paragraph_renders_double_width_graphemes() {
     let text = vec![Line::from(s)];
     let paragraph = Paragraph::new(text)
         .block(Block::bordered())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new().trim(true));
 
     test_case(
         paragraph,
@@ -56,7 +56,7 @@ fn widgets_paragraph_renders_mixed_width_graphemes() {
             let text = vec![Line::from(s)];
             let paragraph = Paragraph::new(text)
                 .block(Block::bordered())
-                .wrap(Wrap { trim: true });
+                .wrap(Wrap::new().trim(true));
             f.render_widget(paragraph, f.area());
         })
         .unwrap();
@@ -137,7 +137,7 @@ fn widgets_paragraph_can_wrap_its_content() {
     let text = vec![Line::from(SAMPLE_STRING)];
     let paragraph = Paragraph::new(text)
         .block(Block::bordered())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new().trim(true));
 
     test_case(
         paragraph.clone().alignment(Alignment::Left),
@@ -196,7 +196,7 @@ fn widgets_paragraph_works_with_padding() {
     });
     let paragraph = Paragraph::new(vec![Line::from(SAMPLE_STRING)])
         .block(block.clone())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new().trim(true));
 
     test_case(
         paragraph.clone().alignment(Alignment::Left),
@@ -238,7 +238,7 @@ fn widgets_paragraph_works_with_padding() {
         Line::from(SAMPLE_STRING),
     ])
     .block(block)
-    .wrap(Wrap { trim: true });
+    .wrap(Wrap::new().trim(true));
 
     test_case(
         paragraph.alignment(Alignment::Right),
@@ -272,7 +272,7 @@ fn widgets_paragraph_can_align_spans() {
     ];
     let paragraph = Paragraph::new(text)
         .block(Block::bordered())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new().trim(true));
 
     test_case(
         paragraph.clone().alignment(Alignment::Left),